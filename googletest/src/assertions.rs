@@ -1057,12 +1057,11 @@ macro_rules! expect_ge {
 /// Checks whether the float given by first argument is approximately
 /// equal to second argument.
 ///
-/// This automatically computes a tolerance from the magnitude of `expected` and
-/// matches any actual value within this tolerance of the expected value. The
-/// tolerance is chosen to account for the inaccuracies in most ordinary
-/// floating point calculations. To see details of how the tolerance is
-/// calculated look at the implementation of
-/// [`googletest::approx_eq`][crate::matchers::approx_eq].
+/// The values are considered approximately equal if they are at most 4 units
+/// in the last place (ULPs) apart, as with `EXPECT_FLOAT_EQ` and
+/// `EXPECT_DOUBLE_EQ` in C++ GoogleTest. This accounts for the rounding errors
+/// of most ordinary floating point calculations. See
+/// [`NearMatcher::within_ulps`][crate::matchers::NearMatcher::within_ulps].
 ///
 /// Evaluates to `Result::Ok(())` if the first argument is approximately equal
 /// to the second and `Result::Err(TestAssertionFailure)` if it is not. The
@@ -1091,7 +1090,7 @@ macro_rules! expect_ge {
 #[macro_export]
 macro_rules! verify_float_eq {
     ($actual:expr, $expected:expr $(,)?) => {
-        verify_that!($actual, $crate::matchers::approx_eq($expected))
+        verify_that!($actual, $crate::matchers::approx_eq($expected).within_ulps(4))
     };
 }
 
@@ -1099,12 +1098,11 @@ macro_rules! verify_float_eq {
 /// argument is not approximately equal to the float given by the second
 /// argument.
 ///
-/// This automatically computes a tolerance from the magnitude of `expected` and
-/// matches any actual value within this tolerance of the expected value. The
-/// tolerance is chosen to account for the inaccuracies in most ordinary
-/// floating point calculations. To see details of how the tolerance is
-/// calculated look at the implementation of
-/// [`googletest::approx_eq`][crate::matchers::approx_eq].
+/// The values are considered approximately equal if they are at most 4 units
+/// in the last place (ULPs) apart, as with `EXPECT_FLOAT_EQ` and
+/// `EXPECT_DOUBLE_EQ` in C++ GoogleTest. This accounts for the rounding errors
+/// of most ordinary floating point calculations. See
+/// [`NearMatcher::within_ulps`][crate::matchers::NearMatcher::within_ulps].
 ///
 /// This is a **not-fatal** failure. The test continues execution even after the
 /// macro execution.
@@ -1439,8 +1437,8 @@ macro_rules! expect_pred {
 #[doc(hidden)]
 pub mod internal {
     use crate::{
-        internal::test_outcome::TestAssertionFailure,
-        matcher::{create_assertion_failure, Matcher, MatcherResult},
+        internal::{test_outcome::TestAssertionFailure, update_scope::with_updates_allowed},
+        matcher::{assertion_failure_message, Matcher},
     };
     use std::fmt::Debug;
 
@@ -1459,6 +1457,9 @@ pub mod internal {
     /// # }
     /// ```
     /// See [Method Lookup](https://rustc-dev-guide.rust-lang.org/method-lookup.html)
+    // `Subject` deliberately keeps the `Copy` bound even though `Matcher` only
+    // requires `Clone`: this is what makes method lookup fall back to `&T` for
+    // non-`Copy` values instead of moving them into the assertion.
     pub trait Subject: Copy + Debug {
         /// Checks whether the matcher `expected` matches the `Subject `self`,
         /// adding a test failure report if it does not match.
@@ -1474,11 +1475,22 @@ pub mod internal {
             expected: impl Matcher<Self>,
             actual_expr: &'static str,
         ) -> Result<(), TestAssertionFailure> {
-            match expected.matches(self) {
-                MatcherResult::Match => Ok(()),
-                MatcherResult::NoMatch => {
-                    Err(create_assertion_failure(&expected, self, actual_expr))
-                }
+            let mut failure_message = None;
+            with_updates_allowed(true, || {
+                expected.match_and_explain(self, &mut |result, explanation| {
+                    if result.is_no_match() {
+                        failure_message = Some(assertion_failure_message(
+                            &expected,
+                            &self,
+                            explanation,
+                            actual_expr,
+                        ));
+                    }
+                })
+            });
+            match failure_message {
+                None => Ok(()),
+                Some(message) => Err(TestAssertionFailure::create(message)),
             }
         }
     }
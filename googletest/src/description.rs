@@ -85,7 +85,7 @@ use crate::internal::description_renderer::{List, INDENTATION_SIZE};
 ///
 /// No newline is added after the last element during rendering. This makes it
 /// easier to support single-line matcher descriptions and match explanations.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Description {
     elements: List,
     initial_indentation: usize,
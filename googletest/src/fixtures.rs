@@ -214,6 +214,7 @@ mod tests {
         panic!("Test failed");
     }
 
+    #[allow(dead_code)]
     struct FailingTearDown;
 
     impl Fixture for FailingTearDown {
@@ -38,7 +38,7 @@ pub(crate) const INDENTATION_SIZE: usize = 2;
 ///     [`Block::Literal`] for each `String`.
 ///   * [`Iterator::collect()`] on an [`Iterator`] of `List`, which produces a
 ///     [`Block::Nested`] for each `List`.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct List(Vec<Block>, Decoration);
 
 impl List {
@@ -182,7 +182,7 @@ impl FromIterator<List> for List {
 /// This may be rendered with a prefix specified by the [`Decoration`] of the
 /// containing [`List`]. In this case, all lines are indented to align with the
 /// first character of the first line of the block.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Block {
     /// A block of text.
     ///
@@ -250,7 +250,7 @@ impl From<Cow<'static, str>> for Block {
 }
 
 /// A string representing one line of a description or match explanation.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Fragment(Cow<'static, str>);
 
 impl Fragment {
@@ -260,7 +260,7 @@ impl Fragment {
 }
 
 /// The decoration which appears on [`Block`] of a [`List`] when rendered.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 enum Decoration {
     /// No decoration on each [`Block`]. The default.
     #[default]
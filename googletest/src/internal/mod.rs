@@ -15,4 +15,7 @@
 #![doc(hidden)]
 
 pub(crate) mod description_renderer;
+#[cfg(test)]
+pub(crate) mod temp_dir;
 pub mod test_outcome;
+pub(crate) mod update_scope;
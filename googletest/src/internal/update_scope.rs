@@ -0,0 +1,48 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+
+thread_local! {
+    static UPDATES_ALLOWED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` with the update modes of golden file and snapshot matchers
+/// allowed or forbidden to write the actual value.
+///
+/// Assertions allow updates for the matcher they evaluate, while [`not`]
+/// forbids them for its inner matcher, whose expected value must differ from
+/// the actual value. Outside of both, as when `matches` is called directly,
+/// updates are forbidden.
+///
+/// [`not`]: crate::matchers::not
+pub(crate) fn with_updates_allowed<R>(allowed: bool, f: impl FnOnce() -> R) -> R {
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            UPDATES_ALLOWED.with(|updates_allowed| updates_allowed.set(self.0));
+        }
+    }
+
+    let _restore =
+        Restore(UPDATES_ALLOWED.with(|updates_allowed| updates_allowed.replace(allowed)));
+    f()
+}
+
+/// Returns whether the update modes of golden file and snapshot matchers may
+/// currently write the actual value.
+pub(crate) fn updates_allowed() -> bool {
+    UPDATES_ALLOWED.with(Cell::get)
+}
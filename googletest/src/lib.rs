@@ -49,12 +49,13 @@ pub use googletest_macro::{__abbreviated_stringify, __googletest_macro_verify_pr
 pub mod prelude {
     pub use super::fixtures::{ConsumableFixture, Fixture, FixtureOf, StaticFixture};
     pub use super::gtest;
-    pub use super::matcher::{Matcher, MatcherBase};
+    pub use super::matcher::{Matchable, Matcher, MatcherBase};
     pub use super::matchers::*;
     pub use super::verify_current_test_outcome;
     pub use super::GoogleTestSupport;
     pub use super::IntoTestResult;
     pub use super::Result;
+    pub use googletest_macro::matcher;
     // Assert macros
     pub use super::{
         add_failure, add_failure_at, assert_pred, assert_that, expect_eq, expect_false,
@@ -66,6 +67,7 @@ pub mod prelude {
 }
 
 pub use googletest_macro::gtest;
+pub use googletest_macro::matcher;
 pub use googletest_macro::test;
 
 use internal::test_outcome::{TestAssertionFailure, TestOutcome};
@@ -15,11 +15,14 @@
 //! The components required to implement matchers.
 
 use crate::description::Description;
-use crate::internal::test_outcome::TestAssertionFailure;
 use crate::matchers::__internal_unstable_do_not_depend_on_these::ConjunctionMatcher;
+use crate::matchers::__internal_unstable_do_not_depend_on_these::DescribedAsMatcher;
 use crate::matchers::__internal_unstable_do_not_depend_on_these::DisjunctionMatcher;
-pub use googletest_macro::MatcherBase;
-use std::fmt::Debug;
+use crate::matchers::__internal_unstable_do_not_depend_on_these::ExplainedByMatcher;
+use crate::matchers::__internal_unstable_do_not_depend_on_these::MapActualMatcher;
+use crate::matchers::BoxedMatcher;
+pub use googletest_macro::{Matchable, MatcherBase};
+use std::{borrow::Cow, fmt::Debug};
 
 /// An interface for checking an arbitrary condition on a datum.
 ///
@@ -32,11 +35,16 @@ use std::fmt::Debug;
 /// on, up to 12 elements. Tuples longer than that do not automatically inherit
 /// the `Debug` trait from their members, so are generally not well-supported;
 /// see [Rust by Example](https://doc.rust-lang.org/rust-by-example/primitives/tuples.html#tuples).
-
-// `ActualT` requires `Copy` so that `actual` could be passed to `matches` and
-// if it fails passed to `explain_match`. We can relax this constraint later by
-// requiring only `Clone`.
-pub trait Matcher<ActualT: Debug + Copy>: MatcherBase {
+///
+/// The actual value is passed by value and only needs to implement `Clone`, so
+/// matchers can be applied to owned values such as `String`, iterators, or the
+/// output of [`result_of!`][crate::matchers::result_of]. Composite matchers
+/// clone the actual value whenever they need to hand it to more than one inner
+/// matcher. Matchers written against the former `Copy` bound keep working,
+/// since every `Copy` type is also `Clone`.
+// `ActualT` requires `Clone` so that `actual` could be passed to `matches` and
+// if it fails passed to `explain_match`.
+pub trait Matcher<ActualT: Debug + Clone>: MatcherBase {
     /// Returns whether the condition matches the datum `actual`.
     ///
     /// The trait implementation defines what it means to "match". Often the
@@ -147,6 +155,53 @@ pub trait Matcher<ActualT: Debug + Copy>: MatcherBase {
     fn explain_match(&self, actual: ActualT) -> Description {
         format!("which {}", self.describe(self.matches(actual))).into()
     }
+
+    /// Evaluates whether this matcher matches `actual` and passes the result
+    /// to `then`, together with a function rendering the explanation of the
+    /// match.
+    ///
+    /// Unlike calling [`matches`][Self::matches] and then
+    /// [`explain_match`][Self::explain_match], this evaluates the matcher only
+    /// once. This matters for matchers which call user code, such as
+    /// [`result_of!`][crate::matchers::result_of] and
+    /// [`property!`][crate::matchers::property]: functions with side effects or
+    /// expensive computations are called only once per assertion. The
+    /// explanation is rendered only if `then` calls the function it receives,
+    /// so that a successful match doesn't pay for it.
+    ///
+    /// The explanation is passed to a callback rather than returned since it
+    /// may borrow values computed during the evaluation, such as the result of
+    /// a projection.
+    ///
+    /// The default implementation calls [`matches`][Self::matches], and
+    /// [`explain_match`][Self::explain_match] when the explanation is
+    /// rendered. Matchers with inner matchers should override it to call
+    /// `match_and_explain` on their inner matchers. For example, the
+    /// implementation of [`some`][crate::matchers::some] is:
+    ///
+    /// ```ignore
+    /// fn match_and_explain(
+    ///     &self,
+    ///     actual: Option<T>,
+    ///     then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    /// ) {
+    ///     match actual {
+    ///         Some(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+    ///             then(result, &|| {
+    ///                 Description::new().text("which has a value").nested(explanation())
+    ///             })
+    ///         }),
+    ///         None => then(MatcherResult::NoMatch, &|| "which is None".into()),
+    ///     }
+    /// }
+    /// ```
+    fn match_and_explain(
+        &self,
+        actual: ActualT,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        then(self.matches(actual.clone()), &|| self.explain_match(actual.clone()))
+    }
 }
 
 /// Base trait for matchers. Any type implementing `Matcher` must implement
@@ -218,38 +273,238 @@ pub trait MatcherBase {
     {
         DisjunctionMatcher::new(self, right)
     }
+
+    /// Constructs a matcher which applies `mapper` to the actual value and
+    /// matches the result against `self`.
+    ///
+    /// The `label` names the mapped value in the description of the matcher,
+    /// which reads "whose `label` ..." followed by the description of `self`.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(vec![1, 2, 3], eq(3).map_actual("length", |v: &Vec<i32>| v.len()))?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!(vec![1, 2], eq(3).map_actual("length", |v: &Vec<i32>| v.len()))?; // Fails
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    ///
+    /// The second assertion fails with:
+    ///
+    /// ```text
+    /// Expected: whose length is equal to 3
+    /// Actual: [1, 2],
+    ///   whose length is 2, which isn't equal to 3
+    /// ```
+    fn map_actual<MapperT>(
+        self,
+        label: &'static str,
+        mapper: MapperT,
+    ) -> MapActualMatcher<Self, MapperT>
+    where
+        Self: Sized,
+    {
+        MapActualMatcher::new(self, label, mapper)
+    }
+
+    /// Constructs a matcher which matches like `self` but is described by
+    /// `description`.
+    ///
+    /// The explanation of a mismatch is still provided by `self`. The
+    /// description of the matcher not matching is derived from
+    /// `description`: "is ..." becomes "isn't ...", "has ..." becomes "doesn't
+    /// have ...", and anything else is prefixed with "not".
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// let is_valid_port = || all![ge(1024), le(65535)].described_as("is a valid port");
+    /// verify_that!(8080, is_valid_port())?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// # let is_valid_port = || all![ge(1024), le(65535)].described_as("is a valid port");
+    /// verify_that!(80, is_valid_port())?; // Fails: "Expected: is a valid port"
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    fn described_as(self, description: impl Into<Cow<'static, str>>) -> DescribedAsMatcher<Self>
+    where
+        Self: Sized,
+    {
+        DescribedAsMatcher::new(self, description.into())
+    }
+
+    /// Constructs a matcher which matches and is described like `self`, but
+    /// whose explanation of the actual value is the output of `explanation`.
+    ///
+    /// The `explanation` returns anything convertible into a [`Description`],
+    /// such as a `String`. By convention, it starts with a relative clause
+    /// like "which ...".
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!(
+    ///     5,
+    ///     eq(4).explained_by(|actual: i32| format!("which is off by {}", actual - 4))
+    /// )?; // Fails: "which is off by 1"
+    /// #     Ok(())
+    /// # }
+    /// # should_fail().unwrap_err();
+    /// ```
+    fn explained_by<ExplanationT>(
+        self,
+        explanation: ExplanationT,
+    ) -> ExplainedByMatcher<Self, ExplanationT>
+    where
+        Self: Sized,
+    {
+        ExplainedByMatcher::new(self, explanation)
+    }
+
+    /// Erases the concrete type of `self` by boxing it into a [`BoxedMatcher`].
+    ///
+    /// This allows storing matchers of different types in the same collection
+    /// and choosing between them at runtime.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// let matchers: Vec<BoxedMatcher<i32>> = vec![eq(2).boxed(), lt(5).boxed()];
+    /// verify_that!(2, all![..matchers])?;  // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    fn boxed<'a, T: Debug + Clone>(self) -> BoxedMatcher<'a, T>
+    where
+        Self: Matcher<T> + Sized + 'a,
+    {
+        BoxedMatcher::new(self)
+    }
+}
+
+/// Types for which a matcher can be built field by field.
+///
+/// This trait should be implemented through `#[derive(Matchable)]`. For a
+/// struct `MyStruct`, the derive generates a matcher type `MyStructMatcher`
+/// with one builder method per field, each taking a matcher for a reference to
+/// that field. Fields of tuple structs are named `_0`, `_1`, and so on. The
+/// matcher matches a reference to `MyStruct` when every field matcher matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug, Matchable)]
+/// struct MyStruct {
+///     a_field: String,
+///     another_field: u32,
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// let value = MyStruct { a_field: "Something".into(), another_field: 123 };
+/// verify_that!(
+///     value,
+///     MyStruct::matcher().a_field(starts_with("Some")).another_field(gt(&100))
+/// )?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// For an enum `MyEnum`, [`Matchable::matcher`] returns a value with one
+/// method per variant, named after the variant in snake case. Each returns a
+/// matcher `MyEnum<Variant>Matcher` for that variant with one builder method
+/// per field of the variant:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug, Matchable)]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Rectangle(f64, f64),
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Shape::Rectangle(1.0, 2.0), Shape::matcher().rectangle()._1(gt(&1.5)))?;
+/// verify_that!(Shape::Circle { radius: 1.0 }, not(Shape::matcher().rectangle()))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// When the actual value does not match, the failure message lists each
+/// mismatching field. Since field matchers can themselves be generated
+/// matchers, the explanation of a nested struct names the full path to the
+/// mismatching field.
+pub trait Matchable {
+    /// The matcher, or for enums the collection of variant matchers, returned
+    /// by [`Matchable::matcher`].
+    type Matcher<'a>
+    where
+        Self: 'a;
+
+    /// Returns a matcher for `Self` without any field expectations.
+    fn matcher<'a>() -> Self::Matcher<'a>
+    where
+        Self: 'a;
 }
 
 /// Any actual value whose debug length is greater than this value will be
 /// pretty-printed. Otherwise, it will have normal debug output formatting.
 const PRETTY_PRINT_LENGTH_THRESHOLD: usize = 60;
 
-/// Constructs a [`TestAssertionFailure`] reporting that the given `matcher`
-/// does not match the value `actual`.
+/// Formats the message of a
+/// [`TestAssertionFailure`][crate::internal::test_outcome::TestAssertionFailure]
+/// reporting that the given
+/// `matcher` does not match the value `actual`, with the explanation rendered
+/// by `explanation`.
 ///
 /// The parameter `actual_expr` contains the expression which was evaluated to
-/// obtain `actual`.
-#[track_caller]
-pub(crate) fn create_assertion_failure<T: Debug + Copy>(
+/// obtain `actual`. The actual value is formatted before the explanation is
+/// rendered.
+pub(crate) fn assertion_failure_message<T: Debug + Clone>(
     matcher: &impl Matcher<T>,
-    actual: T,
+    actual: &T,
+    explanation: &dyn Fn() -> Description,
     actual_expr: &'static str,
-) -> TestAssertionFailure {
+) -> String {
     let actual_formatted = format!("{actual:?}");
     let actual_formatted = if actual_formatted.len() > PRETTY_PRINT_LENGTH_THRESHOLD {
         format!("{actual:#?}")
     } else {
         actual_formatted
     };
-    TestAssertionFailure::create(format!(
+    format!(
         "\
 Value of: {actual_expr}
 Expected: {}
 Actual: {actual_formatted},
 {}",
         matcher.describe(MatcherResult::Match),
-        matcher.explain_match(actual).indent(),
-    ))
+        explanation().indent(),
+    )
+}
+
+/// Returns the explanation of `matcher` for `actual`, rendered through
+/// [`Matcher::match_and_explain`].
+///
+/// Matchers overriding `match_and_explain` can implement
+/// [`Matcher::explain_match`] with this function.
+pub(crate) fn explain_with_match_and_explain<T: Debug + Clone>(
+    matcher: &(impl Matcher<T> + ?Sized),
+    actual: T,
+) -> Description {
+    let mut description = None;
+    matcher.match_and_explain(actual, &mut |_, explanation| description = Some(explanation()));
+    description.expect("match_and_explain must pass its result to the callback")
 }
 
 /// The result of applying a [`Matcher`] on an actual value.
@@ -293,7 +548,7 @@ impl MatcherResult {
 
 impl<M: ?Sized + MatcherBase> MatcherBase for &M {}
 
-impl<T: Debug + Copy, M: Matcher<T>> Matcher<T> for &M {
+impl<T: Debug + Clone, M: Matcher<T>> Matcher<T> for &M {
     fn matches(&self, actual: T) -> MatcherResult {
         (*self).matches(actual)
     }
@@ -305,10 +560,19 @@ impl<T: Debug + Copy, M: Matcher<T>> Matcher<T> for &M {
     fn explain_match(&self, actual: T) -> Description {
         (*self).explain_match(actual)
     }
+
+    fn match_and_explain(
+        &self,
+        actual: T,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        (*self).match_and_explain(actual, then)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::matcher::MatcherResult;
     use crate::prelude::*;
 
     #[test]
@@ -325,4 +589,54 @@ mod tests {
 
         verify_that!([2, 3, 4, 5], [&matcher, &matcher, &matcher, &matcher])
     }
+
+    #[test]
+    fn matcher_matches_owned_non_copy_value() -> Result<()> {
+        let matcher = some(starts_with("A"));
+
+        verify_that!(matcher.matches(Some("A string".to_string())), eq(MatcherResult::Match))
+    }
+
+    #[test]
+    fn matcher_explains_owned_non_copy_value() -> Result<()> {
+        let matcher = eq("A string").and(contains_substring("other"));
+
+        verify_that!(
+            matcher.explain_match("A string".to_string()),
+            displays_as(eq("which does not contain a substring \"other\""))
+        )
+    }
+
+    #[test]
+    fn container_matchers_match_owned_iterator() -> Result<()> {
+        verify_that!(
+            3,
+            result_of!(|n: i32| (1..=n).map(|x| x * 2), elements_are![eq(2), eq(4), eq(6)])
+        )
+    }
+
+    #[test]
+    fn match_and_explain_passes_result_and_explanation() -> Result<()> {
+        let mut outcome = None;
+
+        eq(1).match_and_explain(2, &mut |result, explanation| {
+            outcome = Some((result, explanation().to_string()))
+        });
+
+        verify_that!(outcome, some((eq(&MatcherResult::NoMatch), eq("which isn't equal to 1"))))
+    }
+
+    #[test]
+    fn match_and_explain_inverts_result_through_not() -> Result<()> {
+        let mut outcome = None;
+
+        not(some(eq(1))).match_and_explain(Some(1), &mut |result, explanation| {
+            outcome = Some((result, explanation().to_string()))
+        });
+
+        verify_that!(
+            outcome,
+            some((eq(&MatcherResult::NoMatch), eq("which has a value\n  which is equal to 1")))
+        )
+    }
 }
@@ -21,6 +21,15 @@ use std::fmt::Debug;
 /// quadratically increasing its worst-case runtime.
 const MAX_DISTANCE: i32 = 50;
 
+/// Maximum number of edits [`large_edit_list`] searches for in each part into
+/// which it divides the inputs. Beyond that, it divides the part at the point
+/// furthest from its start or end reached so far, which may not lie on an
+/// edit list of minimal length.
+///
+/// This bounds the work of [`large_edit_list`] for each division of the inputs
+/// to roughly the square of this limit, plus the length of the inputs.
+const MAX_LARGE_DISTANCE: isize = 256;
+
 /// The difference between two inputs as produced by [`edit_list`].
 #[derive(Debug)]
 pub(crate) enum Difference<T> {
@@ -33,8 +42,9 @@ pub(crate) enum Difference<T> {
     /// Contains the list of [`Edit`] to perform the transformation.
     Editable(Vec<Edit<T>>),
 
-    /// More than [`MAX_DISTANCE`] edits are required to convert one input to
-    /// the other.
+    /// The inputs have no elements in common, or, in modes other than
+    /// [`Mode::Exact`], more than [`MAX_DISTANCE`] edits are required to convert
+    /// one input to the other.
     ///
     /// The inputs are therefore considered unrelated and no edit list is
     /// provided.
@@ -92,7 +102,11 @@ pub(crate) enum Mode {
 /// [edit distance](https://en.wikipedia.org/wiki/Edit_distance)
 /// of at most [`MAX_DISTANCE`], this returns [`Difference::Editable`] with the
 /// sequence of [`Edit`] which can be applied to `actual` to obtain `expected`.
-/// Otherwise this returns [`Difference::Unrelated`].
+///
+/// If the edit distance is larger, then in [`Mode::Exact`] this falls back to
+/// [`large_edit_list`], which still returns [`Difference::Editable`] unless
+/// the inputs have no elements in common. In the other modes, this returns
+/// [`Difference::Unrelated`].
 ///
 /// This uses [Myers Algorithm](https://neil.fraser.name/writing/diff/myers.pdf)
 /// with a maximum edit distance of [`MAX_DISTANCE`]. Thus the worst-case
@@ -226,9 +240,185 @@ pub(crate) fn edit_list<T: PartialEq + Copy>(
         paths_last = paths_current;
     }
 
+    if matches!(mode, Mode::Exact) {
+        let edits = large_edit_list(&actual, &expected);
+        if edits.iter().any(|edit| matches!(edit, Edit::Both(_))) {
+            return Difference::Editable(edits);
+        }
+    }
     Difference::Unrelated
 }
 
+/// Computes an edit list of `actual` and `expected` with the linear space
+/// variant of [Myers Algorithm](https://neil.fraser.name/writing/diff/myers.pdf).
+///
+/// This divides the inputs at the middle of an edit list of minimal length and
+/// recurses into both halves. Unlike [`edit_list`], the memory use does not
+/// depend on the edit distance, so this is suitable for large inputs which
+/// differ a lot.
+///
+/// If more than [`MAX_LARGE_DISTANCE`] edits are required for a part of the
+/// inputs, then the result may not be minimal.
+fn large_edit_list<T: PartialEq + Copy>(actual: &[T], expected: &[T]) -> Vec<Edit<T>> {
+    let mut edits = Vec::with_capacity(actual.len().max(expected.len()));
+    push_large_edit_list(actual, expected, &mut edits);
+    edits
+}
+
+fn push_large_edit_list<T: PartialEq + Copy>(
+    actual: &[T],
+    expected: &[T],
+    edits: &mut Vec<Edit<T>>,
+) {
+    let prefix_len = actual.iter().zip(expected).take_while(|(a, e)| a == e).count();
+    edits.extend(actual[..prefix_len].iter().copied().map(Edit::Both));
+    let (actual, expected) = (&actual[prefix_len..], &expected[prefix_len..]);
+    let suffix_len =
+        actual.iter().rev().zip(expected.iter().rev()).take_while(|(a, e)| a == e).count();
+    let suffix = &actual[actual.len() - suffix_len..];
+    let (actual, expected) =
+        (&actual[..actual.len() - suffix_len], &expected[..expected.len() - suffix_len]);
+
+    if actual.is_empty() || expected.is_empty() {
+        edits.extend(actual.iter().copied().map(Edit::ExtraActual));
+        edits.extend(expected.iter().copied().map(Edit::ExtraExpected));
+    } else if let Some((actual_split, expected_split)) = middle_snake(actual, expected) {
+        push_large_edit_list(&actual[..actual_split], &expected[..expected_split], edits);
+        push_large_edit_list(&actual[actual_split..], &expected[expected_split..], edits);
+    } else {
+        // This can only happen if the search found no point at all, in which
+        // case the elements are replaced. They interleave, as in edit_list.
+        for i in 0..actual.len().max(expected.len()) {
+            edits.extend(actual.get(i).copied().map(Edit::ExtraActual));
+            edits.extend(expected.get(i).copied().map(Edit::ExtraExpected));
+        }
+    }
+    edits.extend(suffix.iter().copied().map(Edit::Both));
+}
+
+/// Returns a point at which a minimal edit list of `actual` and `expected`
+/// can be divided, such that both parts are strictly smaller than the inputs.
+///
+/// The inputs must be non-empty and differ in their first and in their last
+/// elements. If more than [`MAX_LARGE_DISTANCE`] edits are required, this
+/// returns the point furthest from the start or the end reached by the search.
+/// This returns `None` if there is no such point strictly inside the inputs.
+fn middle_snake<T: PartialEq>(actual: &[T], expected: &[T]) -> Option<(usize, usize)> {
+    let (n, m) = (actual.len() as isize, expected.len() as isize);
+    let delta = n - m;
+    let max_d = ((n + m + 1) / 2).min(MAX_LARGE_DISTANCE);
+    // The furthest reaching x on each diagonal k = x - y, starting from the
+    // beginning in forward and from the end in backward. The backward search
+    // works on the reversed inputs. Unreached diagonals hold -1.
+    let mut forward = vec![-1; 2 * max_d as usize + 3];
+    let mut backward = forward.clone();
+    let index = |k: isize| (k + max_d + 1) as usize;
+    forward[index(1)] = 0;
+    backward[index(1)] = 0;
+
+    for d in 0..=max_d {
+        for k in (-d..=d).step_by(2) {
+            let Some((start, end)) =
+                extend_diagonal(&mut forward, index, k, n, m, |x, y| actual[x] == expected[y])
+            else {
+                continue;
+            };
+            let k_backward = delta - k;
+            if delta % 2 != 0
+                && (-(d - 1)..=d - 1).contains(&k_backward)
+                && backward[index(k_backward)] >= 0
+                && end + backward[index(k_backward)] >= n
+            {
+                return split_point(start, start - k, end, end - k, n, m);
+            }
+        }
+        for k in (-d..=d).step_by(2) {
+            let Some((start, end)) = extend_diagonal(&mut backward, index, k, n, m, |x, y| {
+                actual[(n - 1) as usize - x] == expected[(m - 1) as usize - y]
+            }) else {
+                continue;
+            };
+            let k_forward = delta - k;
+            if delta % 2 == 0
+                && (-d..=d).contains(&k_forward)
+                && forward[index(k_forward)] >= 0
+                && forward[index(k_forward)] + end >= n
+            {
+                return split_point(n - end, m - (end - k), n - start, m - (start - k), n, m);
+            }
+        }
+    }
+
+    // The search was too expensive. The furthest reaching point is where x + y
+    // is maximal. Its diagonal k has the parity of max_d.
+    let furthest = |paths: &[isize]| {
+        (-max_d..=max_d)
+            .step_by(2)
+            .filter(|&k| paths[index(k)] >= 0)
+            .map(|k| (paths[index(k)], paths[index(k)] - k))
+            .max_by_key(|&(x, y)| x + y)
+            .unwrap_or((0, 0))
+    };
+    let (forward_x, forward_y) = furthest(&forward);
+    let (backward_x, backward_y) = furthest(&backward);
+    if forward_x + forward_y >= backward_x + backward_y {
+        split_point(forward_x, forward_y, forward_x, forward_y, n, m)
+    } else {
+        split_point(n - backward_x, m - backward_y, n - backward_x, m - backward_y, n, m)
+    }
+}
+
+// Advances the furthest reaching path on diagonal k by one edit and then
+// through all common elements. Returns the x coordinates before and after the
+// common elements, or `None` if diagonal k cannot be reached with this edit.
+fn extend_diagonal(
+    furthest: &mut [isize],
+    index: impl Fn(isize) -> usize,
+    k: isize,
+    n: isize,
+    m: isize,
+    equal: impl Fn(usize, usize) -> bool,
+) -> Option<(isize, isize)> {
+    // Moving right from diagonal k - 1 increases x. Moving down from diagonal
+    // k + 1 keeps x.
+    let right = furthest.get(index(k - 1)).copied().filter(|&x| x >= 0 && x < n).map(|x| x + 1);
+    let down = furthest.get(index(k + 1)).copied().filter(|&x| x >= 0 && x - k <= m);
+    let mut x = match (right, down) {
+        (Some(right), Some(down)) => right.max(down),
+        (Some(x), None) | (None, Some(x)) => x,
+        (None, None) => {
+            furthest[index(k)] = -1;
+            return None;
+        }
+    };
+    if x - k < 0 || x - k > m {
+        furthest[index(k)] = -1;
+        return None;
+    }
+    let start = x;
+    while x < n && x - k < m && equal(x as usize, (x - k) as usize) {
+        x += 1;
+    }
+    furthest[index(k)] = x;
+    Some((start, x))
+}
+
+// Chooses the end or start of the middle snake from (x, y) to (u, v) as the
+// point at which to split, such that both parts are smaller than the inputs.
+fn split_point(
+    x: isize,
+    y: isize,
+    u: isize,
+    v: isize,
+    n: isize,
+    m: isize,
+) -> Option<(usize, usize)> {
+    [(u, v), (x, y)]
+        .into_iter()
+        .find(|&(x, y)| x + y > 0 && x + y < n + m)
+        .map(|(x, y)| (x as usize, y as usize))
+}
+
 fn index_of_k(k: i32, k_min: i32) -> usize {
     ((k - k_min) / 2) as usize
 }
@@ -537,6 +727,56 @@ mod tests {
         verify_that!(result, matches_pattern!(&Difference::Unrelated))
     }
 
+    #[test]
+    fn returns_editable_when_maximum_distance_exceeded_with_common_elements() -> Result<()> {
+        let actual = (0..200).map(|i| if i % 3 == 0 { i } else { -i }).collect::<Vec<_>>();
+        let expected = (0..200).collect::<Vec<_>>();
+        let Difference::Editable(edits) = edit_list(actual.clone(), expected.clone(), Mode::Exact)
+        else {
+            fail!("Expected an editable difference")?;
+            unreachable!()
+        };
+        verify_that!(apply_edits_to_actual(&edits, &actual), eq(&expected))?;
+        verify_that!(edits.iter().filter(|edit| matches!(edit, Edit::Both(_))).count(), eq(67))
+    }
+
+    #[test]
+    fn returns_unrelated_in_prefix_mode_when_maximum_distance_exceeded() -> Result<()> {
+        let actual = (0..200).map(|i| if i % 3 == 0 { i } else { -i }).collect::<Vec<_>>();
+        let result = edit_list(actual, 0..200, Mode::Prefix);
+        verify_that!(result, matches_pattern!(&Difference::Unrelated))
+    }
+
+    quickcheck! {
+        fn large_edit_list_edits_actual_to_expected(
+            actual: Vec<Alphabet>,
+            expected: Vec<Alphabet>
+        ) -> bool {
+            let edits = large_edit_list(&actual, &expected);
+            apply_edits_to_actual(&edits, &actual) == expected
+                && apply_edits_to_expected(&edits, &expected) == actual
+        }
+    }
+
+    quickcheck! {
+        fn large_edit_list_is_no_longer_than_edit_list(
+            actual: Vec<Alphabet>,
+            expected: Vec<Alphabet>
+        ) -> TestResult {
+            let count_edits = |edits: &[Edit<Alphabet>]| {
+                edits.iter().filter(|edit| !matches!(edit, Edit::Both(_))).count()
+            };
+            match edit_list(actual.clone(), expected.clone(), Mode::Exact) {
+                Difference::Editable(edits) if count_edits(&edits) <= MAX_DISTANCE as usize => {
+                    TestResult::from_bool(
+                        count_edits(&large_edit_list(&actual, &expected)) <= count_edits(&edits),
+                    )
+                }
+                _ => TestResult::discard(),
+            }
+        }
+    }
+
     quickcheck! {
         fn edit_list_edits_actual_to_expected(
             actual: Vec<Alphabet>,
@@ -0,0 +1,63 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+/// A collection mapping keys to values, which the map matchers such as
+/// [`has_entry`][crate::matchers::has_entry],
+/// [`has_key`][crate::matchers::has_key], and
+/// [`has_value`][crate::matchers::has_value] can inspect.
+///
+/// This is implemented for [`HashMap`] and [`BTreeMap`]. Implement it for other
+/// map types to use them with these matchers.
+pub trait MapLike {
+    /// The type of the keys of the map.
+    type Key;
+
+    /// The type of the values of the map.
+    type Value;
+
+    /// Returns the value to which `key` is mapped, if any.
+    fn lookup(&self, key: &Self::Key) -> Option<&Self::Value>;
+
+    /// Returns an iterator over all entries of the map.
+    fn entries(&self) -> Box<dyn Iterator<Item = (&Self::Key, &Self::Value)> + '_>;
+}
+
+impl<KeyT: Eq + Hash, ValueT, S: BuildHasher> MapLike for HashMap<KeyT, ValueT, S> {
+    type Key = KeyT;
+    type Value = ValueT;
+
+    fn lookup(&self, key: &KeyT) -> Option<&ValueT> {
+        self.get(key)
+    }
+
+    fn entries(&self) -> Box<dyn Iterator<Item = (&KeyT, &ValueT)> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<KeyT: Ord, ValueT> MapLike for BTreeMap<KeyT, ValueT> {
+    type Key = KeyT;
+    type Value = ValueT;
+
+    fn lookup(&self, key: &KeyT) -> Option<&ValueT> {
+        self.get(key)
+    }
+
+    fn entries(&self) -> Box<dyn Iterator<Item = (&KeyT, &ValueT)> + '_> {
+        Box::new(self.iter())
+    }
+}
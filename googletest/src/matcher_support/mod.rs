@@ -21,10 +21,16 @@
 mod auto_eq;
 pub(crate) mod count_elements;
 pub(crate) mod edit_distance;
+mod map_like;
+mod negated_description;
+pub(crate) mod structural_diff;
 pub(crate) mod summarize_diff;
 pub(crate) mod zipped_iterator;
 
+pub use map_like::MapLike;
+
 pub mod __internal_unstable_do_not_depend_on_these {
     pub use super::auto_eq::internal::{ExpectedKind, Wrapper};
+    pub use super::negated_description::negated_description;
     pub use crate::__auto_eq as auto_eq;
 }
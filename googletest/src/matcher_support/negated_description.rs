@@ -0,0 +1,70 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Derives the description of a matcher not matching from the description
+/// `description` of it matching.
+///
+/// Descriptions of the form "is ...", "has ...", "contains ..." and
+/// "matches ..." are negated by negating their verb. Any other description is
+/// prefixed with "not".
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub fn negated_description(description: &str) -> String {
+    let (verb, rest) = description.split_once(' ').unwrap_or((description, ""));
+    let negated_verb = match verb {
+        "is" => "isn't",
+        "has" => "doesn't have",
+        "contains" => "doesn't contain",
+        "matches" => "doesn't match",
+        _ => return format!("not {description}"),
+    };
+    if rest.is_empty() {
+        negated_verb.to_string()
+    } else {
+        format!("{negated_verb} {rest}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::negated_description;
+    use crate::prelude::*;
+
+    #[test]
+    fn negates_is() -> Result<()> {
+        verify_that!(negated_description("is even"), eq("isn't even"))
+    }
+
+    #[test]
+    fn negates_has() -> Result<()> {
+        verify_that!(negated_description("has length 3"), eq("doesn't have length 3"))
+    }
+
+    #[test]
+    fn negates_contains_and_matches() -> Result<()> {
+        verify_that!(negated_description("contains 3"), eq("doesn't contain 3"))?;
+        verify_that!(negated_description("matches [a-z]+"), eq("doesn't match [a-z]+"))
+    }
+
+    #[test]
+    fn negates_lone_verb() -> Result<()> {
+        verify_that!(negated_description("is"), eq("isn't"))
+    }
+
+    #[test]
+    fn prefixes_other_descriptions_with_not() -> Result<()> {
+        verify_that!(negated_description("a valid port"), eq("not a valid port"))
+    }
+}
@@ -0,0 +1,670 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher_support::edit_distance;
+
+/// Returns a list of the differences between two values, each given by its
+/// path in the values, or `None` if the values are not suited to this form.
+///
+/// Both arguments must be the output of the alternate [`Debug`] formatting
+/// `{:#?}`. This parses them into trees of structs, tuples, sequences, sets,
+/// and maps. A difference is reported at the deepest path at which the values
+/// differ, such as `.servers[2].port`. Elements inserted into or removed from
+/// sequences are aligned, and map entries are compared by key.
+///
+/// Elements of sequences are designated by their index in the actual value.
+/// When the element of the expected value has a different index, or is
+/// missing from the actual value, its index is noted as in
+/// `.servers[2] (expected[3])`.
+///
+/// This returns `None` if either value cannot be parsed, if the values are of
+/// different shapes, or if neither value contains any nested structure. In
+/// that case, a line diff is at least as informative.
+pub(crate) fn create_structural_diff(actual_debug: &str, expected_debug: &str) -> Option<String> {
+    let actual = DebugTree::parse(actual_debug)?;
+    let expected = DebugTree::parse(expected_debug)?;
+    if !actual.has_same_shape(&expected) || actual.depth().max(expected.depth()) < 2 {
+        return None;
+    }
+    let mut differences = vec![];
+    diff(String::new(), &actual, &expected, &mut differences);
+    if differences.is_empty() {
+        return None;
+    }
+    let mut output = "\nDifferences by path:".to_string();
+    for difference in differences.iter().take(MAX_DIFFERENCES) {
+        output.push_str("\n  ");
+        output.push_str(difference);
+    }
+    if differences.len() > MAX_DIFFERENCES {
+        output.push_str(&format!(
+            "\n  <---- {} more differences omitted ---->",
+            differences.len() - MAX_DIFFERENCES
+        ));
+    }
+    Some(output)
+}
+
+// The number of differences listed before the rest are omitted.
+const MAX_DIFFERENCES: usize = 20;
+
+// Values whose single line rendering is longer than this are abbreviated.
+const MAX_VALUE_WIDTH: usize = 60;
+
+// The indentation of each level of nesting in the alternate Debug output.
+const INDENTATION: usize = 4;
+
+#[derive(Debug, PartialEq)]
+enum DebugTree<'a> {
+    Leaf(&'a str),
+    Composite { opening: &'a str, kind: Kind, children: Vec<(Option<&'a str>, DebugTree<'a>)> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    // `Name {`, with children keyed by field name.
+    Struct,
+    // `(` or `Name(`.
+    Tuple,
+    // `[`.
+    Sequence,
+    // `{`, with children keyed by the Debug output of the map key.
+    Map,
+    // `{`, with unkeyed children.
+    Set,
+}
+
+impl<'a> DebugTree<'a> {
+    fn parse(debug: &'a str) -> Option<Self> {
+        let lines = debug.lines().collect::<Vec<_>>();
+        let mut position = 0;
+        let tree = parse_value(&lines, &mut position, 0, lines.first()?)?;
+        (position == lines.len() && !debug.ends_with(',')).then_some(tree)
+    }
+
+    fn has_same_shape(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                DebugTree::Composite { opening, kind, .. },
+                DebugTree::Composite { opening: other_opening, kind: other_kind, .. },
+            ) => opening == other_opening && kind == other_kind,
+            _ => false,
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match self {
+            DebugTree::Leaf(_) => 0,
+            DebugTree::Composite { children, .. } => {
+                1 + children.iter().map(|(_, child)| child.depth()).max().unwrap_or(0)
+            }
+        }
+    }
+
+    // Renders the tree on a single line, as the non-alternate Debug output
+    // would.
+    fn render(&self) -> String {
+        match self {
+            DebugTree::Leaf(value) => value.to_string(),
+            DebugTree::Composite { opening, kind, children } => {
+                let children = children
+                    .iter()
+                    .map(|(key, child)| match key {
+                        Some(key) => format!("{key}: {}", child.render()),
+                        None => child.render(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match kind {
+                    Kind::Struct => format!("{opening} {children} }}"),
+                    Kind::Tuple => format!("{opening}{children})"),
+                    Kind::Sequence => format!("[{children}]"),
+                    Kind::Map | Kind::Set => format!("{{{children}}}"),
+                }
+            }
+        }
+    }
+
+    // Renders the tree on a single line, abbreviating composites which are too
+    // long.
+    fn render_abbreviated(&self) -> String {
+        let rendered = self.render();
+        match self {
+            DebugTree::Composite { opening, kind, .. }
+                if rendered.chars().count() > MAX_VALUE_WIDTH =>
+            {
+                match kind {
+                    Kind::Struct => format!("{opening} .. }}"),
+                    Kind::Tuple => format!("{opening}..)"),
+                    Kind::Sequence => "[..]".into(),
+                    Kind::Map | Kind::Set => "{..}".into(),
+                }
+            }
+            _ => rendered,
+        }
+    }
+}
+
+// Parses the value starting with `content` on the line at `position`, which
+// is indented by `indent`, and advances `position` past its last line.
+fn parse_value<'a>(
+    lines: &[&'a str],
+    position: &mut usize,
+    indent: usize,
+    content: &'a str,
+) -> Option<DebugTree<'a>> {
+    *position += 1;
+    let (kind, closing) = if content == "[" {
+        (Kind::Sequence, "]")
+    } else if content == "{" {
+        // Whether this is a map or a set is only apparent from its entries.
+        (Kind::Set, "}")
+    } else if content.ends_with(" {") && is_type_name(&content[..content.len() - 2]) {
+        (Kind::Struct, "}")
+    } else if content.ends_with('(') && is_type_name(&content[..content.len() - 1]) {
+        (Kind::Tuple, ")")
+    } else {
+        return Some(DebugTree::Leaf(content));
+    };
+
+    let mut kind = kind;
+    let mut children = vec![];
+    loop {
+        let line = lines.get(*position)?;
+        let rest = line.strip_prefix(&" ".repeat(indent)).unwrap_or_default();
+        if rest.strip_suffix(',').unwrap_or(rest) == closing {
+            *position += 1;
+            return Some(DebugTree::Composite { opening: content, kind, children });
+        }
+        let child = line.strip_prefix(&" ".repeat(indent + INDENTATION))?;
+        if child.starts_with(' ') {
+            return None;
+        }
+        let (key, child) = match kind {
+            Kind::Struct => {
+                let (name, value) = child.split_once(": ")?;
+                is_identifier(name).then_some((Some(name), value))?
+            }
+            Kind::Map => split_map_key(child).map(|(key, value)| (Some(key), value))?,
+            Kind::Set if children.is_empty() => match split_map_key(child) {
+                Some((key, value)) => {
+                    kind = Kind::Map;
+                    (Some(key), value)
+                }
+                None => (None, child),
+            },
+            Kind::Tuple | Kind::Sequence | Kind::Set => (None, child),
+        };
+        let child = parse_child(lines, position, indent + INDENTATION, child)?;
+        children.push((key, child));
+    }
+}
+
+// Parses an entry of a composite, which is followed by a comma on its last
+// line.
+fn parse_child<'a>(
+    lines: &[&'a str],
+    position: &mut usize,
+    indent: usize,
+    content: &'a str,
+) -> Option<DebugTree<'a>> {
+    if let Some(leaf) = content.strip_suffix(',') {
+        if !matches!(leaf.chars().last(), Some('{' | '(' | '[')) {
+            *position += 1;
+            return Some(DebugTree::Leaf(leaf));
+        }
+    }
+    let start = *position;
+    let tree = parse_value(lines, position, indent, content)?;
+    // The closing line of a nested composite carries the comma.
+    if *position == start + 1 || !lines[*position - 1].ends_with(',') {
+        return None;
+    }
+    Some(tree)
+}
+
+// Splits a line of a map into the Debug output of the key and the value, if
+// the key fits on a single line.
+fn split_map_key(line: &str) -> Option<(&str, &str)> {
+    let key_length = if line.starts_with('"') {
+        // Skip over the string literal, which may contain ": ".
+        let mut escaped = false;
+        let end = line.char_indices().skip(1).find(|&(_, c)| {
+            let is_end = c == '"' && !escaped;
+            escaped = c == '\\' && !escaped;
+            is_end
+        })?;
+        end.0 + 1
+    } else {
+        line.find(": ")?
+    };
+    let value = line[key_length..].strip_prefix(": ")?;
+    Some((&line[..key_length], value))
+}
+
+fn is_identifier(name: &str) -> bool {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+// Whether `name` can precede the opening bracket of a struct or a tuple. It is
+// empty for tuples.
+fn is_type_name(name: &str) -> bool {
+    name.is_empty() || name.split("::").all(is_identifier)
+}
+
+fn diff(path: String, actual: &DebugTree, expected: &DebugTree, differences: &mut Vec<String>) {
+    if actual == expected {
+        return;
+    }
+    let (
+        DebugTree::Composite { kind, children: actual_children, .. },
+        DebugTree::Composite { children: expected_children, .. },
+    ) = (actual, expected)
+    else {
+        differences.push(changed(&path, actual, expected));
+        return;
+    };
+    if !actual.has_same_shape(expected) {
+        differences.push(changed(&path, actual, expected));
+        return;
+    }
+    match kind {
+        Kind::Struct | Kind::Tuple => {
+            let same_fields = actual_children.len() == expected_children.len()
+                && actual_children.iter().zip(expected_children).all(|(a, e)| a.0 == e.0);
+            if !same_fields {
+                differences.push(changed(&path, actual, expected));
+                return;
+            }
+            for (i, ((key, actual_child), (_, expected_child))) in
+                actual_children.iter().zip(expected_children).enumerate()
+            {
+                let child_path = match key {
+                    Some(name) => format!("{path}.{name}"),
+                    None => format!("{path}.{i}"),
+                };
+                diff(child_path, actual_child, expected_child, differences);
+            }
+        }
+        Kind::Sequence | Kind::Set => {
+            diff_sequences(&path, actual_children, expected_children, differences)
+        }
+        Kind::Map => {
+            for (key, actual_child) in actual_children {
+                let child_path = format!("{path}[{}]", key.unwrap_or_default());
+                match expected_children.iter().find(|(k, _)| k == key) {
+                    Some((_, expected_child)) => {
+                        diff(child_path, actual_child, expected_child, differences)
+                    }
+                    None => differences.push(unexpected(&child_path, actual_child)),
+                }
+            }
+            for (key, expected_child) in expected_children {
+                if !actual_children.iter().any(|(k, _)| k == key) {
+                    let child_path = format!("{path}[{}]", key.unwrap_or_default());
+                    differences.push(missing(&child_path, expected_child));
+                }
+            }
+        }
+    }
+}
+
+// Aligns the elements of two sequences, so that elements inserted or removed
+// are reported as such rather than as changes of all subsequent elements.
+//
+// Paths use the index in the actual sequence, which for a missing element is
+// the position where it would be inserted. The index in the expected sequence
+// is noted whenever it could not be told from the path.
+fn diff_sequences(
+    path: &str,
+    actual: &[(Option<&str>, DebugTree)],
+    expected: &[(Option<&str>, DebugTree)],
+    differences: &mut Vec<String>,
+) {
+    let actual_elements = actual.iter().map(|(_, child)| child);
+    let expected_elements = expected.iter().map(|(_, child)| child);
+    let edits = match edit_distance::edit_list(
+        actual_elements.clone(),
+        expected_elements.clone(),
+        edit_distance::Mode::Exact,
+    ) {
+        edit_distance::Difference::Equal => return,
+        edit_distance::Difference::Editable(edits) => edits,
+        edit_distance::Difference::Unrelated => {
+            // Nothing is in common, so compare the elements by index.
+            let mut edits = vec![];
+            for i in 0..actual.len().max(expected.len()) {
+                edits.extend(
+                    actual.get(i).map(|(_, child)| edit_distance::Edit::ExtraActual(child)),
+                );
+                edits.extend(
+                    expected.get(i).map(|(_, child)| edit_distance::Edit::ExtraExpected(child)),
+                );
+            }
+            edits
+        }
+    };
+
+    let (mut actual_index, mut expected_index) = (0, 0);
+    let mut remaining = edits.as_slice();
+    while !remaining.is_empty() {
+        let run_length =
+            remaining.iter().take_while(|e| !matches!(e, edit_distance::Edit::Both(_))).count();
+        if run_length == 0 {
+            actual_index += 1;
+            expected_index += 1;
+            remaining = &remaining[1..];
+            continue;
+        }
+        // Within a run of differing elements, pair up elements of the actual and
+        // the expected value as changes, in order.
+        let extra_actual = remaining[..run_length]
+            .iter()
+            .filter_map(|e| match e {
+                edit_distance::Edit::ExtraActual(child) => Some(*child),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let extra_expected = remaining[..run_length]
+            .iter()
+            .filter_map(|e| match e {
+                edit_distance::Edit::ExtraExpected(child) => Some(*child),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for i in 0..extra_actual.len().max(extra_expected.len()) {
+            match (extra_actual.get(i), extra_expected.get(i)) {
+                (Some(actual_child), Some(expected_child)) => {
+                    let (actual_i, expected_i) = (actual_index + i, expected_index + i);
+                    let child_path = if actual_i == expected_i {
+                        format!("{path}[{actual_i}]")
+                    } else {
+                        format!("{path}[{actual_i}] (expected[{expected_i}])")
+                    };
+                    diff(child_path, actual_child, expected_child, differences)
+                }
+                (Some(actual_child), None) => differences
+                    .push(unexpected(&format!("{path}[{}]", actual_index + i), actual_child)),
+                (None, Some(expected_child)) => differences.push(missing(
+                    &format!(
+                        "{path}[{}] (expected[{}])",
+                        actual_index + extra_actual.len(),
+                        expected_index + i
+                    ),
+                    expected_child,
+                )),
+                (None, None) => {}
+            }
+        }
+        actual_index += extra_actual.len();
+        expected_index += extra_expected.len();
+        remaining = &remaining[run_length..];
+    }
+}
+
+fn changed(path: &str, actual: &DebugTree, expected: &DebugTree) -> String {
+    format!(
+        "{}: expected {}, actual {}",
+        display_path(path),
+        expected.render_abbreviated(),
+        actual.render_abbreviated()
+    )
+}
+
+fn unexpected(path: &str, actual: &DebugTree) -> String {
+    format!("{}: unexpected {}", display_path(path), actual.render_abbreviated())
+}
+
+fn missing(path: &str, expected: &DebugTree) -> String {
+    format!("{}: missing {}", display_path(path), expected.render_abbreviated())
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "."
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::BTreeMap;
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Config {
+        name: &'static str,
+        servers: Vec<Server>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Server {
+        host: &'static str,
+        port: u16,
+    }
+
+    fn servers(ports: &[u16]) -> Vec<Server> {
+        ports.iter().map(|&port| Server { host: "localhost", port }).collect()
+    }
+
+    fn structural_diff(actual: impl std::fmt::Debug, expected: impl std::fmt::Debug) -> String {
+        create_structural_diff(&format!("{actual:#?}"), &format!("{expected:#?}"))
+            .unwrap_or_else(|| "<no structural diff>".into())
+    }
+
+    #[test]
+    fn reports_changed_field_of_nested_struct_by_path() -> Result<()> {
+        let actual = Config { name: "prod", servers: servers(&[80, 443, 8081]) };
+        let expected = Config { name: "prod", servers: servers(&[80, 443, 8080]) };
+
+        verify_that!(
+            structural_diff(actual, expected),
+            eq(indoc!(
+                "
+
+                Differences by path:
+                  .servers[2].port: expected 8080, actual 8081"
+            ))
+        )
+    }
+
+    #[test]
+    fn reports_several_differences() -> Result<()> {
+        let actual = Config { name: "dev", servers: servers(&[81, 443]) };
+        let expected = Config { name: "prod", servers: servers(&[80, 443]) };
+
+        verify_that!(
+            structural_diff(actual, expected),
+            eq(indoc!(
+                r#"
+
+                Differences by path:
+                  .name: expected "prod", actual "dev"
+                  .servers[0].port: expected 80, actual 81"#
+            ))
+        )
+    }
+
+    #[test]
+    fn aligns_element_inserted_into_sequence() -> Result<()> {
+        verify_that!(
+            structural_diff(servers(&[1, 2, 3, 4]), servers(&[1, 2, 4])),
+            eq(indoc!(
+                r#"
+
+                Differences by path:
+                  [2]: unexpected Server { host: "localhost", port: 3 }"#
+            ))
+        )
+    }
+
+    #[test]
+    fn aligns_element_removed_from_sequence() -> Result<()> {
+        verify_that!(
+            structural_diff(servers(&[1, 3, 4]), servers(&[1, 2, 3, 4])),
+            eq(indoc!(
+                r#"
+
+                Differences by path:
+                  [1] (expected[1]): missing Server { host: "localhost", port: 2 }"#
+            ))
+        )
+    }
+
+    #[test]
+    fn notes_expected_index_of_elements_after_insertion() -> Result<()> {
+        verify_that!(
+            structural_diff(servers(&[0, 1, 2, 3]), servers(&[1, 2, 4])),
+            eq(indoc!(
+                r#"
+
+                Differences by path:
+                  [0]: unexpected Server { host: "localhost", port: 0 }
+                  [3] (expected[2]).port: expected 4, actual 3"#
+            ))
+        )
+    }
+
+    #[test]
+    fn notes_expected_index_of_missing_elements() -> Result<()> {
+        verify_that!(
+            structural_diff(servers(&[1]), servers(&[1, 2, 3])),
+            eq(indoc!(
+                r#"
+
+                Differences by path:
+                  [1] (expected[1]): missing Server { host: "localhost", port: 2 }
+                  [1] (expected[2]): missing Server { host: "localhost", port: 3 }"#
+            ))
+        )
+    }
+
+    #[test]
+    fn reports_map_entries_by_key() -> Result<()> {
+        let actual = BTreeMap::from([("a: b", vec![1, 2]), ("c", vec![3])]);
+        let expected = BTreeMap::from([("a: b", vec![1, 5]), ("d", vec![3])]);
+
+        verify_that!(
+            structural_diff(actual, expected),
+            eq(indoc!(
+                r#"
+
+                Differences by path:
+                  ["a: b"][1]: expected 5, actual 2
+                  ["c"]: unexpected [3]
+                  ["d"]: missing [3]"#
+            ))
+        )
+    }
+
+    #[test]
+    fn reports_tuple_elements_by_index() -> Result<()> {
+        verify_that!(
+            structural_diff((1, (2, "x")), (1, (3, "x"))),
+            eq(indoc!(
+                "
+
+                Differences by path:
+                  .1.0: expected 3, actual 2"
+            ))
+        )
+    }
+
+    #[test]
+    fn abbreviates_long_values() -> Result<()> {
+        let actual = vec![vec!["a long string which makes the vector too long to show"; 2]];
+        let expected: Vec<Vec<&str>> = vec![vec![]];
+
+        verify_that!(
+            structural_diff(actual, expected),
+            eq(indoc!(
+                "
+
+                Differences by path:
+                  [0]: expected [], actual [..]"
+            ))
+        )
+    }
+
+    #[test]
+    fn omits_differences_beyond_maximum() -> Result<()> {
+        let actual = vec![(0..30).collect::<Vec<_>>()];
+        let expected = vec![(100..130).collect::<Vec<_>>()];
+
+        verify_that!(
+            structural_diff(actual, expected),
+            all!(
+                contains_substring("[0][19]: expected 119, actual 19"),
+                not(contains_substring("[0][20]")),
+                ends_with("<---- 10 more differences omitted ---->")
+            )
+        )
+    }
+
+    #[test]
+    fn returns_none_for_values_without_nested_structure() -> Result<()> {
+        verify_that!(structural_diff(vec![1, 2], vec![1, 3]), eq("<no structural diff>"))
+    }
+
+    #[test]
+    fn returns_none_for_values_of_different_shape() -> Result<()> {
+        verify_that!(structural_diff(vec![vec![1]], (vec![1],)), eq("<no structural diff>"))
+    }
+
+    #[test]
+    fn returns_none_for_unparsable_values() -> Result<()> {
+        verify_that!(
+            create_structural_diff(
+                "[\n    [\n        1,\n    ],\n",
+                "[\n    [\n        2,\n    ],\n]"
+            ),
+            none()
+        )
+    }
+
+    #[test]
+    fn parses_set_of_structs() -> Result<()> {
+        let tree = DebugTree::parse(indoc!(
+            "
+            {
+                Server {
+                    port: 1,
+                },
+            }"
+        ));
+
+        verify_that!(
+            tree,
+            some(eq(&DebugTree::Composite {
+                opening: "{",
+                kind: Kind::Set,
+                children: vec![(
+                    None,
+                    DebugTree::Composite {
+                        opening: "Server {",
+                        kind: Kind::Struct,
+                        children: vec![(Some("port"), DebugTree::Leaf("1"))]
+                    }
+                )]
+            }))
+        )
+    }
+}
@@ -28,6 +28,17 @@ use std::{borrow::Cow, cell::Cell, fmt::Display};
 /// the maximum edit distance, then this returns the empty string. If the two
 /// are equal, it returns a simple statement that they are equal. Otherwise,
 /// this constructs a unified diff view of the actual and expected values.
+///
+/// Within a removed line directly followed by an added line, the characters
+/// which differ are highlighted if colors are enabled. Otherwise they are
+/// marked by carets on a line starting with `?` below each of the two lines.
+///
+/// If the side-by-side style is enabled through the environment variable
+/// `GOOGLETEST_DIFF_STYLE=side-by-side` and `diff_mode` is
+/// [`edit_distance::Mode::Exact`], the actual and expected values are instead
+/// shown in two columns with line numbers. This falls back to the unified diff
+/// if the terminal, whose width is read from the environment variable
+/// `COLUMNS`, is too narrow for two columns.
 pub(crate) fn create_diff(
     actual_debug: &str,
     expected_debug: &str,
@@ -41,6 +52,15 @@ pub(crate) fn create_diff(
     match edit_distance::edit_list(actual_debug.lines(), expected_debug.lines(), diff_mode) {
         edit_distance::Difference::Equal => "No difference found between debug strings.".into(),
         edit_distance::Difference::Editable(edit_list) => {
+            if matches!(diff_mode, edit_distance::Mode::Exact) && SIDE_BY_SIDE.with(Cell::get) {
+                if let Some(side_by_side) = render_side_by_side(
+                    &edit_list,
+                    actual_debug.lines().count(),
+                    TERMINAL_WIDTH.with(Cell::get),
+                ) {
+                    return format!("\n{}{side_by_side}", summary_header()).into();
+                }
+            }
             format!("\n{}{}", summary_header(), edit_list.into_iter().collect::<BufferedSummary>(),)
                 .into()
         }
@@ -55,6 +75,9 @@ pub(crate) fn create_diff(
 /// in both the expected and actual values, then reverses the constructed edit
 /// list. When `diff_mode` is [`edit_distance::Mode::Prefix`], this becomes a
 /// diff of the suffix for use by [`ends_with`][crate::matchers::ends_with].
+///
+/// As with [`create_diff`], the diff is shown in two columns if the
+/// side-by-side style is enabled and the terminal is wide enough.
 pub(crate) fn create_diff_reversed(
     actual_debug: &str,
     expected_debug: &str,
@@ -73,6 +96,15 @@ pub(crate) fn create_diff_reversed(
         edit_distance::Difference::Equal => "No difference found between debug strings.".into(),
         edit_distance::Difference::Editable(mut edit_list) => {
             edit_list.reverse();
+            if SIDE_BY_SIDE.with(Cell::get) {
+                if let Some(side_by_side) = render_side_by_side(
+                    &edit_list,
+                    actual_debug.lines().count(),
+                    TERMINAL_WIDTH.with(Cell::get),
+                ) {
+                    return format!("\n{}{side_by_side}", summary_header()).into();
+                }
+            }
             format!("\n{}{}", summary_header(), edit_list.into_iter().collect::<BufferedSummary>(),)
                 .into()
         }
@@ -80,6 +112,366 @@ pub(crate) fn create_diff_reversed(
     }
 }
 
+/// Returns a string describing how two single-line values differ character by
+/// character.
+///
+/// This complements [`create_diff`], which only compares values line by line.
+/// The common prefix and suffix of the two values are shortened to a few
+/// characters of context. The differing span is highlighted with colors if
+/// they are enabled, and otherwise marked by carets on a line below the two
+/// values.
+///
+/// If either value has more than one line, both are short enough to be
+/// compared at a glance, or the two are equal, then this returns the empty
+/// string.
+pub(crate) fn create_inline_diff(actual_debug: &str, expected_debug: &str) -> Cow<'static, str> {
+    // Values shorter than this are printed in full next to each other anyway.
+    const MIN_INLINE_DIFF_LENGTH: usize = 20;
+
+    if actual_debug.contains('\n')
+        || expected_debug.contains('\n')
+        || actual_debug == expected_debug
+        || actual_debug.chars().count().max(expected_debug.chars().count()) < MIN_INLINE_DIFF_LENGTH
+    {
+        return "".into();
+    }
+    let actual: Vec<char> = actual_debug.chars().collect();
+    let expected: Vec<char> = expected_debug.chars().collect();
+    let prefix_len = actual.iter().zip(&expected).take_while(|(a, e)| a == e).count();
+    let suffix_len = actual[prefix_len..]
+        .iter()
+        .rev()
+        .zip(expected[prefix_len..].iter().rev())
+        .take_while(|(a, e)| a == e)
+        .count();
+    let actual_middle = &actual[prefix_len..actual.len() - suffix_len];
+    let expected_middle = &expected[prefix_len..expected.len() - suffix_len];
+    let prefix = &actual[..prefix_len];
+    let suffix = &actual[actual.len() - suffix_len..];
+
+    let mut actual_summary = SummaryBuilder::default();
+    actual_summary.new_line_for_actual();
+    let mut expected_summary = SummaryBuilder::default();
+    expected_summary.new_line_for_expected();
+
+    let prefix_width =
+        push_inline_context(&mut actual_summary, &mut expected_summary, prefix, true);
+    match edit_distance::edit_list(
+        actual_middle.iter().copied(),
+        expected_middle.iter().copied(),
+        edit_distance::Mode::Exact,
+    ) {
+        edit_distance::Difference::Editable(edit_list) => {
+            for edit in edit_list {
+                match edit {
+                    edit_distance::Edit::ExtraActual(c) => actual_summary.push_actual_only(c),
+                    edit_distance::Edit::ExtraExpected(c) => expected_summary.push_expected_only(c),
+                    edit_distance::Edit::Both(c) => {
+                        actual_summary.push_actual_with_match(c);
+                        expected_summary.push_expected_with_match(c);
+                    }
+                    edit_distance::Edit::AdditionalActual => {
+                        // Calling edit_distance::edit_list(_, _, Mode::Exact) should never return
+                        // this enum
+                        panic!("This should not happen. This is a bug in gtest_rust")
+                    }
+                }
+            }
+        }
+        _ => {
+            actual_middle.iter().for_each(|c| actual_summary.push_actual_only(*c));
+            expected_middle.iter().for_each(|c| expected_summary.push_expected_only(*c));
+        }
+    }
+    push_inline_context(&mut actual_summary, &mut expected_summary, suffix, false);
+    actual_summary.reset_ansi();
+    expected_summary.reset_ansi();
+
+    let mut summary = SummaryBuilder::default();
+    summary.push_str(&actual_summary.summary);
+    summary.push_str(&expected_summary.summary);
+    if !USE_COLOR.with(Cell::get) {
+        // Without colors, mark the differing span below the two values. An empty
+        // span, as for a pure insertion, is marked by a single caret.
+        let span_width = actual_middle.len().max(expected_middle.len()).max(1);
+        summary.new_line();
+        summary.push_str(&" ".repeat(prefix_width));
+        summary.push_str(&"^".repeat(span_width));
+    }
+    format!("\n{}{}", summary_header(), summary.summary).into()
+}
+
+// Appends the common `context` to both summaries, eliding all but the
+// characters closest to the differing span. Returns the number of characters
+// written to each summary.
+fn push_inline_context(
+    actual_summary: &mut SummaryBuilder,
+    expected_summary: &mut SummaryBuilder,
+    context: &[char],
+    is_prefix: bool,
+) -> usize {
+    // The number of common characters kept next to the differing span.
+    const INLINE_CONTEXT_SIZE: usize = 20;
+    const ELISION: &str = "...";
+
+    let elided = context.len() > INLINE_CONTEXT_SIZE + ELISION.len();
+    let kept = match (elided, is_prefix) {
+        (false, _) => context,
+        (true, true) => &context[context.len() - INLINE_CONTEXT_SIZE..],
+        (true, false) => &context[..INLINE_CONTEXT_SIZE],
+    };
+    if elided && is_prefix {
+        actual_summary.push_str_as_comment(ELISION);
+        expected_summary.push_str_as_comment(ELISION);
+    }
+    for c in kept {
+        actual_summary.push_actual_with_match(*c);
+        expected_summary.push_expected_with_match(*c);
+    }
+    if elided && !is_prefix {
+        actual_summary.push_str_as_comment(ELISION);
+        expected_summary.push_str_as_comment(ELISION);
+    }
+    kept.len() + if elided { ELISION.len() } else { 0 }
+}
+
+// The number of the common lines kept before and after compressed common
+// lines.
+const COMMON_LINES_CONTEXT_SIZE: usize = 2;
+
+// The narrowest column in which a side-by-side diff is still shown.
+const MIN_SIDE_BY_SIDE_COLUMN_WIDTH: usize = 30;
+
+// Separates the actual column from the expected column.
+const SIDE_BY_SIDE_SEPARATOR: &str = " | ";
+
+// A line of the side-by-side diff, before it is laid out in columns.
+enum SideBySideRow<'a> {
+    // A line of each value, or of only one of them, with its line number.
+    Lines { actual: Option<(usize, &'a str)>, expected: Option<(usize, &'a str)> },
+    // A comment spanning both columns.
+    Comment(String),
+}
+
+// Renders the edit list as two columns of the given total width, or returns
+// `None` if the width does not allow for two readable columns. The number of
+// lines of the actual value is needed to number its lines when some of them
+// are omitted from the edit list, as in `Mode::Prefix`.
+fn render_side_by_side(
+    edit_list: &[edit_distance::Edit<&str>],
+    actual_line_count: usize,
+    width: usize,
+) -> Option<String> {
+    let rows = side_by_side_rows(edit_list, actual_line_count);
+    let last_line_number = rows
+        .iter()
+        .filter_map(|row| match row {
+            SideBySideRow::Lines { actual, expected } => {
+                Some(actual.map_or(0, |(n, _)| n).max(expected.map_or(0, |(n, _)| n)))
+            }
+            SideBySideRow::Comment(_) => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let number_width = last_line_number.to_string().len();
+    // Each column starts with the line number, a space, the marker, and a space.
+    let gutter_width = number_width + 3;
+    let available_width = width.saturating_sub(SIDE_BY_SIDE_SEPARATOR.len() + 2 * gutter_width) / 2;
+    if available_width < MIN_SIDE_BY_SIDE_COLUMN_WIDTH {
+        return None;
+    }
+    let longest_line = rows
+        .iter()
+        .flat_map(|row| match row {
+            SideBySideRow::Lines { actual, expected } => [*actual, *expected],
+            SideBySideRow::Comment(_) => [None, None],
+        })
+        .flatten()
+        .map(|(_, line)| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let column_width = longest_line.clamp(1, available_width);
+
+    let mut output = String::new();
+    for row in rows {
+        match row {
+            SideBySideRow::Lines { actual, expected } => {
+                let (actual_marker, expected_marker) = match (actual, expected) {
+                    (Some((_, a)), Some((_, e))) if a == e => (' ', ' '),
+                    _ => ('-', '+'),
+                };
+                let actual_chunks = actual.map(|(_, line)| wrap(line, column_width));
+                let expected_chunks = expected.map(|(_, line)| wrap(line, column_width));
+                let row_count = actual_chunks
+                    .as_ref()
+                    .map_or(0, Vec::len)
+                    .max(expected_chunks.as_ref().map_or(0, Vec::len));
+                for i in 0..row_count {
+                    // Only the first row of a wrapped line shows its line number.
+                    let number = |cell: Option<(usize, &str)>| match cell {
+                        Some((n, _)) if i == 0 => n.to_string(),
+                        _ => String::new(),
+                    };
+                    output.push('\n');
+                    output.push_str(&side_by_side_cell(
+                        &number(actual),
+                        actual_marker,
+                        actual_chunks.as_ref().and_then(|chunks| chunks.get(i)),
+                        number_width,
+                        Some(column_width),
+                        ACTUAL_ONLY_STYLE,
+                    ));
+                    output.push_str(SIDE_BY_SIDE_SEPARATOR.trim_end());
+                    let expected_cell = side_by_side_cell(
+                        &number(expected),
+                        expected_marker,
+                        expected_chunks.as_ref().and_then(|chunks| chunks.get(i)),
+                        number_width,
+                        None,
+                        EXPECTED_ONLY_STYLE,
+                    );
+                    if !expected_cell.trim().is_empty() {
+                        output.push(' ');
+                        output.push_str(&expected_cell);
+                    }
+                }
+            }
+            SideBySideRow::Comment(comment) => {
+                output.push('\n');
+                output.push_str(&" ".repeat(gutter_width));
+                output.push_str(&styled(&comment, COMMENT_STYLE));
+            }
+        }
+    }
+    Some(output)
+}
+
+// Pairs up the lines of the edit list, placing lines which were replaced next
+// to each other and compressing long runs of common lines.
+fn side_by_side_rows<'a>(
+    edit_list: &[edit_distance::Edit<&'a str>],
+    actual_line_count: usize,
+) -> Vec<SideBySideRow<'a>> {
+    let listed_actual_lines = edit_list
+        .iter()
+        .filter(|edit| {
+            matches!(edit, edit_distance::Edit::ExtraActual(_) | edit_distance::Edit::Both(_))
+        })
+        .count();
+    let mut rows = vec![];
+    let (mut actual_number, mut expected_number) = (0, 0);
+    let mut remaining = edit_list;
+    while let Some(first) = remaining.first() {
+        if let edit_distance::Edit::AdditionalActual = first {
+            rows.push(SideBySideRow::Comment("<---- remaining lines omitted ---->".into()));
+            actual_number += actual_line_count.saturating_sub(listed_actual_lines);
+            remaining = &remaining[1..];
+        } else if let edit_distance::Edit::Both(_) = first {
+            let run_length = remaining
+                .iter()
+                .take_while(|edit| matches!(edit, edit_distance::Edit::Both(_)))
+                .count();
+            let omitted = if run_length > 2 * COMMON_LINES_CONTEXT_SIZE + 1 {
+                COMMON_LINES_CONTEXT_SIZE..run_length - COMMON_LINES_CONTEXT_SIZE
+            } else {
+                0..0
+            };
+            for (i, edit) in remaining[..run_length].iter().enumerate() {
+                actual_number += 1;
+                expected_number += 1;
+                if omitted.contains(&i) {
+                    if i == omitted.start {
+                        rows.push(SideBySideRow::Comment(format!(
+                            "<---- {} common lines omitted ---->",
+                            omitted.len()
+                        )));
+                    }
+                    continue;
+                }
+                if let edit_distance::Edit::Both(line) = edit {
+                    rows.push(SideBySideRow::Lines {
+                        actual: Some((actual_number, line)),
+                        expected: Some((expected_number, line)),
+                    });
+                }
+            }
+            remaining = &remaining[run_length..];
+        } else {
+            let run_length = remaining
+                .iter()
+                .take_while(|edit| {
+                    matches!(
+                        edit,
+                        edit_distance::Edit::ExtraActual(_) | edit_distance::Edit::ExtraExpected(_)
+                    )
+                })
+                .count();
+            let mut actual_lines = vec![];
+            let mut expected_lines = vec![];
+            for edit in &remaining[..run_length] {
+                match edit {
+                    edit_distance::Edit::ExtraActual(line) => {
+                        actual_number += 1;
+                        actual_lines.push((actual_number, *line));
+                    }
+                    edit_distance::Edit::ExtraExpected(line) => {
+                        expected_number += 1;
+                        expected_lines.push((expected_number, *line));
+                    }
+                    edit_distance::Edit::Both(_) | edit_distance::Edit::AdditionalActual => {}
+                }
+            }
+            for i in 0..actual_lines.len().max(expected_lines.len()) {
+                rows.push(SideBySideRow::Lines {
+                    actual: actual_lines.get(i).copied(),
+                    expected: expected_lines.get(i).copied(),
+                });
+            }
+            remaining = &remaining[run_length..];
+        }
+    }
+    rows
+}
+
+// Formats one column of a row of the side-by-side diff. If `pad_to` is set,
+// the text is padded with spaces to that width.
+fn side_by_side_cell(
+    number: &str,
+    marker: char,
+    text: Option<&String>,
+    number_width: usize,
+    pad_to: Option<usize>,
+    style: &'static str,
+) -> String {
+    let padding = pad_to.map_or(0, |width| width - text.map_or(0, |text| text.chars().count()));
+    match text {
+        Some(text) => {
+            let text = if marker == ' ' { text.clone() } else { styled(text, style).into_owned() };
+            format!("{number:>number_width$} {marker} {text}{}", " ".repeat(padding))
+        }
+        None => format!("{:number_width$}   {}", "", " ".repeat(padding)),
+    }
+}
+
+// Splits the line into chunks of at most `width` characters. An empty line
+// yields a single empty chunk.
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.is_empty() {
+        return vec![String::new()];
+    }
+    chars.chunks(width).map(|chunk| chunk.iter().collect()).collect()
+}
+
+// Wraps the text in the given style if colors are enabled.
+fn styled<'a>(text: &'a str, style: &'static str) -> Cow<'a, str> {
+    if USE_COLOR.with(Cell::get) && !text.is_empty() {
+        format!("{style}{text}{RESET_ALL}").into()
+    } else {
+        text.into()
+    }
+}
+
 // Produces the header, with or without coloring depending on
 // USE_COLOR
 fn summary_header() -> Cow<'static, str> {
@@ -159,15 +551,27 @@ impl<'a> BufferedSummary<'a> {
             actual_summary.new_line_for_actual();
             let mut expected_summary = SummaryBuilder::default();
             expected_summary.new_line_for_expected();
+            // Without colors, the characters found in only one of the lines are
+            // marked with carets on a line below it.
+            let mut actual_markers = String::new();
+            let mut expected_markers = String::new();
+            let mut common_count = 0;
             for edit in &edit_list {
                 match edit {
-                    edit_distance::Edit::ExtraActual(c) => actual_summary.push_actual_only(*c),
+                    edit_distance::Edit::ExtraActual(c) => {
+                        actual_summary.push_actual_only(*c);
+                        actual_markers.push('^');
+                    }
                     edit_distance::Edit::ExtraExpected(c) => {
-                        expected_summary.push_expected_only(*c)
+                        expected_summary.push_expected_only(*c);
+                        expected_markers.push('^');
                     }
                     edit_distance::Edit::Both(c) => {
                         actual_summary.push_actual_with_match(*c);
                         expected_summary.push_expected_with_match(*c);
+                        actual_markers.push(' ');
+                        expected_markers.push(' ');
+                        common_count += 1;
                     }
                     edit_distance::Edit::AdditionalActual => {
                         // Calling edit_distance::edit_list(_, _, Mode::Exact) should never return
@@ -178,8 +582,19 @@ impl<'a> BufferedSummary<'a> {
             }
             actual_summary.reset_ansi();
             expected_summary.reset_ansi();
+            // Markers only help when the lines are mostly the same. Otherwise
+            // nearly every character would be marked.
+            let show_markers = !USE_COLOR.with(Cell::get)
+                && 2 * common_count
+                    >= actual_line.chars().count().max(expected_line.chars().count());
             self.summary.push_str(&actual_summary.summary);
+            if show_markers {
+                self.summary.push_markers(&actual_markers);
+            }
             self.summary.push_str(&expected_summary.summary);
+            if show_markers {
+                self.summary.push_markers(&expected_markers);
+            }
         } else {
             self.summary.new_line_for_actual();
             self.summary.push_str_actual_only(actual_line);
@@ -227,7 +642,9 @@ impl<'a> Display for BufferedSummary<'a> {
     }
 }
 
+#[derive(Default)]
 enum Buffer<'a> {
+    #[default]
     Empty,
     CommonLines(Vec<&'a str>),
     ExtraActualLineChunk(&'a str),
@@ -254,9 +671,6 @@ impl<'a> Buffer<'a> {
     }
 
     fn flush_common_lines(common_lines: Vec<&'a str>, summary: &mut SummaryBuilder) {
-        // The number of the lines kept before and after the compressed lines.
-        const COMMON_LINES_CONTEXT_SIZE: usize = 2;
-
         if common_lines.len() <= 2 * COMMON_LINES_CONTEXT_SIZE + 1 {
             for line in common_lines {
                 summary.new_line();
@@ -288,14 +702,23 @@ impl<'a> Buffer<'a> {
     }
 }
 
-impl<'a> Default for Buffer<'a> {
-    fn default() -> Self {
-        Self::Empty
-    }
-}
-
 thread_local! {
   pub(crate) static USE_COLOR: Cell<bool> = Cell::new(stdout_supports_color());
+  pub(crate) static SIDE_BY_SIDE: Cell<bool> = Cell::new(side_by_side_requested());
+  pub(crate) static TERMINAL_WIDTH: Cell<usize> = Cell::new(terminal_width());
+}
+
+fn side_by_side_requested() -> bool {
+    std::env::var("GOOGLETEST_DIFF_STYLE").map(|s| s == "side-by-side").unwrap_or(false)
+}
+
+// The width assumed for the output when the environment variable COLUMNS is
+// not set. Shells set COLUMNS without exporting it, so it is only seen by the
+// tests if it is exported explicitly, for instance with `export COLUMNS`.
+const DEFAULT_TERMINAL_WIDTH: usize = 120;
+
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS").ok().and_then(|s| s.parse().ok()).unwrap_or(DEFAULT_TERMINAL_WIDTH)
 }
 
 #[rustversion::since(1.70)]
@@ -392,6 +815,18 @@ impl SummaryBuilder {
         self.summary.push_str("\n+");
     }
 
+    // Appends a line marking characters of the line above, unless nothing is
+    // marked.
+    fn push_markers(&mut self, markers: &str) {
+        let markers = markers.trim_end();
+        if markers.is_empty() {
+            return;
+        }
+        self.reset_ansi();
+        self.summary.push_str("\n?");
+        self.summary.push_str(markers);
+    }
+
     fn reset_ansi(&mut self) {
         if !self.last_ansi_style.is_empty() && USE_COLOR.with(Cell::get) {
             self.summary.push_str(RESET_ALL);
@@ -435,6 +870,67 @@ mod tests {
         verify_that!(create_diff("One", "Two", Mode::Exact), eq(""))
     }
 
+    #[test]
+    fn create_inline_diff_marks_differing_span() -> Result<()> {
+        verify_that!(
+            create_inline_diff("The quick brwn fox jumps", "The quick brown fox jumps"),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                -The quick brwn fox jumps
+                +The quick brown fox jumps
+                             ^"
+            ))
+        )
+    }
+
+    #[test]
+    fn create_inline_diff_elides_long_common_prefix_and_suffix() -> Result<()> {
+        let actual = format!("{}middle{}", "a".repeat(40), "z".repeat(40));
+        let expected = format!("{}center{}", "a".repeat(40), "z".repeat(40));
+        verify_that!(
+            create_inline_diff(&actual, &expected),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                -...aaaaaaaaaaaaaaaaaaaamiddlezzzzzzzzzzzzzzzzzzzz...
+                +...aaaaaaaaaaaaaaaaaaaacenterzzzzzzzzzzzzzzzzzzzz...
+                                        ^^^^^^"
+            ))
+        )
+    }
+
+    #[test]
+    fn create_inline_diff_is_empty_for_short_values() -> Result<()> {
+        verify_that!(create_inline_diff("\"123\"", "\"321\""), eq(""))
+    }
+
+    #[test]
+    fn create_inline_diff_is_empty_for_multi_line_values() -> Result<()> {
+        verify_that!(
+            create_inline_diff("A long first line\nSecond line", "A long first line\nThird line"),
+            eq("")
+        )
+    }
+
+    #[test]
+    fn create_inline_diff_with_color() -> Result<()> {
+        USE_COLOR.with(|cell| cell.set(true));
+
+        verify_that!(
+            create_inline_diff("The quick brwn fox jumps", "The quick brown fox jumps"),
+            eq(indoc! {
+                "
+
+                Difference(-\x1B[1;31mactual\x1B[0m / +\x1B[1;32mexpected\x1B[0m):
+                -\x1B[31mThe quick brwn fox jumps\x1B[0m
+                +\x1B[32mThe quick br\x1B[0m\x1B[1;32mo\x1B[0m\x1B[32mwn fox jumps\x1B[0m"
+            })
+        )
+    }
+
     #[test]
     fn create_diff_exact_same() -> Result<()> {
         let expected = indoc! {"
@@ -483,11 +979,156 @@ mod tests {
         )
     }
 
+    #[test]
+    fn create_diff_marks_differing_characters_of_changed_lines() -> Result<()> {
+        let actual = indoc! {"
+            prefix
+            The quick brown fox
+            suffix"};
+        let expected = indoc! {"
+            prefix
+            The quick brawn fax
+            suffix"};
+        verify_that!(
+            create_diff(actual, expected, Mode::Exact),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                 prefix
+                -The quick brown fox
+                ?            ^    ^
+                +The quick brawn fax
+                ?            ^    ^
+                 suffix"
+            ))
+        )
+    }
+
+    #[test]
+    fn create_diff_side_by_side() -> Result<()> {
+        SIDE_BY_SIDE.with(|cell| cell.set(true));
+        TERMINAL_WIDTH.with(|cell| cell.set(100));
+        let actual = indoc! {"
+            Strukt {
+                int: 123,
+                string: \"something\",
+            }"};
+        let expected = indoc! {"
+            Strukt {
+                int: 321,
+                string: \"something\",
+                extra: true,
+            }"};
+        verify_that!(
+            create_diff(actual, expected, Mode::Exact),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                1   Strukt {                 | 1   Strukt {
+                2 -     int: 123,            | 2 +     int: 321,
+                3       string: \"something\", | 3       string: \"something\",
+                                             | 4 +     extra: true,
+                4   }                        | 5   }"
+            ))
+        )
+    }
+
+    #[test]
+    fn create_diff_side_by_side_wraps_long_lines() -> Result<()> {
+        SIDE_BY_SIDE.with(|cell| cell.set(true));
+        TERMINAL_WIDTH.with(|cell| cell.set(80));
+        let actual = format!("prefix\n{}\nsuffix", "a".repeat(40));
+        let expected = format!("prefix\n{}\nsuffix", "b".repeat(40));
+        verify_that!(
+            create_diff(&actual, &expected, Mode::Exact),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                1   prefix                             | 1   prefix
+                2 - aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa | 2 + bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb
+                  - aaaaaa                             |   + bbbbbb
+                3   suffix                             | 3   suffix"
+            ))
+        )
+    }
+
+    #[test]
+    fn create_diff_side_by_side_omits_common_lines() -> Result<()> {
+        SIDE_BY_SIDE.with(|cell| cell.set(true));
+        TERMINAL_WIDTH.with(|cell| cell.set(100));
+        verify_that!(
+            create_diff(&build_text(1..50), &build_text(1..51), Mode::Exact),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                 1   1  |  1   1
+                 2   2  |  2   2
+                     <---- 45 common lines omitted ---->
+                48   48 | 48   48
+                49   49 | 49   49
+                        | 50 + 50"
+            ))
+        )
+    }
+
+    #[test]
+    fn create_diff_side_by_side_falls_back_to_unified_diff_if_narrow() -> Result<()> {
+        SIDE_BY_SIDE.with(|cell| cell.set(true));
+        TERMINAL_WIDTH.with(|cell| cell.set(60));
+        verify_that!(
+            create_diff("One\nTwo", "One\nThree", Mode::Exact),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                 One
+                -Two
+                +Three"
+            ))
+        )
+    }
+
+    #[test]
+    fn create_diff_reversed_side_by_side_numbers_lines_after_omitted_ones() -> Result<()> {
+        SIDE_BY_SIDE.with(|cell| cell.set(true));
+        TERMINAL_WIDTH.with(|cell| cell.set(100));
+        verify_that!(
+            create_diff_reversed("prefix\nOne\nTwo\nThree", "One\nTwo\nFour", Mode::Prefix),
+            eq(indoc!(
+                "
+
+                Difference(-actual / +expected):
+                    <---- remaining lines omitted ---->
+                2   One   | 1   One
+                3   Two   | 2   Two
+                4 - Three | 3 + Four"
+            ))
+        )
+    }
+
     #[test]
     fn create_diff_exact_unrelated() -> Result<()> {
         verify_that!(create_diff(&build_text(1..500), &build_text(501..1000), Mode::Exact), eq(""))
     }
 
+    #[test]
+    fn create_diff_exact_large_difference() -> Result<()> {
+        let actual = build_text((1..2000).map(|i| if i % 10 == 0 { i } else { -i }));
+        let expected = build_text(1..2000);
+        verify_that!(
+            create_diff(&actual, &expected, Mode::Exact),
+            all![
+                starts_with("\nDifference(-actual / +expected):\n--1\n"),
+                contains_substring("\n 1990\n--1991\n"),
+                ends_with("\n+1999"),
+            ]
+        )
+    }
+
     #[test]
     fn create_diff_exact_small_difference() -> Result<()> {
         verify_that!(
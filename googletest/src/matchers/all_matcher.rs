@@ -59,9 +59,23 @@
 /// verify_that!(123, all![123, lt(1000), gt(100)])
 /// #     .unwrap();
 /// ```
+///
+/// When the number of matchers is only known at runtime, collect them into a
+/// `Vec` of [`BoxedMatcher`][crate::matchers::BoxedMatcher] and pass it
+/// prefixed with `..`. An empty list always matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// let matchers: Vec<BoxedMatcher<i32>> = vec![lt(1000).boxed(), gt(100).boxed()];
+/// verify_that!(123, all![..matchers])
+/// #     .unwrap();
+/// ```
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __all {
+    (.. $matchers:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::all_of($matchers)
+    }};
     ($(,)?) => {{
         $crate::matchers::anything()
     }} ;
@@ -74,7 +88,6 @@ macro_rules! __all {
         $crate::matchers::__internal_unstable_do_not_depend_on_these::ConjunctionMatcher::new(auto_eq!($head), auto_eq!($head2))
     }};
     ($head:expr, $head2:expr, $($tail:expr),+ $(,)?) => {{
-        use $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq;
         $crate::__all![
             $crate::matchers::__internal_unstable_do_not_depend_on_these::ConjunctionMatcher::new(auto_eq!($head), auto_eq!($head2)),
             $($tail),+
@@ -82,6 +95,101 @@ macro_rules! __all {
     }}
 }
 
+/// Module for use only by the macro `all!`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::description::Description;
+    use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+    use crate::matchers::{anything, BoxedMatcher};
+    use std::fmt::Debug;
+
+    /// Combines `matchers` into a single matcher which matches when all of them
+    /// match.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn all_of<'a, T: Debug + Clone + 'a>(
+        matchers: impl IntoIterator<Item = BoxedMatcher<'a, T>>,
+    ) -> BoxedMatcher<'a, T> {
+        let mut matchers = matchers.into_iter().collect::<Vec<_>>();
+        match matchers.len() {
+            0 => anything().boxed(),
+            1 => matchers.remove(0),
+            _ => AllOf { matchers }.boxed(),
+        }
+    }
+
+    /// Matches a value which all of a list of matchers match.
+    ///
+    /// Unlike a chain of `ConjunctionMatcher`,
+    /// this does not nest, so that the depth of the stack does not grow with the
+    /// number of matchers. The descriptions are the same as those of the chain.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    #[derive(MatcherBase)]
+    pub struct AllOf<'a, T: Debug + Clone> {
+        matchers: Vec<BoxedMatcher<'a, T>>,
+    }
+
+    impl<'a, T: Debug + Clone> Matcher<T> for AllOf<'a, T> {
+        fn matches(&self, actual: T) -> MatcherResult {
+            self.matchers.iter().all(|matcher| matcher.matches(actual.clone()).is_match()).into()
+        }
+
+        fn match_and_explain(
+            &self,
+            actual: T,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            let results = self
+                .matchers
+                .iter()
+                .map(|matcher| matcher.matches(actual.clone()))
+                .collect::<Vec<_>>();
+            let all_match = results.iter().all(|result| result.is_match());
+            then(all_match.into(), &|| {
+                // As for the chain, the explanation covers the failing matchers, or
+                // all of them if they all match.
+                let explained = self
+                    .matchers
+                    .iter()
+                    .zip(&results)
+                    .filter(|(_, result)| all_match || !result.is_match())
+                    .map(|(matcher, _)| matcher.explain_match(actual.clone()))
+                    .collect::<Vec<_>>();
+                if explained.len() == 1 {
+                    explained.into_iter().next().unwrap()
+                } else {
+                    Description::new().bullet_list().collect(explained).conjunction_description()
+                }
+            })
+        }
+
+        fn explain_match(&self, actual: T) -> Description {
+            crate::matcher::explain_with_match_and_explain(self, actual)
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> Description {
+            let header = if matcher_result.into() {
+                "has all the following properties:"
+            } else {
+                "has at least one of the following properties:"
+            };
+            Description::new()
+                .text(header)
+                .nested(
+                    Description::new()
+                        .bullet_list()
+                        .collect(self.matchers.iter().map(|m| m.describe(matcher_result))),
+                )
+                .conjunction_description()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::matcher::MatcherResult;
@@ -0,0 +1,593 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use crate::matchers::{approx_eq, near, NearMatcher};
+use num_traits::{Float, FloatConst};
+use std::fmt::Debug;
+
+/// The number of mismatching elements listed in the explanation of a
+/// mismatch.
+const MAX_LISTED_MISMATCHES: usize = 5;
+
+/// Matches a slice, array, or `Vec` of floating point numbers, possibly
+/// nested, each of whose elements is within `max_abs_error` of the element at
+/// the same position in `expected`.
+///
+/// This is equivalent to applying [`near`] element-wise, but the explanation
+/// of a mismatch summarizes the differences instead of listing every element:
+/// it reports the number of mismatching elements, the worst absolute and
+/// relative errors with the positions where they occur, and a table of the
+/// first mismatching elements.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1.0, 2.01, 2.99], all_near([1.0, 2.0, 3.0], 0.05))?; // Passes
+/// verify_that!(vec![vec![1.0], vec![2.01]], all_near(vec![vec![1.0], vec![2.0]], 0.05))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1.0, 2.5, 3.0], all_near([1.0, 2.0, 3.0], 0.05))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The failure message of the last assertion is:
+///
+/// ```text
+/// Expected: has all elements within 0.05 of [1.0, 2.0, 3.0]
+/// Actual: [1.0, 2.5, 3.0],
+///   which has 1 of 3 elements out of tolerance
+///     worst absolute error: 0.5 at [1]
+///     worst relative error: 0.25 at [1]
+///     index  actual  expected  absolute error  relative error
+///     [1]    2.5     2.0       0.5             0.25
+/// ```
+///
+/// The actual value must have the same shape as `expected`. As with [`near`],
+/// NaNs are not equal to each other unless
+/// [`AllNearMatcher::nans_are_equal`] is used.
+#[track_caller]
+pub fn all_near<ExpectedT: FloatArray>(
+    expected: ExpectedT,
+    max_abs_error: ExpectedT::Scalar,
+) -> AllNearMatcher<ExpectedT> {
+    // Validates the tolerance the same way as `near`.
+    let _ = near(<ExpectedT::Scalar as num_traits::Zero>::zero(), max_abs_error);
+    AllNearMatcher {
+        expected,
+        tolerance: Tolerance::Absolute(max_abs_error),
+        nans_are_equal: false,
+    }
+}
+
+/// Matches a slice, array, or `Vec` of floating point numbers, possibly
+/// nested, each of whose elements is approximately equal to the element at the
+/// same position in `expected`.
+///
+/// This is equivalent to applying [`approx_eq`] element-wise, with the
+/// explanation of a mismatch described in [`all_near`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![0.1 + 0.2, 1.0], all_approx_eq([0.3, 1.0]))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub fn all_approx_eq<ExpectedT: FloatArray>(expected: ExpectedT) -> AllNearMatcher<ExpectedT>
+where
+    ExpectedT::Scalar: FloatConst,
+{
+    AllNearMatcher { expected, tolerance: Tolerance::Approximate, nans_are_equal: false }
+}
+
+/// A possibly nested slice, array, or `Vec` of floating point numbers, which
+/// can be compared element-wise by [`all_near`] and [`all_approx_eq`].
+pub trait FloatArray {
+    /// The type of the floating point numbers.
+    type Scalar: Float + FloatConst + Debug;
+
+    /// Appends the elements of `self` to `elements`, each with its position,
+    /// which is `index` followed by the position of the element in `self`.
+    ///
+    /// The elements of each container are followed by `None` at the position
+    /// past its last element, so that containers of different lengths, even
+    /// empty ones, are told apart.
+    fn flatten(
+        &self,
+        index: &mut Vec<usize>,
+        elements: &mut Vec<(Vec<usize>, Option<Self::Scalar>)>,
+    );
+}
+
+macro_rules! impl_float_array_for_scalar {
+    ($($scalar:ty),*) => {
+        $(
+            impl FloatArray for $scalar {
+                type Scalar = $scalar;
+
+                fn flatten(
+                    &self,
+                    index: &mut Vec<usize>,
+                    elements: &mut Vec<(Vec<usize>, Option<Self::Scalar>)>,
+                ) {
+                    elements.push((index.clone(), Some(*self)));
+                }
+            }
+        )*
+    };
+}
+
+impl_float_array_for_scalar!(f32, f64);
+
+impl<T: FloatArray> FloatArray for [T] {
+    type Scalar = T::Scalar;
+
+    fn flatten(
+        &self,
+        index: &mut Vec<usize>,
+        elements: &mut Vec<(Vec<usize>, Option<Self::Scalar>)>,
+    ) {
+        for (position, element) in self.iter().enumerate() {
+            index.push(position);
+            element.flatten(index, elements);
+            index.pop();
+        }
+        index.push(self.len());
+        elements.push((index.clone(), None));
+        index.pop();
+    }
+}
+
+impl<T: FloatArray, const N: usize> FloatArray for [T; N] {
+    type Scalar = T::Scalar;
+
+    fn flatten(
+        &self,
+        index: &mut Vec<usize>,
+        elements: &mut Vec<(Vec<usize>, Option<Self::Scalar>)>,
+    ) {
+        self.as_slice().flatten(index, elements)
+    }
+}
+
+impl<T: FloatArray> FloatArray for Vec<T> {
+    type Scalar = T::Scalar;
+
+    fn flatten(
+        &self,
+        index: &mut Vec<usize>,
+        elements: &mut Vec<(Vec<usize>, Option<Self::Scalar>)>,
+    ) {
+        self.as_slice().flatten(index, elements)
+    }
+}
+
+impl<T: FloatArray + ?Sized> FloatArray for &T {
+    type Scalar = T::Scalar;
+
+    fn flatten(
+        &self,
+        index: &mut Vec<usize>,
+        elements: &mut Vec<(Vec<usize>, Option<Self::Scalar>)>,
+    ) {
+        (*self).flatten(index, elements)
+    }
+}
+
+fn flatten<T: FloatArray + ?Sized>(array: &T) -> Vec<(Vec<usize>, Option<T::Scalar>)> {
+    let mut elements = vec![];
+    array.flatten(&mut vec![], &mut elements);
+    elements
+}
+
+fn format_index(index: &[usize]) -> String {
+    index.iter().map(|position| format!("[{position}]")).collect()
+}
+
+/// The tolerance of an [`AllNearMatcher`].
+enum Tolerance<T> {
+    /// The tolerance of [`near`].
+    Absolute(T),
+    /// The tolerance of [`approx_eq`].
+    Approximate,
+}
+
+/// A matcher which matches a possibly nested slice, array, or `Vec` of
+/// floating point numbers element-wise.
+///
+/// See [`all_near`] and [`all_approx_eq`].
+#[derive(MatcherBase)]
+pub struct AllNearMatcher<ExpectedT: FloatArray> {
+    expected: ExpectedT,
+    tolerance: Tolerance<ExpectedT::Scalar>,
+    nans_are_equal: bool,
+}
+
+/// An element which is not within tolerance of the expected element.
+struct Mismatch<T> {
+    index: Vec<usize>,
+    actual: T,
+    expected: T,
+}
+
+impl<T: Float> Mismatch<T> {
+    fn absolute_error(&self) -> T {
+        (self.actual - self.expected).abs()
+    }
+
+    fn relative_error(&self) -> T {
+        self.absolute_error() / self.expected.abs()
+    }
+}
+
+/// The outcome of the element-wise comparison.
+enum Comparison<T> {
+    /// The actual and expected values have different shapes. Holds the
+    /// position of the first element present in only one of them, and whether
+    /// it is present in the actual value.
+    ShapeMismatch(Vec<usize>, bool),
+    /// Holds the number of elements and the mismatching elements.
+    Elements(usize, Vec<Mismatch<T>>),
+}
+
+impl<ExpectedT: FloatArray> AllNearMatcher<ExpectedT> {
+    /// Configures this instance to treat two NaNs at the same position as
+    /// equal. See [`NearMatcher::nans_are_equal`].
+    pub fn nans_are_equal(mut self) -> Self {
+        self.nans_are_equal = true;
+        self
+    }
+
+    /// Configures this instance to treat two NaNs as not equal, which is the
+    /// default. See [`NearMatcher::nans_are_not_equal`].
+    pub fn nans_are_not_equal(mut self) -> Self {
+        self.nans_are_equal = false;
+        self
+    }
+
+    fn element_matcher(&self, expected: ExpectedT::Scalar) -> NearMatcher<ExpectedT::Scalar> {
+        let matcher = match self.tolerance {
+            Tolerance::Absolute(max_abs_error) => near(expected, max_abs_error),
+            Tolerance::Approximate => approx_eq(expected),
+        };
+        if self.nans_are_equal {
+            matcher.nans_are_equal()
+        } else {
+            matcher
+        }
+    }
+
+    fn compare<ActualT: FloatArray<Scalar = ExpectedT::Scalar> + ?Sized>(
+        &self,
+        actual: &ActualT,
+    ) -> Comparison<ExpectedT::Scalar> {
+        let actual = flatten(actual);
+        let expected = flatten(&self.expected);
+        let first_shape_difference = actual.iter().zip(expected.iter()).find(
+            |((actual_index, actual), (expected_index, expected))| {
+                actual_index != expected_index || actual.is_some() != expected.is_some()
+            },
+        );
+        if let Some(((actual_index, actual_element), (expected_index, expected_element))) =
+            first_shape_difference
+        {
+            // The side whose container ended first lacks an element at the
+            // position of its end marker.
+            let (index, in_actual) = match (actual_element, expected_element) {
+                (None, _) if expected_index.starts_with(actual_index) => (actual_index, false),
+                (_, None) if actual_index.starts_with(expected_index) => (expected_index, true),
+                _ if actual_index < expected_index => (actual_index, true),
+                _ => (expected_index, false),
+            };
+            return Comparison::ShapeMismatch(index.clone(), in_actual);
+        }
+        let mut len = 0;
+        let mut mismatches = vec![];
+        for ((index, actual), (_, expected)) in actual.into_iter().zip(expected) {
+            let (Some(actual), Some(expected)) = (actual, expected) else {
+                continue;
+            };
+            len += 1;
+            if self.element_matcher(expected).matches(actual).is_no_match() {
+                mismatches.push(Mismatch { index, actual, expected });
+            }
+        }
+        Comparison::Elements(len, mismatches)
+    }
+}
+
+impl<ExpectedT: FloatArray + Debug> AllNearMatcher<ExpectedT> {
+    fn describe_tolerance(&self) -> String {
+        match self.tolerance {
+            Tolerance::Absolute(max_abs_error) => {
+                format!("within {max_abs_error:?} of {:?}", self.expected)
+            }
+            Tolerance::Approximate => format!("approximately equal to {:?}", self.expected),
+        }
+    }
+}
+
+impl<ExpectedT: FloatArray + Debug, ActualT> Matcher<ActualT> for AllNearMatcher<ExpectedT>
+where
+    ActualT: FloatArray<Scalar = ExpectedT::Scalar> + Debug + Clone,
+{
+    fn matches(&self, actual: ActualT) -> MatcherResult {
+        match self.compare(&actual) {
+            Comparison::Elements(_, mismatches) => mismatches.is_empty().into(),
+            Comparison::ShapeMismatch(..) => MatcherResult::NoMatch,
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => format!("has all elements {}", self.describe_tolerance()),
+            MatcherResult::NoMatch => {
+                format!("doesn't have all elements {}", self.describe_tolerance())
+            }
+        }
+        .into()
+    }
+
+    fn explain_match(&self, actual: ActualT) -> Description {
+        let (len, mismatches) = match self.compare(&actual) {
+            Comparison::ShapeMismatch(index, true) => {
+                return format!(
+                    "which has an element at {} but the expected value doesn't",
+                    format_index(&index)
+                )
+                .into()
+            }
+            Comparison::ShapeMismatch(index, false) => {
+                return format!(
+                    "which has no element at {} but the expected value does",
+                    format_index(&index)
+                )
+                .into()
+            }
+            Comparison::Elements(len, mismatches) => (len, mismatches),
+        };
+        if mismatches.is_empty() {
+            return format!("which has all elements {}", self.describe_tolerance()).into();
+        }
+        let worst_absolute = worst(&mismatches, Mismatch::absolute_error);
+        let worst_relative = worst(&mismatches, Mismatch::relative_error);
+        Description::new()
+            .text(format!("which has {} of {len} elements out of tolerance", mismatches.len()))
+            .nested(
+                Description::new()
+                    .text(format!(
+                        "worst absolute error: {:?} at {}",
+                        worst_absolute.absolute_error(),
+                        format_index(&worst_absolute.index)
+                    ))
+                    .text(format!(
+                        "worst relative error: {:?} at {}",
+                        worst_relative.relative_error(),
+                        format_index(&worst_relative.index)
+                    ))
+                    .text(mismatch_table(&mismatches)),
+            )
+    }
+}
+
+/// Returns the mismatch with the largest `error`, considering NaN as the
+/// largest error.
+fn worst<T: Float>(mismatches: &[Mismatch<T>], error: fn(&Mismatch<T>) -> T) -> &Mismatch<T> {
+    let mut worst = &mismatches[0];
+    for mismatch in &mismatches[1..] {
+        let worst_error = error(worst);
+        let mismatch_error = error(mismatch);
+        if !worst_error.is_nan() && (mismatch_error.is_nan() || mismatch_error > worst_error) {
+            worst = mismatch;
+        }
+    }
+    worst
+}
+
+/// Formats the first mismatches as a table with aligned columns.
+fn mismatch_table<T: Float + Debug>(mismatches: &[Mismatch<T>]) -> String {
+    let mut rows = vec![[
+        "index".to_string(),
+        "actual".to_string(),
+        "expected".to_string(),
+        "absolute error".to_string(),
+        "relative error".to_string(),
+    ]];
+    rows.extend(mismatches.iter().take(MAX_LISTED_MISMATCHES).map(|mismatch| {
+        [
+            format_index(&mismatch.index),
+            format!("{:?}", mismatch.actual),
+            format!("{:?}", mismatch.expected),
+            format!("{:?}", mismatch.absolute_error()),
+            format!("{:?}", mismatch.relative_error()),
+        ]
+    }));
+    let widths = (0..5)
+        .map(|column| rows.iter().map(|row| row[column].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let mut table = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if mismatches.len() > MAX_LISTED_MISMATCHES {
+        table.push_str(&format!("\n(and {} more)", mismatches.len() - MAX_LISTED_MISMATCHES));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn all_near_matches_vec_within_tolerance() -> Result<()> {
+        verify_that!(vec![1.0, 2.01, 2.99], all_near(vec![1.0, 2.0, 3.0], 0.05))
+    }
+
+    #[test]
+    fn all_near_matches_array_against_slice() -> Result<()> {
+        let expected = [1.0f32, 2.0];
+        verify_that!([1.01f32, 1.99], all_near(expected.as_slice(), 0.05))
+    }
+
+    #[test]
+    fn all_near_matches_nested_vecs() -> Result<()> {
+        verify_that!(
+            vec![vec![1.0, 2.0], vec![3.0]],
+            all_near(vec![vec![1.01, 2.0], vec![2.99]], 0.05)
+        )
+    }
+
+    #[test]
+    fn all_near_does_not_match_element_out_of_tolerance() -> Result<()> {
+        verify_that!(vec![1.0, 2.5], not(all_near([1.0, 2.0], 0.05)))
+    }
+
+    #[test]
+    fn all_near_does_not_match_different_length() -> Result<()> {
+        verify_that!(vec![1.0, 2.0], not(all_near([1.0, 2.0, 3.0], 0.05)))
+    }
+
+    #[test]
+    fn all_near_does_not_match_different_nested_shape() -> Result<()> {
+        verify_that!(vec![vec![1.0, 2.0], vec![]], not(all_near(vec![vec![1.0], vec![2.0]], 0.05)))
+    }
+
+    #[test]
+    fn all_near_does_not_match_trailing_empty_row() -> Result<()> {
+        verify_that!(vec![vec![1.0], vec![]], not(all_near(vec![vec![1.0]], 0.05)))
+    }
+
+    #[test]
+    fn all_near_does_not_match_missing_empty_row() -> Result<()> {
+        verify_that!(vec![vec![1.0]], not(all_near(vec![vec![1.0], vec![]], 0.05)))
+    }
+
+    #[test]
+    fn all_near_matches_empty_rows() -> Result<()> {
+        verify_that!(vec![vec![], vec![1.0]], all_near(vec![vec![], vec![1.0]], 0.05))
+    }
+
+    #[test]
+    fn all_near_does_not_match_nans_by_default() -> Result<()> {
+        verify_that!(vec![f64::NAN], not(all_near([f64::NAN], 0.05)))
+    }
+
+    #[test]
+    fn all_near_matches_nans_if_nans_are_equal() -> Result<()> {
+        verify_that!(vec![f64::NAN], all_near([f64::NAN], 0.05).nans_are_equal())
+    }
+
+    #[test]
+    fn all_approx_eq_matches_rounding_errors() -> Result<()> {
+        verify_that!(vec![0.1 + 0.2, 1.0], all_approx_eq([0.3, 1.0]))
+    }
+
+    #[test]
+    fn all_approx_eq_does_not_match_different_values() -> Result<()> {
+        verify_that!(vec![0.31, 1.0], not(all_approx_eq([0.3, 1.0])))
+    }
+
+    #[test]
+    fn all_near_describes_itself() -> Result<()> {
+        verify_that!(
+            Matcher::<&Vec<f64>>::describe(&all_near([1.0, 2.0], 0.5), MatcherResult::NoMatch),
+            displays_as(eq("doesn't have all elements within 0.5 of [1.0, 2.0]"))
+        )
+    }
+
+    #[test]
+    fn all_near_explains_mismatches() -> Result<()> {
+        let result = verify_that!(vec![1.0, 2.5, 3.0, 5.0], all_near([1.0, 2.0, 3.0, 4.0], 0.25));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: [1.0, 2.5, 3.0, 5.0],
+                  which has 2 of 4 elements out of tolerance
+                    worst absolute error: 1.0 at [3]
+                    worst relative error: 0.25 at [1]
+                    index  actual  expected  absolute error  relative error
+                    [1]    2.5     2.0       0.5             0.25
+                    [3]    5.0     4.0       1.0             0.25
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn all_near_lists_only_first_mismatches() -> Result<()> {
+        let expected = vec![0.0; 8];
+        let matcher = all_near(expected, 0.5);
+
+        verify_that!(
+            matcher.explain_match(&vec![1.0; 8]),
+            displays_as(ends_with(
+                "  [4]    1.0     0.0       1.0             inf\n  (and 3 more)"
+            ))
+        )
+    }
+
+    #[test]
+    fn all_near_explains_nested_index() -> Result<()> {
+        let value = vec![vec![1.0], vec![2.0, 3.5]];
+        let matcher = all_near(vec![vec![1.0], vec![2.0, 3.0]], 0.25);
+
+        verify_that!(
+            matcher.explain_match(&value),
+            displays_as(contains_substring("worst absolute error: 0.5 at [1][1]"))
+        )
+    }
+
+    #[test]
+    fn all_near_explains_shape_mismatch() -> Result<()> {
+        let value = vec![vec![1.0], vec![2.0]];
+        let matcher = all_near(vec![vec![1.0], vec![2.0, 3.0]], 0.25);
+
+        verify_that!(
+            matcher.explain_match(&value),
+            displays_as(eq("which has no element at [1][1] but the expected value does"))
+        )
+    }
+
+    #[test]
+    fn all_near_explains_trailing_empty_row() -> Result<()> {
+        let value = vec![vec![1.0], vec![]];
+        let matcher = all_near(vec![vec![1.0]], 0.25);
+
+        verify_that!(
+            matcher.explain_match(&value),
+            displays_as(eq("which has an element at [1] but the expected value doesn't"))
+        )
+    }
+}
@@ -61,9 +61,23 @@
 /// verify_that!(123, any![lt(1), 123, gt(1000)])
 /// #     .unwrap();
 /// ```
+///
+/// When the number of matchers is only known at runtime, collect them into a
+/// `Vec` of [`BoxedMatcher`][crate::matchers::BoxedMatcher] and pass it
+/// prefixed with `..`. An empty list never matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// let matchers: Vec<BoxedMatcher<i32>> = vec![lt(1).boxed(), eq(123).boxed()];
+/// verify_that!(123, any![..matchers])
+/// #     .unwrap();
+/// ```
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __any {
+    (.. $matchers:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::any_of($matchers)
+    }};
     ($(,)?) => {{
         $crate::matchers::not($crate::matchers::anything())
     }} ;
@@ -76,7 +90,6 @@ macro_rules! __any {
         $crate::matchers::__internal_unstable_do_not_depend_on_these::DisjunctionMatcher::new(auto_eq!($head), auto_eq!($head2))
     }};
     ($head:expr, $head2:expr, $($tail:expr),+ $(,)?) => {{
-        use $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq;
         $crate::__any![
             $crate::matchers::__internal_unstable_do_not_depend_on_these::DisjunctionMatcher::new(auto_eq!($head), auto_eq!($head2)),
             $($tail),+
@@ -84,6 +97,101 @@ macro_rules! __any {
     }}
 }
 
+/// Module for use only by the macro `any!`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::description::Description;
+    use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+    use crate::matchers::{anything, not, BoxedMatcher};
+    use std::fmt::Debug;
+
+    /// Combines `matchers` into a single matcher which matches when at least one of them
+    /// match.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn any_of<'a, T: Debug + Clone + 'a>(
+        matchers: impl IntoIterator<Item = BoxedMatcher<'a, T>>,
+    ) -> BoxedMatcher<'a, T> {
+        let mut matchers = matchers.into_iter().collect::<Vec<_>>();
+        match matchers.len() {
+            0 => not(anything()).boxed(),
+            1 => matchers.remove(0),
+            _ => AnyOf { matchers }.boxed(),
+        }
+    }
+
+    /// Matches a value which at least one of a list of matchers matches.
+    ///
+    /// Unlike a chain of `DisjunctionMatcher`,
+    /// this does not nest, so that the depth of the stack does not grow with the
+    /// number of matchers.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    #[derive(MatcherBase)]
+    pub struct AnyOf<'a, T: Debug + Clone> {
+        matchers: Vec<BoxedMatcher<'a, T>>,
+    }
+
+    impl<'a, T: Debug + Clone> Matcher<T> for AnyOf<'a, T> {
+        fn matches(&self, actual: T) -> MatcherResult {
+            self.matchers.iter().any(|matcher| matcher.matches(actual.clone()).is_match()).into()
+        }
+
+        fn match_and_explain(
+            &self,
+            actual: T,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            let results = self
+                .matchers
+                .iter()
+                .map(|matcher| matcher.matches(actual.clone()))
+                .collect::<Vec<_>>();
+            let any_match = results.iter().any(|result| result.is_match());
+            then(any_match.into(), &|| {
+                // The explanation covers the matching matchers, or all of them if
+                // none matches.
+                let explained = self
+                    .matchers
+                    .iter()
+                    .zip(&results)
+                    .filter(|(_, result)| !any_match || result.is_match())
+                    .map(|(matcher, _)| matcher.explain_match(actual.clone()))
+                    .collect::<Vec<_>>();
+                if explained.len() == 1 {
+                    explained.into_iter().next().unwrap()
+                } else {
+                    Description::new().bullet_list().collect(explained).disjunction_description()
+                }
+            })
+        }
+
+        fn explain_match(&self, actual: T) -> Description {
+            crate::matcher::explain_with_match_and_explain(self, actual)
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> Description {
+            let header = if matcher_result.into() {
+                "has at least one of the following properties:"
+            } else {
+                "has all of the following properties:"
+            };
+            Description::new()
+                .text(header)
+                .nested(
+                    Description::new()
+                        .bullet_list()
+                        .collect(self.matchers.iter().map(|m| m.describe(matcher_result))),
+                )
+                .disjunction_description()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::matcher::MatcherResult;
@@ -39,7 +39,7 @@ pub fn anything() -> Anything {
 #[derive(MatcherBase)]
 pub struct Anything;
 
-impl<T: Debug + Copy> Matcher<T> for Anything {
+impl<T: Debug + Clone> Matcher<T> for Anything {
     fn matches(&self, _: T) -> MatcherResult {
         MatcherResult::Match
     }
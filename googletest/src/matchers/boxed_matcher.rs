@@ -0,0 +1,187 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherBase, MatcherResult},
+};
+use std::fmt::Debug;
+
+/// A type-erased [`Matcher`] against actual values of type `T`.
+///
+/// Matchers of different concrete types can be stored together once boxed,
+/// which makes it possible to build the expectations of a test at runtime, for
+/// example from a table of test cases. Use
+/// [`MatcherBase::boxed`][crate::matcher::MatcherBase::boxed] to create one.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let matchers: Vec<BoxedMatcher<i32>> = vec![eq(1).boxed(), gt(1).boxed(), anything().boxed()];
+/// for matcher in &matchers {
+///     verify_that!(2, any![matcher, gt(0)])?;
+/// }
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// A `Vec<BoxedMatcher<T>>` can be passed to [`elements_are!`],
+/// [`unordered_elements_are!`], [`contains_each!`], [`is_contained_in!`],
+/// [`any!`], and [`all!`] by prefixing it with `..`, for lists of matchers
+/// whose length is only known at runtime:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let expected = [1, 2, 3];
+/// let matchers: Vec<BoxedMatcher<&i32>> = expected.iter().map(|e| eq(e).boxed()).collect();
+/// verify_that!(vec![1, 2, 3], elements_are![..matchers])?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// [`elements_are!`]: crate::matchers::elements_are
+/// [`unordered_elements_are!`]: crate::matchers::unordered_elements_are
+/// [`contains_each!`]: crate::matchers::contains_each
+/// [`is_contained_in!`]: crate::matchers::is_contained_in
+/// [`any!`]: crate::matchers::any
+/// [`all!`]: crate::matchers::all
+#[derive(MatcherBase)]
+pub struct BoxedMatcher<'a, T: Debug + Clone> {
+    inner: Box<dyn Matcher<T> + 'a>,
+    equality_hint: Option<String>,
+}
+
+impl<'a, T: Debug + Clone> BoxedMatcher<'a, T> {
+    pub(crate) fn new(inner: impl Matcher<T> + 'a) -> Self {
+        Self { inner: Box::new(inner), equality_hint: None }
+    }
+
+    /// Records that the boxed matcher only matches values equal to one whose
+    /// `Debug` representation is `hint`.
+    pub(crate) fn with_equality_hint(self, hint: String) -> Self {
+        Self { equality_hint: Some(hint), ..self }
+    }
+
+    /// Returns the `Debug` representation of the value the boxed matcher
+    /// expects the actual value to be equal to, if it was boxed from an
+    /// [`eq`][crate::matchers::eq] matcher.
+    ///
+    /// Container matchers use this hint to find the elements a matcher is
+    /// likely to match by hashing, instead of trying every element. Since a
+    /// value may be equal to one with a different `Debug` representation, the
+    /// hint can only be used to find matches, never to rule them out.
+    pub(crate) fn equality_hint(&self) -> Option<&str> {
+        self.equality_hint.as_deref()
+    }
+}
+
+impl<'a, T: Debug + Clone> Matcher<T> for BoxedMatcher<'a, T> {
+    fn matches(&self, actual: T) -> MatcherResult {
+        self.inner.matches(actual)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        self.inner.describe(matcher_result)
+    }
+
+    fn explain_match(&self, actual: T) -> Description {
+        self.inner.explain_match(actual)
+    }
+
+    fn match_and_explain(
+        &self,
+        actual: T,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        self.inner.match_and_explain(actual, then)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn boxed_matcher_matches_like_inner_matcher() -> Result<()> {
+        let matcher: BoxedMatcher<i32> = eq(1).boxed();
+
+        verify_that!(matcher.matches(1), eq(MatcherResult::Match))?;
+        verify_that!(matcher.matches(2), eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn boxed_matchers_of_different_types_can_be_stored_together() -> Result<()> {
+        let matchers: Vec<BoxedMatcher<i32>> =
+            vec![eq(1).boxed(), gt(0).boxed(), not(lt(0)).boxed(), anything().boxed()];
+
+        verify_that!(matchers.iter().all(|m| m.matches(1).is_match()), eq(true))
+    }
+
+    #[test]
+    fn boxed_matcher_describes_like_inner_matcher() -> Result<()> {
+        let matcher: BoxedMatcher<&str> = starts_with("A").boxed();
+
+        verify_that!(
+            matcher.describe(MatcherResult::NoMatch),
+            displays_as(eq("does not start with \"A\""))
+        )
+    }
+
+    #[test]
+    fn boxed_matcher_explains_like_inner_matcher() -> Result<()> {
+        let result = verify_that!(vec![1, 2], contains(eq(&3)).boxed());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: contains at least one element which is equal to 3
+                Actual: [1, 2],
+                  which does not contain a matching element"
+            ))))
+        )
+    }
+
+    #[test]
+    fn boxed_eq_matcher_has_equality_hint() -> Result<()> {
+        let matcher: BoxedMatcher<&str> = eq("A").boxed();
+
+        verify_that!(matcher.equality_hint(), some(eq("\"A\"")))
+    }
+
+    #[test]
+    fn boxed_matcher_of_other_matcher_has_no_equality_hint() -> Result<()> {
+        let matcher: BoxedMatcher<i32> = gt(1).boxed();
+
+        verify_that!(matcher.equality_hint(), none())
+    }
+
+    #[test]
+    fn boxed_matcher_can_be_combined_with_other_matchers() -> Result<()> {
+        verify_that!(5, gt(1).boxed().and(lt(10)))
+    }
+
+    #[test]
+    fn boxed_matcher_can_borrow_its_inner_matcher() -> Result<()> {
+        let inner = eq(3);
+        let matcher: BoxedMatcher<i32> = (&inner).boxed();
+
+        verify_that!(3, matcher)
+    }
+}
@@ -65,7 +65,7 @@ pub struct CharLenMatcher<E> {
     expected: E,
 }
 
-impl<T: Debug + Copy + AsRef<str>, E: Matcher<usize>> Matcher<T> for CharLenMatcher<E> {
+impl<T: Debug + Clone + AsRef<str>, E: Matcher<usize>> Matcher<T> for CharLenMatcher<E> {
     fn matches(&self, actual: T) -> MatcherResult {
         self.expected.matches(actual.as_ref().chars().count())
     }
@@ -127,7 +127,7 @@ mod tests {
         #[derive(MatcherBase)]
         struct TestMatcher;
 
-        impl<T: Debug + Copy> Matcher<T> for TestMatcher {
+        impl<T: Debug + Clone> Matcher<T> for TestMatcher {
             fn matches(&self, _: T) -> MatcherResult {
                 false.into()
             }
@@ -50,6 +50,13 @@ use std::fmt::Debug;
 /// uses a naive algorithm requiring time proportional to the product of the
 /// sizes of the expected and actual values. This should therefore only be used
 /// when the containers are small enough that this is not a problem.
+///
+/// To compare maps, use [`map_eq`][crate::matchers::map_eq] instead, which
+/// reports differences key by key. To compare containers regardless of the
+/// order of their elements, for instance a `Vec` with a
+/// [`HashSet`][std::collections::HashSet], use
+/// [`unordered_eq`][crate::matchers::unordered_eq], which also reports
+/// differences in the number of occurrences of each element.
 pub fn container_eq<ExpectedContainerT>(
     expected: ExpectedContainerT,
 ) -> ContainerEqMatcher<ExpectedContainerT>
@@ -67,8 +74,8 @@ pub struct ContainerEqMatcher<ExpectedContainerT> {
 impl<ActualElementT, ActualContainerT, ExpectedElementT, ExpectedContainerT>
     Matcher<ActualContainerT> for ContainerEqMatcher<ExpectedContainerT>
 where
-    ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + Debug + Copy,
-    ActualContainerT: for<'a> PartialEq<&'a ExpectedContainerT> + Debug + Copy,
+    ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + Debug + Clone,
+    ActualContainerT: for<'a> PartialEq<&'a ExpectedContainerT> + Debug + Clone,
     ExpectedElementT: Debug,
     ExpectedContainerT: Debug,
     ActualContainerT: IntoIterator<Item = ActualElementT>,
@@ -79,7 +86,8 @@ where
     }
 
     fn explain_match(&self, actual: ActualContainerT) -> Description {
-        build_explanation(self.get_missing_items(actual), self.get_unexpected_items(actual)).into()
+        build_explanation(self.get_missing_items(actual.clone()), self.get_unexpected_items(actual))
+            .into()
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> Description {
@@ -99,11 +107,11 @@ where
         actual: ActualContainerT,
     ) -> Vec<&'_ ExpectedElementT>
     where
-        ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + Copy,
-        ActualContainerT: for<'a> PartialEq<&'a ExpectedContainerT> + Copy,
+        ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + Clone,
+        ActualContainerT: for<'a> PartialEq<&'a ExpectedContainerT> + Clone,
         ActualContainerT: IntoIterator<Item = ActualElementT>,
     {
-        self.expected.into_iter().filter(|i| !actual.into_iter().any(|j| j == *i)).collect()
+        self.expected.into_iter().filter(|i| !actual.clone().into_iter().any(|j| j == *i)).collect()
     }
 
     fn get_unexpected_items<ActualElementT, ActualContainerT>(
@@ -111,8 +119,8 @@ where
         actual: ActualContainerT,
     ) -> Vec<ActualElementT>
     where
-        ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + Copy,
-        ActualContainerT: for<'a> PartialEq<&'a ExpectedContainerT> + Copy,
+        ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + Clone,
+        ActualContainerT: for<'a> PartialEq<&'a ExpectedContainerT> + Clone,
         ActualContainerT: IntoIterator<Item = ActualElementT>,
     {
         actual.into_iter().filter(|i| !self.expected.into_iter().any(|j| i == &j)).collect()
@@ -61,7 +61,7 @@ pub struct ContainsRegexMatcher {
     regex: Regex,
 }
 
-impl<ActualT: AsRef<str> + Debug + Copy> Matcher<ActualT> for ContainsRegexMatcher {
+impl<ActualT: AsRef<str> + Debug + Clone> Matcher<ActualT> for ContainsRegexMatcher {
     fn matches(&self, actual: ActualT) -> MatcherResult {
         self.regex.is_match(actual.as_ref()).into()
     }
@@ -0,0 +1,329 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macros are documented in the matchers module.
+#![doc(hidden)]
+
+/// Matches a container containing elements matched by the given matchers in
+/// the same order, possibly with other elements between them.
+///
+/// Each matcher must match a distinct element of the actual value, and the
+/// elements matched by successive matchers must appear in the same order as
+/// the matchers.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_subsequence![eq(&1), eq(&3), gt(&4)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_subsequence![eq(&3), eq(&1)])?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// When the match fails, the explanation shows the longest prefix of the
+/// matchers which could be matched, the elements they matched, and the first
+/// matcher for which no later element could be found:
+///
+/// ```text
+/// Actual: [1, 2, 3, 4, 5],
+///   which contains the first 1 of 2 expected elements in order, at #2,
+///   but no later element is equal to 1
+/// ```
+///
+/// The actual value must be a container such as a `&Vec`, an array, or a slice.
+/// More precisely, the actual value must implement [`IntoIterator`]. If an
+/// inner matcher is `eq(...)`, it can be omitted, as in
+/// [`elements_are!`][crate::matchers::elements_are]. A `Vec` of
+/// [`BoxedMatcher`][crate::matchers::BoxedMatcher] built at runtime can be
+/// passed prefixed with `..`.
+///
+/// To require the matched elements to be adjacent, use
+/// [`contains_contiguous!`][crate::matchers::contains_contiguous].
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __contains_subsequence {
+    (.. $matchers:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::ContainsSubsequence::new(
+            ::std::iter::IntoIterator::into_iter($matchers).collect())
+    }};
+
+    ($($matcher:expr),* $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::ContainsSubsequence::new(
+            vec![$($crate::matcher::MatcherBase::boxed(
+                $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
+                    $matcher
+                )
+            )),*])
+    }}
+}
+
+/// Matches a container containing a run of adjacent elements matched by the
+/// given matchers, in the same order.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_contiguous![eq(&2), eq(&3), eq(&4)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_contiguous![eq(&2), eq(&4)])?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// When the match fails, the explanation shows the longest run of elements
+/// matching a prefix of the matchers, and the element where it broke off:
+///
+/// ```text
+/// Actual: [1, 2, 3, 4, 5],
+///   whose longest partial match starts at element #1 and matches the first 1
+///   of 2 expected elements, but element #2 is 3, which isn't equal to 4
+/// ```
+///
+/// The actual value must be a container such as a `&Vec`, an array, or a slice.
+/// More precisely, the actual value must implement [`IntoIterator`]. If an
+/// inner matcher is `eq(...)`, it can be omitted, as in
+/// [`elements_are!`][crate::matchers::elements_are]. A `Vec` of
+/// [`BoxedMatcher`][crate::matchers::BoxedMatcher] built at runtime can be
+/// passed prefixed with `..`.
+///
+/// To allow other elements between the matched elements, use
+/// [`contains_subsequence!`][crate::matchers::contains_subsequence].
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __contains_contiguous {
+    (.. $matchers:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::ContainsContiguous::new(
+            ::std::iter::IntoIterator::into_iter($matchers).collect())
+    }};
+
+    ($($matcher:expr),* $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::ContainsContiguous::new(
+            vec![$($crate::matcher::MatcherBase::boxed(
+                $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
+                    $matcher
+                )
+            )),*])
+    }}
+}
+
+/// Module for use only by the declarative macros in this module.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::description::Description;
+    use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+    use crate::matchers::BoxedMatcher;
+    use std::fmt::Debug;
+
+    /// This struct is meant to be used only by the macro
+    /// `contains_subsequence!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    #[derive(MatcherBase)]
+    pub struct ContainsSubsequence<'a, T: Debug + Clone> {
+        elements: Vec<BoxedMatcher<'a, T>>,
+    }
+
+    impl<'a, T: Debug + Clone> ContainsSubsequence<'a, T> {
+        /// Factory only intended for use in the macro `contains_subsequence!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        #[doc(hidden)]
+        pub fn new(elements: Vec<BoxedMatcher<'a, T>>) -> Self {
+            Self { elements }
+        }
+
+        /// Returns the indexes of the actual elements matched by the longest
+        /// prefix of `self.elements` which can be matched in order.
+        ///
+        /// Matching each matcher with the earliest possible element never
+        /// prevents a later matcher from matching, so this greedy search finds
+        /// the longest prefix.
+        fn matched_indexes(&self, actual: impl IntoIterator<Item = T>) -> Vec<usize> {
+            let mut matched_indexes = vec![];
+            let mut matchers = self.elements.iter().peekable();
+            for (index, element) in actual.into_iter().enumerate() {
+                let Some(matcher) = matchers.peek() else {
+                    break;
+                };
+                if matcher.matches(element).is_match() {
+                    matched_indexes.push(index);
+                    matchers.next();
+                }
+            }
+            matched_indexes
+        }
+    }
+
+    impl<'a, T: Debug + Clone, ContainerT: Debug + Clone> Matcher<ContainerT>
+        for ContainsSubsequence<'a, T>
+    where
+        ContainerT: IntoIterator<Item = T>,
+    {
+        fn matches(&self, actual: ContainerT) -> MatcherResult {
+            (self.matched_indexes(actual).len() == self.elements.len()).into()
+        }
+
+        fn explain_match(&self, actual: ContainerT) -> Description {
+            let matched_indexes = self.matched_indexes(actual);
+            let Some(missing) = self.elements.get(matched_indexes.len()) else {
+                return "which contains all expected elements in order".into();
+            };
+            let missing = missing.describe(MatcherResult::Match);
+            match matched_indexes.as_slice() {
+                [] => format!("which contains no element that {missing}").into(),
+                matched_indexes => format!(
+                    "which contains the first {} of {} expected elements in order, at {},\n\
+                     but no later element {missing}",
+                    matched_indexes.len(),
+                    self.elements.len(),
+                    matched_indexes
+                        .iter()
+                        .map(|index| format!("#{index}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+                .into(),
+            }
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> Description {
+            format!(
+                "{} a subsequence of elements:\n{}",
+                if matcher_result.into() { "contains" } else { "doesn't contain" },
+                describe_elements(&self.elements)
+            )
+            .into()
+        }
+    }
+
+    /// This struct is meant to be used only by the macro
+    /// `contains_contiguous!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    #[derive(MatcherBase)]
+    pub struct ContainsContiguous<'a, T: Debug + Clone> {
+        elements: Vec<BoxedMatcher<'a, T>>,
+    }
+
+    impl<'a, T: Debug + Clone> ContainsContiguous<'a, T> {
+        /// Factory only intended for use in the macro `contains_contiguous!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        #[doc(hidden)]
+        pub fn new(elements: Vec<BoxedMatcher<'a, T>>) -> Self {
+            Self { elements }
+        }
+
+        /// Returns the start index and the length of the longest run of
+        /// actual elements matched by a prefix of `self.elements`, preferring
+        /// the earliest run among those of the same length.
+        fn longest_run(&self, actual: &[T]) -> (usize, usize) {
+            let mut longest = (0, 0);
+            for start in 0..actual.len() {
+                let length = actual[start..]
+                    .iter()
+                    .zip(self.elements.iter())
+                    .take_while(|(element, matcher)| matcher.matches((*element).clone()).is_match())
+                    .count();
+                if length > longest.1 {
+                    longest = (start, length);
+                }
+                if length == self.elements.len() {
+                    break;
+                }
+            }
+            longest
+        }
+    }
+
+    impl<'a, T: Debug + Clone, ContainerT: Debug + Clone> Matcher<ContainerT>
+        for ContainsContiguous<'a, T>
+    where
+        ContainerT: IntoIterator<Item = T>,
+    {
+        fn matches(&self, actual: ContainerT) -> MatcherResult {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            (self.longest_run(&actual).1 == self.elements.len()).into()
+        }
+
+        fn explain_match(&self, actual: ContainerT) -> Description {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            let (start, length) = self.longest_run(&actual);
+            let Some(missing) = self.elements.get(length) else {
+                return format!(
+                    "which contains all expected elements contiguously, starting at element \
+                     #{start}"
+                )
+                .into();
+            };
+            if length == 0 {
+                return format!(
+                    "which contains no element that {}",
+                    missing.describe(MatcherResult::Match)
+                )
+                .into();
+            }
+            let header = format!(
+                "whose longest partial match starts at element #{start} and matches the first \
+                 {length} of {} expected elements",
+                self.elements.len()
+            );
+            match actual.get(start + length) {
+                Some(element) => format!(
+                    "{header}, but element #{} is {element:?}, {}",
+                    start + length,
+                    missing.explain_match(element.clone())
+                )
+                .into(),
+                None => format!(
+                    "{header}, but the container ends before an element that {}",
+                    missing.describe(MatcherResult::Match)
+                )
+                .into(),
+            }
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> Description {
+            format!(
+                "{} a contiguous run of elements:\n{}",
+                if matcher_result.into() { "contains" } else { "doesn't contain" },
+                describe_elements(&self.elements)
+            )
+            .into()
+        }
+    }
+
+    fn describe_elements<T: Debug + Clone>(elements: &[BoxedMatcher<T>]) -> Description {
+        elements
+            .iter()
+            .map(|matcher| matcher.describe(MatcherResult::Match))
+            .collect::<Description>()
+            .enumerate()
+            .indent()
+    }
+}
@@ -42,7 +42,7 @@ pub struct DerefsTo<InnerT> {
 impl<'a, ActualT, ExpectedT, Inner> Matcher<&'a ActualT> for DerefsTo<Inner>
 where
     ActualT: Deref<Target = ExpectedT> + Debug,
-    ExpectedT: Copy + Debug + 'a,
+    ExpectedT: Clone + Debug + 'a,
     Inner: Matcher<&'a ExpectedT>,
 {
     fn matches(&self, actual: &'a ActualT) -> MatcherResult {
@@ -56,6 +56,14 @@ where
     fn explain_match(&self, actual: &'a ActualT) -> Description {
         self.inner.explain_match(actual.deref())
     }
+
+    fn match_and_explain(
+        &self,
+        actual: &'a ActualT,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        self.inner.match_and_explain(actual.deref(), then)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,101 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module.
+#![doc(hidden)]
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherBase, MatcherResult},
+    matcher_support::__internal_unstable_do_not_depend_on_these::negated_description,
+};
+use std::{borrow::Cow, fmt::Debug};
+
+/// Matcher created by [`MatcherBase::described_as`].
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[derive(MatcherBase)]
+pub struct DescribedAsMatcher<InnerMatcherT> {
+    inner: InnerMatcherT,
+    description: Cow<'static, str>,
+}
+
+impl<InnerMatcherT> DescribedAsMatcher<InnerMatcherT> {
+    pub fn new(inner: InnerMatcherT, description: Cow<'static, str>) -> Self {
+        Self { inner, description }
+    }
+}
+
+impl<T: Debug + Clone, InnerMatcherT: Matcher<T>> Matcher<T> for DescribedAsMatcher<InnerMatcherT> {
+    fn matches(&self, actual: T) -> MatcherResult {
+        self.inner.matches(actual)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => self.description.clone().into(),
+            MatcherResult::NoMatch => negated_description(&self.description).into(),
+        }
+    }
+
+    fn explain_match(&self, actual: T) -> Description {
+        self.inner.explain_match(actual)
+    }
+
+    fn match_and_explain(
+        &self,
+        actual: T,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        self.inner.match_and_explain(actual, then)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_like_inner_matcher() -> Result<()> {
+        verify_that!(8080, all![ge(1024), le(65535)].described_as("is a valid port"))
+    }
+
+    #[test]
+    fn replaces_description() -> Result<()> {
+        let result = verify_that!(80, all![ge(1024), le(65535)].described_as("is a valid port"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: is a valid port
+                Actual: 80,
+                  which is less than 1024"
+            ))))
+        )
+    }
+
+    #[test]
+    fn derives_negated_description() -> Result<()> {
+        let matcher = eq(1).described_as("is one");
+
+        verify_that!(
+            Matcher::<i32>::describe(&matcher, MatcherResult::NoMatch),
+            displays_as(eq("isn't one"))
+        )
+    }
+}
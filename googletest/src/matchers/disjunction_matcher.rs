@@ -17,7 +17,7 @@
 
 use crate::{
     description::Description,
-    matcher::{Matcher, MatcherBase, MatcherResult},
+    matcher::{explain_with_match_and_explain, Matcher, MatcherBase, MatcherResult},
 };
 use std::fmt::Debug;
 
@@ -47,30 +47,44 @@ impl<M1, M2> DisjunctionMatcher<M1, M2> {
     }
 }
 
-impl<T: Debug + Copy, M1: Matcher<T>, M2: Matcher<T>> Matcher<T> for DisjunctionMatcher<M1, M2> {
+impl<T: Debug + Clone, M1: Matcher<T>, M2: Matcher<T>> Matcher<T> for DisjunctionMatcher<M1, M2> {
     fn matches(&self, actual: T) -> MatcherResult {
-        match (self.m1.matches(actual), self.m2.matches(actual)) {
+        match (self.m1.matches(actual.clone()), self.m2.matches(actual)) {
             (MatcherResult::NoMatch, MatcherResult::NoMatch) => MatcherResult::NoMatch,
             _ => MatcherResult::Match,
         }
     }
 
     fn explain_match(&self, actual: T) -> Description {
-        match (self.m1.matches(actual), self.m2.matches(actual)) {
-            (MatcherResult::NoMatch, MatcherResult::Match) => self.m1.explain_match(actual),
-            (MatcherResult::Match, MatcherResult::NoMatch) => self.m2.explain_match(actual),
-            (_, _) => {
-                let m1_description = self.m1.explain_match(actual);
-                if m1_description.is_disjunction_description() {
-                    m1_description.nested(self.m2.explain_match(actual))
-                } else {
-                    Description::new()
-                        .bullet_list()
-                        .collect([m1_description, self.m2.explain_match(actual)])
-                        .disjunction_description()
-                }
-            }
-        }
+        explain_with_match_and_explain(self, actual)
+    }
+
+    fn match_and_explain(
+        &self,
+        actual: T,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        self.m1.match_and_explain(actual.clone(), &mut |m1_result, m1_explanation| {
+            self.m2.match_and_explain(actual.clone(), &mut |m2_result, m2_explanation| {
+                then((m1_result.is_match() || m2_result.is_match()).into(), &|| match (
+                    m1_result, m2_result,
+                ) {
+                    (MatcherResult::NoMatch, MatcherResult::Match) => m1_explanation(),
+                    (MatcherResult::Match, MatcherResult::NoMatch) => m2_explanation(),
+                    (_, _) => {
+                        let m1_description = m1_explanation();
+                        if m1_description.is_disjunction_description() {
+                            m1_description.nested(m2_explanation())
+                        } else {
+                            Description::new()
+                                .bullet_list()
+                                .collect([m1_description, m2_explanation()])
+                                .disjunction_description()
+                        }
+                    }
+                })
+            })
+        })
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> Description {
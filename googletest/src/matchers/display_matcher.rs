@@ -33,7 +33,7 @@ pub struct DisplayMatcher<InnerMatcher> {
     inner: InnerMatcher,
 }
 
-impl<T: Debug + Display + Copy, InnerMatcher: for<'a> Matcher<&'a str>> Matcher<T>
+impl<T: Debug + Display + Clone, InnerMatcher: for<'a> Matcher<&'a str>> Matcher<T>
     for DisplayMatcher<InnerMatcher>
 {
     fn matches(&self, actual: T) -> MatcherResult {
@@ -114,7 +114,9 @@ mod tests {
                     Difference(-actual / +expected):
                      123
                     -234
+                    ?^
                     +345
+                    ?  ^
                 "
             ))))
         )
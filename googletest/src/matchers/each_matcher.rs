@@ -58,7 +58,7 @@ pub struct EachMatcher<MatcherT> {
     inner: MatcherT,
 }
 
-impl<ElementT: Debug + Copy, ActualT: Debug + Copy, MatcherT> Matcher<ActualT>
+impl<ElementT: Debug + Clone, ActualT: Debug + Clone, MatcherT> Matcher<ActualT>
     for EachMatcher<MatcherT>
 where
     ActualT: IntoIterator<Item = ElementT>,
@@ -76,8 +76,9 @@ where
     fn explain_match(&self, actual: ActualT) -> Description {
         let mut non_matching_elements = Vec::new();
         for (index, element) in actual.into_iter().enumerate() {
-            if self.inner.matches(element).is_no_match() {
-                non_matching_elements.push((index, element, self.inner.explain_match(element)));
+            if self.inner.matches(element.clone()).is_no_match() {
+                let explanation = self.inner.explain_match(element.clone());
+                non_matching_elements.push((index, element, explanation));
             }
         }
         if non_matching_elements.is_empty() {
@@ -96,7 +97,7 @@ where
             .join(", ");
         let element_explanations = non_matching_elements
             .iter()
-            .map(|&(_, element, ref explanation)| format!("{element:?}, {explanation}"))
+            .map(|(_, element, explanation)| format!("{element:?}, {explanation}"))
             .collect::<Description>()
             .indent();
         format!("whose elements {failed_indexes} don't match\n{element_explanations}").into()
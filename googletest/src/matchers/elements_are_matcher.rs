@@ -74,6 +74,34 @@
 /// #     .unwrap();
 /// ```
 ///
+/// When the number of matchers is only known at runtime, collect them into a
+/// `Vec` of [`BoxedMatcher`][crate::matchers::BoxedMatcher] and pass it
+/// prefixed with `..`:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// let expected = vec![1, 2, 3];
+/// let matchers: Vec<BoxedMatcher<&i32>> = expected.iter().map(|e| eq(e).boxed()).collect();
+/// verify_that!(vec![1, 2, 3], elements_are![..matchers])
+/// #     .unwrap();
+/// ```
+///
+/// Use `_` in place of a matcher to accept any single element, and `..` to
+/// accept any number of elements, including none:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// verify_that!(vec![1, 2, 3, 4, 5], elements_are![eq(&1), .., eq(&5)])
+/// #     .unwrap();
+/// verify_that!(vec![1, 4, 3], elements_are![_, gt(&3), ..])
+/// #     .unwrap();
+/// ```
+///
+/// When `..` is used, a failure is explained by aligning the elements with the
+/// matchers so that as few elements as possible are reported as mismatching,
+/// missing, or unexpected. The wildcards are not available in the `[...]`
+/// shorthand of [`verify_that!`].
+///
 /// Do not use this with unordered containers, since that will lead to flaky
 /// tests. Use
 /// [`unordered_elements_are!`][crate::matchers::unordered_elements_are]
@@ -86,14 +114,80 @@
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __elements_are {
-    ($($matcher:expr),* $(,)?) => {{
+    (.. $matchers:expr $(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::ElementsAre::new(
-            vec![$(Box::new(
-                $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
-                    $matcher
-                )
-            )),*])
-    }}
+            ::std::iter::IntoIterator::into_iter($matchers).collect())
+    }};
+
+    // `_` is not an expression, so only lists containing it are handled
+    // element by element below. `..` is recognised by its type instead.
+    ($($matcher:expr),* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::matchers::__internal_unstable_do_not_depend_on_these::MatcherPatternKind as _;
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::ElementsAre::with_patterns(
+            vec![$(
+                match $matcher {
+                    pattern => {
+                        $crate::matchers::__internal_unstable_do_not_depend_on_these::PatternWrapper(
+                            &pattern,
+                        )
+                        .pattern_kind()
+                        .pattern(
+                            $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
+                                pattern
+                            )
+                        )
+                    }
+                }
+            ),*])
+    }};
+
+    (@patterns [$($patterns:tt)*]) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::ElementsAre::with_patterns(
+            vec![$($patterns)*])
+    }};
+
+    (@patterns [$($patterns:tt)*] _ $(, $($rest:tt)*)?) => {
+        $crate::__elements_are!(
+            @patterns [
+                $($patterns)*
+                $crate::matchers::__internal_unstable_do_not_depend_on_these::ElementPattern::Matcher(
+                    $crate::matcher::MatcherBase::boxed($crate::matchers::anything())
+                ),
+            ]
+            $($($rest)*)?
+        )
+    };
+
+    (@patterns [$($patterns:tt)*] .. $(, $($rest:tt)*)?) => {
+        $crate::__elements_are!(
+            @patterns [
+                $($patterns)*
+                $crate::matchers::__internal_unstable_do_not_depend_on_these::ElementPattern::Rest,
+            ]
+            $($($rest)*)?
+        )
+    };
+
+    (@patterns [$($patterns:tt)*] $matcher:expr $(, $($rest:tt)*)?) => {
+        $crate::__elements_are!(
+            @patterns [
+                $($patterns)*
+                $crate::matchers::__internal_unstable_do_not_depend_on_these::ElementPattern::Matcher(
+                    $crate::matcher::MatcherBase::boxed(
+                        $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
+                            $matcher
+                        )
+                    )
+                ),
+            ]
+            $($($rest)*)?
+        )
+    };
+
+    ($($patterns:tt)*) => {
+        $crate::__elements_are!(@patterns [] $($patterns)*)
+    };
 }
 
 /// Module for use only by the procedural macros in this module.
@@ -104,34 +198,262 @@ pub mod internal {
     use crate::description::Description;
     use crate::matcher::{Matcher, MatcherBase, MatcherResult};
     use crate::matcher_support::zipped_iterator::zip;
+    use crate::matchers::BoxedMatcher;
     use std::fmt::Debug;
+    use std::ops::RangeFull;
+
+    /// One of the arguments of the macro `elements_are!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub enum ElementPattern<'a, T: Debug + Clone> {
+        /// A matcher for exactly one element.
+        Matcher(BoxedMatcher<'a, T>),
+        /// `..`: any number of elements.
+        Rest,
+    }
+
+    /// Wraps an argument of the macro `elements_are!` to tell `..` apart
+    /// from matchers.
+    ///
+    /// This uses the same pattern as `auto_eq!`: the inherent method
+    /// `pattern_kind` for `..` takes precedence over the one of
+    /// [`MatcherPatternKind`].
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct PatternWrapper<T>(pub T);
+
+    impl PatternWrapper<&RangeFull> {
+        #[inline]
+        pub fn pattern_kind(&self) -> RestTag {
+            RestTag
+        }
+    }
+
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub trait MatcherPatternKind {
+        #[inline]
+        fn pattern_kind(&self) -> MatcherPatternTag {
+            MatcherPatternTag
+        }
+    }
+
+    impl<T> MatcherPatternKind for PatternWrapper<T> {}
+
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct RestTag;
+
+    impl RestTag {
+        #[inline]
+        pub fn pattern<'a, T: Debug + Clone, IgnoredT>(self, _: IgnoredT) -> ElementPattern<'a, T> {
+            ElementPattern::Rest
+        }
+    }
+
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct MatcherPatternTag;
+
+    impl MatcherPatternTag {
+        #[inline]
+        pub fn pattern<'a, T: Debug + Clone, MatcherT: Matcher<T> + 'a>(
+            self,
+            matcher: MatcherT,
+        ) -> ElementPattern<'a, T> {
+            ElementPattern::Matcher(matcher.boxed())
+        }
+    }
+
+    impl<'a, T: Debug + Clone> ElementPattern<'a, T> {
+        fn describe(&self) -> Description {
+            match self {
+                ElementPattern::Matcher(matcher) => matcher.describe(MatcherResult::Match),
+                ElementPattern::Rest => "any number of elements".into(),
+            }
+        }
+    }
 
     /// This struct is meant to be used only by the macro `elements_are!`.
     ///
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
     #[derive(MatcherBase)]
-    pub struct ElementsAre<'a, T: Debug + Copy> {
-        elements: Vec<Box<dyn Matcher<T> + 'a>>,
+    pub struct ElementsAre<'a, T: Debug + Clone> {
+        elements: Vec<ElementPattern<'a, T>>,
     }
 
-    impl<'a, T: Debug + Copy> ElementsAre<'a, T> {
+    impl<'a, T: Debug + Clone> ElementsAre<'a, T> {
         /// Factory only intended for use in the macro `elements_are!`.
         ///
         /// **For internal use only. API stablility is not guaranteed!**
         #[doc(hidden)]
-        pub fn new(elements: Vec<Box<dyn Matcher<T> + 'a>>) -> Self {
+        pub fn new(elements: Vec<BoxedMatcher<'a, T>>) -> Self {
+            Self { elements: elements.into_iter().map(ElementPattern::Matcher).collect() }
+        }
+
+        /// Factory only intended for use in the macro `elements_are!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        #[doc(hidden)]
+        pub fn with_patterns(elements: Vec<ElementPattern<'a, T>>) -> Self {
             Self { elements }
         }
+
+        fn has_rest(&self) -> bool {
+            self.elements.iter().any(|element| matches!(element, ElementPattern::Rest))
+        }
+
+        /// Returns the cheapest alignment of `actual` with the patterns, where
+        /// each mismatching, missing, or unexpected element costs 1.
+        ///
+        /// This is an edit distance computation in which `..` absorbs any
+        /// number of elements for free.
+        fn align(&self, actual: &[T]) -> Vec<Step> {
+            let patterns = self.elements.len();
+            let elements = actual.len();
+            let is_match = self
+                .elements
+                .iter()
+                .map(|pattern| match pattern {
+                    ElementPattern::Matcher(matcher) => actual
+                        .iter()
+                        .map(|element| matcher.matches(element.clone()).is_match())
+                        .collect(),
+                    ElementPattern::Rest => vec![],
+                })
+                .collect::<Vec<Vec<bool>>>();
+
+            // cost[p][a] is the cost of aligning patterns[p..] with actual[a..].
+            let mut cost = vec![vec![0usize; elements + 1]; patterns + 1];
+            for p in (0..=patterns).rev() {
+                for a in (0..=elements).rev() {
+                    cost[p][a] = if p == patterns {
+                        elements - a
+                    } else if matches!(self.elements[p], ElementPattern::Rest) {
+                        if a < elements {
+                            cost[p + 1][a].min(cost[p][a + 1])
+                        } else {
+                            cost[p + 1][a]
+                        }
+                    } else {
+                        let mut best = 1 + cost[p + 1][a];
+                        if a < elements {
+                            best = best
+                                .min(usize::from(!is_match[p][a]) + cost[p + 1][a + 1])
+                                .min(1 + cost[p][a + 1]);
+                        }
+                        best
+                    };
+                }
+            }
+
+            let mut steps = vec![];
+            let (mut p, mut a) = (0, 0);
+            while p < patterns || a < elements {
+                let current = cost[p][a];
+                if p == patterns {
+                    steps.push(Step::Unexpected(a));
+                    a += 1;
+                } else if matches!(self.elements[p], ElementPattern::Rest) {
+                    // Absorb as few elements as possible, so that the elements
+                    // are rather reported against the following matchers.
+                    if cost[p + 1][a] == current {
+                        p += 1;
+                    } else {
+                        a += 1;
+                    }
+                } else if a < elements
+                    && usize::from(!is_match[p][a]) + cost[p + 1][a + 1] == current
+                {
+                    if !is_match[p][a] {
+                        steps.push(Step::Mismatch(p, a));
+                    }
+                    p += 1;
+                    a += 1;
+                } else if 1 + cost[p + 1][a] == current {
+                    steps.push(Step::Missing(p));
+                    p += 1;
+                } else {
+                    steps.push(Step::Unexpected(a));
+                    a += 1;
+                }
+            }
+            steps
+        }
+
+        fn explain_alignment(&self, actual: &[T]) -> Description {
+            let mismatches = self
+                .align(actual)
+                .into_iter()
+                .map(|step| match step {
+                    Step::Mismatch(p, a) => {
+                        let ElementPattern::Matcher(matcher) = &self.elements[p] else {
+                            unreachable!("`..` never mismatches");
+                        };
+                        format!(
+                            "element #{a} is {:?}, {}, for matcher #{p}",
+                            actual[a],
+                            matcher.explain_match(actual[a].clone())
+                        )
+                    }
+                    Step::Missing(p) => {
+                        format!(
+                            "no element is left for matcher #{p}: {}",
+                            self.elements[p].describe()
+                        )
+                    }
+                    Step::Unexpected(a) => {
+                        format!("element #{a} is {:?}, which is unexpected", actual[a])
+                    }
+                })
+                .collect::<Vec<_>>();
+            explain_mismatches(mismatches, || "whose elements all match".into())
+        }
+    }
+
+    /// A step of the alignment of the actual elements with the patterns which
+    /// does not match.
+    enum Step {
+        /// The actual element at the given index is aligned with the matcher
+        /// at the given index, which does not match it.
+        Mismatch(usize, usize),
+        /// No actual element is aligned with the matcher at the given index.
+        Missing(usize),
+        /// The actual element at the given index is not aligned with any
+        /// pattern.
+        Unexpected(usize),
+    }
+
+    fn explain_mismatches(
+        mismatches: Vec<String>,
+        on_no_mismatch: impl FnOnce() -> Description,
+    ) -> Description {
+        if mismatches.is_empty() {
+            on_no_mismatch()
+        } else if mismatches.len() == 1 {
+            let mismatches = mismatches.into_iter().collect::<Description>();
+            format!("where {mismatches}").into()
+        } else {
+            let mismatches = mismatches.into_iter().collect::<Description>();
+            format!("where:\n{}", mismatches.bullet_list().indent()).into()
+        }
     }
 
-    impl<'a, T: Debug + Copy, ContainerT: Debug + Copy> Matcher<ContainerT> for ElementsAre<'a, T>
+    impl<'a, T: Debug + Clone, ContainerT: Debug + Clone> Matcher<ContainerT> for ElementsAre<'a, T>
     where
         ContainerT: IntoIterator<Item = T>,
     {
         fn matches(&self, actual: ContainerT) -> MatcherResult {
+            if self.has_rest() {
+                let actual = actual.into_iter().collect::<Vec<_>>();
+                return self.align(&actual).is_empty().into();
+            }
             let mut zipped_iterator = zip(actual.into_iter(), self.elements.iter());
             for (a, e) in zipped_iterator.by_ref() {
+                let ElementPattern::Matcher(e) = e else { unreachable!("checked by `has_rest`") };
                 if e.matches(a).is_no_match() {
                     return MatcherResult::NoMatch;
                 }
@@ -144,27 +466,26 @@ pub mod internal {
         }
 
         fn explain_match(&self, actual: ContainerT) -> Description {
+            if self.has_rest() {
+                return self.explain_alignment(&actual.into_iter().collect::<Vec<_>>());
+            }
             let actual_iterator = actual.into_iter();
             let mut zipped_iterator = zip(actual_iterator, self.elements.iter());
             let mut mismatches = Vec::new();
             for (idx, (a, e)) in zipped_iterator.by_ref().enumerate() {
-                if e.matches(a).is_no_match() {
-                    mismatches.push(format!("element #{idx} is {a:?}, {}", e.explain_match(a)));
+                let ElementPattern::Matcher(e) = e else { unreachable!("checked by `has_rest`") };
+                if e.matches(a.clone()).is_no_match() {
+                    mismatches
+                        .push(format!("element #{idx} is {a:?}, {}", e.explain_match(a.clone())));
                 }
             }
-            if mismatches.is_empty() {
+            explain_mismatches(mismatches, move || {
                 if !zipped_iterator.has_size_mismatch() {
                     "whose elements all match".into()
                 } else {
                     format!("whose size is {}", zipped_iterator.left_size()).into()
                 }
-            } else if mismatches.len() == 1 {
-                let mismatches = mismatches.into_iter().collect::<Description>();
-                format!("where {mismatches}").into()
-            } else {
-                let mismatches = mismatches.into_iter().collect::<Description>();
-                format!("where:\n{}", mismatches.bullet_list().indent()).into()
-            }
+            })
         }
 
         fn describe(&self, matcher_result: MatcherResult) -> Description {
@@ -174,7 +495,7 @@ pub mod internal {
                 &self
                     .elements
                     .iter()
-                    .map(|matcher| matcher.describe(MatcherResult::Match))
+                    .map(|element| element.describe())
                     .collect::<Description>()
                     .enumerate()
                     .indent()
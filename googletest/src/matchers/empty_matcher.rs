@@ -45,7 +45,7 @@ pub fn empty() -> EmptyMatcher {
 #[derive(MatcherBase)]
 pub struct EmptyMatcher;
 
-impl<T: Debug + Copy> Matcher<T> for EmptyMatcher
+impl<T: Debug + Clone> Matcher<T> for EmptyMatcher
 where
     T: IntoIterator,
 {
@@ -15,7 +15,9 @@
 use crate::description::Description;
 use crate::matcher::{Matcher, MatcherBase, MatcherResult};
 use crate::matcher_support::edit_distance;
-use crate::matcher_support::summarize_diff::create_diff;
+use crate::matcher_support::structural_diff::create_structural_diff;
+use crate::matcher_support::summarize_diff::{create_diff, create_inline_diff};
+use crate::matchers::BoxedMatcher;
 
 use std::fmt::Debug;
 
@@ -71,6 +73,9 @@ use std::fmt::Debug;
 /// options on how equality is checked through the
 /// [`StrMatcherConfigurator`][crate::matchers::str_matcher::StrMatcherConfigurator]
 /// extension trait, which is implemented for this matcher.
+///
+/// When comparing maps, prefer [`map_eq`][crate::matchers::map_eq], whose
+/// failure message lists the differences key by key.
 pub fn eq<T>(expected: T) -> EqMatcher<T> {
     EqMatcher { expected }
 }
@@ -78,12 +83,23 @@ pub fn eq<T>(expected: T) -> EqMatcher<T> {
 /// A matcher which matches a value equal to `expected`.
 ///
 /// See [`eq`].
-#[derive(MatcherBase)]
 pub struct EqMatcher<T> {
     pub(crate) expected: T,
 }
 
-impl<T: Debug, A: Debug + Copy + PartialEq<T>> Matcher<A> for EqMatcher<T> {
+// Not derived, so that boxing records the expected value as a hint for the
+// hashing fast path of `unordered_elements_are!`.
+impl<T: Debug> MatcherBase for EqMatcher<T> {
+    fn boxed<'a, A: Debug + Clone>(self) -> BoxedMatcher<'a, A>
+    where
+        Self: Matcher<A> + Sized + 'a,
+    {
+        let hint = format!("{:?}", self.expected);
+        BoxedMatcher::new(self).with_equality_hint(hint)
+    }
+}
+
+impl<T: Debug, A: Debug + Clone + PartialEq<T>> Matcher<A> for EqMatcher<T> {
     fn matches(&self, actual: A) -> MatcherResult {
         (actual == self.expected).into()
     }
@@ -112,6 +128,13 @@ impl<T: Debug, A: Debug + Copy + PartialEq<T>> Matcher<A> for EqMatcher<T> {
                 &to_display_output(&expected_debug).unwrap(),
                 edit_distance::Mode::Exact,
             )
+        } else if is_single_line_string_debug(&actual_debug)
+            && is_single_line_string_debug(&expected_debug)
+        {
+            create_inline_diff(&actual_debug, &expected_debug)
+        } else if let Some(structural_diff) = create_structural_diff(&actual_debug, &expected_debug)
+        {
+            structural_diff.into()
         } else {
             create_diff(&actual_debug, &expected_debug, edit_distance::Mode::Exact)
         };
@@ -127,6 +150,14 @@ fn is_multiline_string_debug(string: &str) -> bool {
         && string.contains("\\n")
 }
 
+fn is_single_line_string_debug(string: &str) -> bool {
+    string.len() >= 2
+        && string.starts_with('"')
+        && string.ends_with('"')
+        && !string.contains('\n')
+        && !string.contains("\\n")
+}
+
 fn to_display_output(string: &str) -> Option<String> {
     Some(string.strip_prefix('"')?.strip_suffix('"')?.split("\\n").collect::<Vec<_>>().join("\n"))
 }
@@ -179,14 +210,44 @@ mod tests {
               Difference(-actual / +expected):
                Strukt {
               -    int: 123,
+              ?         ^ ^
               +    int: 321,
+              ?         ^ ^
               -    string: \"something\",
+              ?                 ^^^ ^
               +    string: \"someone\",
+              ?                 ^ ^
                }
             "})))
         )
     }
 
+    #[test]
+    fn eq_nested_struct_debug_diff_by_path() -> Result<()> {
+        #[derive(Debug, PartialEq)]
+        struct Config {
+            servers: Vec<Server>,
+        }
+        #[derive(Debug, PartialEq)]
+        struct Server {
+            port: u16,
+        }
+
+        let result = verify_that!(
+            Config { servers: vec![Server { port: 80 }, Server { port: 8081 }] },
+            eq(&Config { servers: vec![Server { port: 80 }, Server { port: 8080 }] })
+        );
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            "
+            which isn't equal to Config { servers: [Server { port: 80 }, Server { port: 8080 }] }
+              Differences by path:
+                .servers[1].port: expected 8080, actual 8081
+            "})))
+        )
+    }
+
     #[test]
     fn eq_vec_debug_diff() -> Result<()> {
         let result = verify_that!(vec![1, 2, 3], eq(&vec![1, 3, 4]));
@@ -365,11 +426,42 @@ mod tests {
    First line
   -Second line
   +Second lines
+  ?           ^
    Third line"
             )))
         )
     }
 
+    #[test]
+    fn match_explanation_contains_inline_diff_of_single_line_strings() -> Result<()> {
+        let result = verify_that!(
+            "The quick brown fox jumps over the lazy dog",
+            eq("The quick brown fox jumped over the lazy dog")
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "
+  Difference(-actual / +expected):
+  -...quick brown fox jumps over the lazy dog\"
+  +...quick brown fox jumped over the lazy dog\"
+                          ^^
+"
+            )))
+        )
+    }
+
+    #[test]
+    fn match_explanation_does_not_show_inline_diff_of_short_strings() -> Result<()> {
+        let result = verify_that!("A string", eq("A strong"));
+
+        verify_that!(
+            result,
+            err(displays_as(not(contains_substring("Difference(-actual / +expected):"))))
+        )
+    }
+
     #[test]
     fn match_explanation_does_not_show_diff_if_actual_value_is_single_line() -> Result<()> {
         let result = verify_that!(
@@ -47,8 +47,8 @@ pub struct ErrMatcher<InnerMatcherT> {
     inner: InnerMatcherT,
 }
 
-impl<T: Debug + Copy, E: Debug + Copy, InnerMatcherT: Matcher<E>> Matcher<std::result::Result<T, E>>
-    for ErrMatcher<InnerMatcherT>
+impl<T: Debug + Clone, E: Debug + Clone, InnerMatcherT: Matcher<E>>
+    Matcher<std::result::Result<T, E>> for ErrMatcher<InnerMatcherT>
 {
     fn matches(&self, actual: std::result::Result<T, E>) -> MatcherResult {
         actual.err().map(|v| self.inner.matches(v)).unwrap_or(MatcherResult::NoMatch)
@@ -63,6 +63,19 @@ impl<T: Debug + Copy, E: Debug + Copy, InnerMatcherT: Matcher<E>> Matcher<std::r
         }
     }
 
+    fn match_and_explain(
+        &self,
+        actual: std::result::Result<T, E>,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        match actual {
+            Err(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+                then(result, &|| Description::new().text("which is an error").nested(explanation()))
+            }),
+            Ok(_) => then(MatcherResult::NoMatch, &|| "which is a success".into()),
+        }
+    }
+
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         match matcher_result {
             MatcherResult::Match => {
@@ -93,6 +106,19 @@ impl<'a, T: Debug, E: Debug, InnerMatcherT: Matcher<&'a E>> Matcher<&'a std::res
         }
     }
 
+    fn match_and_explain(
+        &self,
+        actual: &'a std::result::Result<T, E>,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        match actual {
+            Err(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+                then(result, &|| Description::new().text("which is an error").nested(explanation()))
+            }),
+            Ok(_) => then(MatcherResult::NoMatch, &|| "which is a success".into()),
+        }
+    }
+
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         match matcher_result {
             MatcherResult::Match => {
@@ -0,0 +1,97 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module.
+#![doc(hidden)]
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherBase, MatcherResult},
+};
+use std::fmt::Debug;
+
+/// Matcher created by [`MatcherBase::explained_by`].
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[derive(MatcherBase)]
+pub struct ExplainedByMatcher<InnerMatcherT, ExplanationT> {
+    inner: InnerMatcherT,
+    explanation: ExplanationT,
+}
+
+impl<InnerMatcherT, ExplanationT> ExplainedByMatcher<InnerMatcherT, ExplanationT> {
+    pub fn new(inner: InnerMatcherT, explanation: ExplanationT) -> Self {
+        Self { inner, explanation }
+    }
+}
+
+impl<
+        T: Debug + Clone,
+        InnerMatcherT: Matcher<T>,
+        ExplanationT: Fn(T) -> DescriptionT,
+        DescriptionT: Into<Description>,
+    > Matcher<T> for ExplainedByMatcher<InnerMatcherT, ExplanationT>
+{
+    fn matches(&self, actual: T) -> MatcherResult {
+        self.inner.matches(actual)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        self.inner.describe(matcher_result)
+    }
+
+    fn explain_match(&self, actual: T) -> Description {
+        (self.explanation)(actual).into()
+    }
+
+    fn match_and_explain(
+        &self,
+        actual: T,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        self.inner.match_and_explain(actual.clone(), &mut |result, _| {
+            then(result, &|| (self.explanation)(actual.clone()).into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_like_inner_matcher() -> Result<()> {
+        verify_that!(4, eq(4).explained_by(|actual: i32| format!("which is {actual}")))
+    }
+
+    #[test]
+    fn replaces_explanation() -> Result<()> {
+        let result = verify_that!(
+            5,
+            eq(4).explained_by(|actual: i32| format!("which is off by {}", actual - 4))
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: is equal to 4
+                Actual: 5,
+                  which is off by 1"
+            ))))
+        )
+    }
+}
@@ -125,7 +125,7 @@
 /// The specification of the field follow the syntax: `(ref)? (&)?
 /// $TYPE.$FIELD`.
 /// The `&` allows to specify whether this matcher matches against an actual of
-/// type `$TYPE` (`$TYPE`` must implement `Copy`) or a `&$TYPE`.
+/// type `$TYPE` (`$TYPE` must implement `Clone`) or a `&$TYPE`.
 ///
 /// For instance:
 ///
@@ -152,7 +152,7 @@
 /// ```
 ///
 /// The `ref` allows to bind the field value by reference, which is required if
-/// the field type does not implement `Copy`.
+/// the field type does not implement `Clone`.
 ///
 /// For instance:
 ///
@@ -285,6 +285,21 @@ pub mod internal {
             }
         }
 
+        fn match_and_explain(
+            &self,
+            actual: &'a OuterT,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            match (self.field_accessor)(actual) {
+                Some(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+                    then(result, &|| {
+                        format!("which has field `{}`, {}", self.field_path, explanation()).into()
+                    })
+                }),
+                None => then(MatcherResult::NoMatch, &|| self.explain_match(actual)),
+            }
+        }
+
         fn explain_match(&self, actual: &'a OuterT) -> Description {
             if let Some(actual) = (self.field_accessor)(actual) {
                 format!(
@@ -311,23 +326,41 @@ pub mod internal {
         }
     }
 
-    impl<OuterT: Debug + Copy, InnerT: Debug + Copy, InnerMatcher: Matcher<InnerT>> Matcher<OuterT>
-        for FieldMatcher<OuterT, InnerT, InnerMatcher>
+    impl<OuterT: Debug + Clone, InnerT: Debug + Clone, InnerMatcher: Matcher<InnerT>>
+        Matcher<OuterT> for FieldMatcher<OuterT, InnerT, InnerMatcher>
     {
         fn matches(&self, actual: OuterT) -> MatcherResult {
             if let Some(value) = (self.field_accessor)(&actual) {
-                self.inner.matches(*value)
+                self.inner.matches(value.clone())
             } else {
                 MatcherResult::NoMatch
             }
         }
 
+        fn match_and_explain(
+            &self,
+            actual: OuterT,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            match (self.field_accessor)(&actual) {
+                Some(value) => {
+                    self.inner.match_and_explain(value.clone(), &mut |result, explanation| {
+                        then(result, &|| {
+                            format!("which has field `{}`, {}", self.field_path, explanation())
+                                .into()
+                        })
+                    })
+                }
+                None => then(MatcherResult::NoMatch, &|| self.explain_match(actual.clone())),
+            }
+        }
+
         fn explain_match(&self, actual: OuterT) -> Description {
             if let Some(actual) = (self.field_accessor)(&actual) {
                 format!(
                     "which has field `{}`, {}",
                     self.field_path,
-                    self.inner.explain_match(*actual)
+                    self.inner.explain_match(actual.clone())
                 )
                 .into()
             } else {
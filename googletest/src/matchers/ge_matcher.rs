@@ -83,7 +83,7 @@ pub struct GeMatcher<ExpectedT> {
     expected: ExpectedT,
 }
 
-impl<ActualT: Debug + PartialOrd<ExpectedT> + Copy, ExpectedT: Debug> Matcher<ActualT>
+impl<ActualT: Debug + PartialOrd<ExpectedT> + Clone, ExpectedT: Debug> Matcher<ActualT>
     for GeMatcher<ExpectedT>
 {
     fn matches(&self, actual: ActualT) -> MatcherResult {
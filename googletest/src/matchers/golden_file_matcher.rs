@@ -0,0 +1,491 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::internal::update_scope::updates_allowed;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use crate::matchers::str_matcher::StrMatcher;
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Matches a string equal to the contents of the given file.
+///
+/// A relative `path` is resolved against the directory of the crate under
+/// test, as given by the environment variable `CARGO_MANIFEST_DIR` which
+/// `cargo test` sets, or else against the current directory.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let inventory = "Inventory\n  apples: 3\n  pears: 5\n";
+/// verify_that!(inventory, eq_file("tests/testdata/inventory.txt"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// The file is read when the matcher is created. If the strings differ, the
+/// explanation shows a diff as for [`eq`][crate::matchers::eq] on strings.
+/// With `apples: 4` in the example above, the assertion fails with:
+///
+/// ```text
+/// Value of: inventory
+/// Expected: is equal to the contents of tests/testdata/inventory.txt
+/// Actual: "Inventory\n  apples: 4\n  pears: 5\n",
+///   which isn't equal to the contents of tests/testdata/inventory.txt
+///
+///   Difference(-actual / +expected):
+///    Inventory
+///   -  apples: 4
+///   ?          ^
+///   +  apples: 3
+///   ?          ^
+///      pears: 5
+/// ```
+///
+/// As the returned matcher is a [`StrMatcher`], all options of
+/// [`StrMatcherConfigurator`][crate::matchers::StrMatcherConfigurator] are
+/// available:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let inventory = "Inventory\r\n  apples: 3\r\n  pears: 5\r\n";
+/// verify_that!(
+///     inventory,
+///     eq_file("tests/testdata/inventory.txt").ignoring_line_endings()
+/// )?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Running the tests with the environment variable
+/// `GOOGLETEST_UPDATE_GOLDEN_FILES=1` instead writes the actual value to the
+/// file, creating or replacing it, and the assertion passes. Only assertions
+/// write the file: the matcher inside [`not`][crate::matchers::not], or one
+/// whose `matches` is called directly, never does.
+pub fn eq_file(path: impl AsRef<Path>) -> StrMatcher<String> {
+    let golden_file = GoldenFile::new(path.as_ref());
+    let contents = std::fs::read(&golden_file.path)
+        .map_err(ReadError::from)
+        .and_then(|bytes| String::from_utf8(bytes).map_err(|_| ReadError::NotUtf8));
+    let (contents, golden_file) = match contents {
+        Ok(contents) => (contents, golden_file),
+        Err(e) => (String::new(), GoldenFile { read_error: Some(e), ..golden_file }),
+    };
+    StrMatcher::with_golden_file(contents, golden_file)
+}
+
+/// Matches a byte sequence equal to the contents of the given file.
+///
+/// This is the counterpart of [`eq_file`] for values which are not text. The
+/// actual value may be anything which can be viewed as `&[u8]`, such as a
+/// `Vec<u8>` or a byte slice.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let png_signature = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+/// verify_that!(png_signature, eq_file_bytes("tests/testdata/header.bin"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// The explanation of a mismatch gives the offset of the first differing byte.
+/// Running the tests with the environment variable
+/// `GOOGLETEST_UPDATE_GOLDEN_FILES=1` instead writes the actual value to the
+/// file, creating or replacing it, and the assertion passes. Only assertions
+/// write the file: the matcher inside [`not`][crate::matchers::not], or one
+/// whose `matches` is called directly, never does.
+pub fn eq_file_bytes(path: impl AsRef<Path>) -> EqFileBytesMatcher {
+    let golden_file = GoldenFile::new(path.as_ref());
+    match std::fs::read(&golden_file.path) {
+        Ok(contents) => EqFileBytesMatcher { contents, golden_file },
+        Err(e) => EqFileBytesMatcher {
+            contents: vec![],
+            golden_file: GoldenFile { read_error: Some(e.into()), ..golden_file },
+        },
+    }
+}
+
+/// A matcher comparing a byte sequence with the contents of a file.
+///
+/// Intended only to be used from the function [`eq_file_bytes`] only.
+/// Should not be referenced by code outside this library.
+#[derive(MatcherBase)]
+pub struct EqFileBytesMatcher {
+    contents: Vec<u8>,
+    golden_file: GoldenFile,
+}
+
+impl<ActualT: AsRef<[u8]> + Debug + Clone> Matcher<ActualT> for EqFileBytesMatcher {
+    fn matches(&self, actual: ActualT) -> MatcherResult {
+        if self.golden_file.update(actual.as_ref()) {
+            return MatcherResult::Match;
+        }
+        (self.golden_file.read_error.is_none() && actual.as_ref() == self.contents).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => {
+                format!("is equal to the contents of {}", self.golden_file.display_path).into()
+            }
+            MatcherResult::NoMatch => {
+                format!("isn't equal to the contents of {}", self.golden_file.display_path).into()
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: ActualT) -> Description {
+        if let Some(explanation) = self.golden_file.explain_read_error() {
+            return explanation.into();
+        }
+        let (actual, expected) = (actual.as_ref(), self.contents.as_slice());
+        let first_difference = actual.iter().zip(expected).position(|(a, e)| a != e);
+        match first_difference {
+            Some(offset) => format!(
+                "which differs from the contents of {} first at byte {offset}: actual 0x{:02x}, \
+                expected 0x{:02x}",
+                self.golden_file.display_path, actual[offset], expected[offset]
+            )
+            .into(),
+            None if actual.len() != expected.len() => format!(
+                "which has {} bytes, but the contents of {} have {} bytes and the rest is equal",
+                actual.len(),
+                self.golden_file.display_path,
+                expected.len()
+            )
+            .into(),
+            None => format!("which is equal to the contents of {}", self.golden_file.display_path)
+                .into(),
+        }
+    }
+}
+
+// The file holding the expected value of a golden file matcher.
+pub(crate) struct GoldenFile {
+    path: PathBuf,
+    // The path as given by the test, for messages.
+    pub(crate) display_path: String,
+    // The directory against which a relative path was resolved, for messages.
+    base_dir: Option<String>,
+    // Why the file could not be read when the matcher was created, if it could
+    // not.
+    pub(crate) read_error: Option<ReadError>,
+}
+
+// Why a golden file could not be read.
+pub(crate) enum ReadError {
+    // The file does not exist yet, so the update mode can create it.
+    NotFound,
+    // The file was read by `eq_file`, but does not hold text.
+    NotUtf8,
+    Other(std::io::Error),
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(error: std::io::Error) -> Self {
+        match error.kind() {
+            ErrorKind::NotFound => ReadError::NotFound,
+            _ => ReadError::Other(error),
+        }
+    }
+}
+
+impl GoldenFile {
+    fn new(path: &Path) -> Self {
+        let (resolved_path, base_dir) = if path.is_absolute() {
+            (path.to_path_buf(), None)
+        } else if let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
+            let manifest_dir = PathBuf::from(manifest_dir);
+            let base_dir = format!("CARGO_MANIFEST_DIR {}", manifest_dir.display());
+            (manifest_dir.join(path), Some(base_dir))
+        } else {
+            let current_dir = std::env::current_dir()
+                .map(|dir| format!("the current directory {}", dir.display()))
+                .unwrap_or_else(|_| "the current directory".to_string());
+            (path.to_path_buf(), Some(current_dir))
+        };
+        Self {
+            path: resolved_path,
+            display_path: path.display().to_string(),
+            base_dir,
+            read_error: None,
+        }
+    }
+
+    // Writes `actual` to the file if golden files are to be updated, and returns
+    // whether it did. Only an assertion evaluating the matcher other than
+    // through `not` writes the file.
+    pub(crate) fn update(&self, actual: &[u8]) -> bool {
+        if !UPDATE_GOLDEN_FILES.with(Cell::get) || !updates_allowed() {
+            return false;
+        }
+        if std::fs::read(&self.path).ok().as_deref() == Some(actual) {
+            return true;
+        }
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).unwrap_or_else(|e| {
+                panic!("Could not create the directory of the golden file {}: {e}", dir.display())
+            });
+        }
+        std::fs::write(&self.path, actual).unwrap_or_else(|e| {
+            panic!("Could not write the golden file {}: {e}", self.path.display())
+        });
+        true
+    }
+
+    pub(crate) fn explain_read_error(&self) -> Option<String> {
+        let path = self.path.display();
+        self.read_error.as_ref().map(|e| match e {
+            ReadError::NotFound => {
+                let resolution = match &self.base_dir {
+                    Some(base_dir) => format!(" (resolved against {base_dir})"),
+                    None => String::new(),
+                };
+                format!(
+                    "which can't be compared, as {path} does not exist{resolution}; run with \
+                    GOOGLETEST_UPDATE_GOLDEN_FILES=1 to create it"
+                )
+            }
+            ReadError::NotUtf8 => format!("which can't be compared, as {path} isn't UTF-8"),
+            ReadError::Other(e) => {
+                format!("which can't be compared, as {path} could not be read: {e}")
+            }
+        })
+    }
+}
+
+thread_local! {
+    pub(crate) static UPDATE_GOLDEN_FILES: Cell<bool> = Cell::new(update_requested());
+}
+
+fn update_requested() -> bool {
+    std::env::var("GOOGLETEST_UPDATE_GOLDEN_FILES")
+        .map(|s| !s.is_empty() && s != "0")
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::temp_dir::TempDir;
+    use crate::prelude::*;
+    use indoc::formatdoc;
+
+    // Returns a fresh temporary directory and the path of a file in it, which
+    // contains `contents` if given.
+    fn golden_file(test_name: &str, contents: Option<&[u8]>) -> (TempDir, PathBuf) {
+        let dir = TempDir::new(&format!("golden_file_{test_name}"));
+        let path = dir.path().join("expected.txt");
+        if let Some(contents) = contents {
+            std::fs::write(&path, contents).unwrap();
+        }
+        (dir, path)
+    }
+
+    #[test]
+    fn eq_file_matches_file_contents() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_matches", Some(b"line 1\nline 2\n"));
+
+        verify_that!("line 1\nline 2\n", eq_file(&path))
+    }
+
+    #[test]
+    fn eq_file_explains_mismatch_with_diff() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_mismatch", Some(b"line 1\nline 2\nline 3\n"));
+
+        let result = verify_that!("line 1\nline two\nline 3\n", eq_file(&path));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(formatdoc! {r#"
+                Actual: "line 1\nline two\nline 3\n",
+                  which isn't equal to the contents of {}
+                  
+                  Difference(-actual / +expected):
+                   line 1
+                  -line two
+                  ?     ^^^
+                  +line 2
+                  ?     ^
+                   line 3
+                "#,
+                path.display()
+            })))
+        )
+    }
+
+    #[test]
+    fn eq_file_describes_file_by_path() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_describe", Some(b"contents"));
+
+        verify_that!(
+            Matcher::<&str>::describe(&eq_file(&path), MatcherResult::Match),
+            displays_as(eq(format!("is equal to the contents of {}", path.display())))
+        )
+    }
+
+    #[test]
+    fn eq_file_honors_str_matcher_configuration() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_configuration", Some(b"line 1\r\nline 2\r\n"));
+
+        verify_that!("line 1\nline 2\n", eq_file(&path).ignoring_line_endings())?;
+        verify_that!(
+            Matcher::<&str>::describe(
+                &eq_file(&path).ignoring_line_endings(),
+                MatcherResult::NoMatch
+            ),
+            displays_as(ends_with("(ignoring line endings)"))
+        )
+    }
+
+    #[test]
+    fn eq_file_does_not_match_missing_file() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_missing", None);
+
+        let result = verify_that!("", eq_file(&path));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(format!(
+                "which can't be compared, as {} does not exist; run with \
+                GOOGLETEST_UPDATE_GOLDEN_FILES=1 to create it",
+                path.display()
+            ))))
+        )
+    }
+
+    #[test]
+    fn eq_file_explains_non_utf8_file_without_update_hint() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_non_utf8", Some(&[0, 159, 146, 150]));
+
+        let result = verify_that!("", eq_file(&path));
+
+        verify_that!(
+            result,
+            err(displays_as(all![
+                contains_substring(format!(
+                    "which can't be compared, as {} isn't UTF-8",
+                    path.display()
+                )),
+                not(contains_substring("GOOGLETEST_UPDATE_GOLDEN_FILES")),
+            ]))
+        )
+    }
+
+    #[test]
+    fn eq_file_update_mode_writes_file() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_update", Some(b"outdated"));
+        let matcher = eq_file(&path);
+        UPDATE_GOLDEN_FILES.with(|update| update.set(true));
+
+        let result = verify_that!("new contents", matcher);
+
+        UPDATE_GOLDEN_FILES.with(|update| update.set(false));
+        verify_that!(result, ok(anything()))?;
+        verify_that!("new contents", eq_file(&path))
+    }
+
+    #[test]
+    fn eq_file_update_mode_keeps_file_matching_with_configuration() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_update_configured", Some(b"line 1\r\nline 2\r\n"));
+        let matcher = eq_file(&path).ignoring_line_endings();
+        UPDATE_GOLDEN_FILES.with(|update| update.set(true));
+
+        let result = verify_that!("line 1\nline 2\n", matcher);
+
+        UPDATE_GOLDEN_FILES.with(|update| update.set(false));
+        verify_that!(result, ok(anything()))?;
+        verify_that!("line 1\r\nline 2\r\n", eq_file(&path))
+    }
+
+    #[test]
+    fn eq_file_update_mode_does_not_write_negated_file() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_negated", Some(b"outdated"));
+        let matcher = not(eq_file(&path));
+        UPDATE_GOLDEN_FILES.with(|update| update.set(true));
+
+        let result = verify_that!("new contents", matcher);
+
+        UPDATE_GOLDEN_FILES.with(|update| update.set(false));
+        verify_that!(result, ok(anything()))?;
+        verify_that!("outdated", eq_file(&path))
+    }
+
+    #[test]
+    fn eq_file_update_mode_does_not_write_file_outside_assertion() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_outside_assertion", Some(b"outdated"));
+        let matcher = eq_file(&path);
+        UPDATE_GOLDEN_FILES.with(|update| update.set(true));
+
+        let result = matcher.matches("new contents");
+
+        UPDATE_GOLDEN_FILES.with(|update| update.set(false));
+        verify_that!(result, eq(MatcherResult::NoMatch))?;
+        verify_that!("outdated", eq_file(&path))
+    }
+
+    #[test]
+    fn eq_file_bytes_matches_file_contents() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_bytes_matches", Some(&[0, 159, 146, 150]));
+
+        verify_that!(vec![0u8, 159, 146, 150], eq_file_bytes(&path))
+    }
+
+    #[test]
+    fn eq_file_bytes_explains_first_difference() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_bytes_mismatch", Some(&[1, 2, 3]));
+
+        let result = verify_that!([1u8, 2, 4], eq_file_bytes(&path));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("first at byte 2: actual 0x04, expected 0x03")))
+        )
+    }
+
+    #[test]
+    fn eq_file_bytes_explains_length_difference() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_bytes_length", Some(&[1, 2, 3]));
+
+        let result = verify_that!([1u8, 2], eq_file_bytes(&path));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(format!(
+                "which has 2 bytes, but the contents of {} have 3 bytes and the rest is equal",
+                path.display()
+            ))))
+        )
+    }
+
+    #[test]
+    fn eq_file_bytes_update_mode_creates_file() -> Result<()> {
+        let (_dir, path) = golden_file("eq_file_bytes_update", None);
+        let matcher = eq_file_bytes(&path);
+        UPDATE_GOLDEN_FILES.with(|update| update.set(true));
+
+        let result = verify_that!([255u8, 0], matcher);
+
+        UPDATE_GOLDEN_FILES.with(|update| update.set(false));
+        verify_that!(result, ok(anything()))?;
+        verify_that!([255u8, 0], eq_file_bytes(&path))
+    }
+}
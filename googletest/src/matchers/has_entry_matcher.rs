@@ -14,12 +14,19 @@
 
 use crate::description::Description;
 use crate::matcher::{Matcher, MatcherBase, MatcherResult};
-use std::collections::HashMap;
+use crate::matcher_support::edit_distance::{self, Difference, Edit};
+use crate::matcher_support::MapLike;
 use std::fmt::Debug;
-use std::hash::Hash;
 
-/// Matches a `&HashMap` containing the given `key` whose value is matched by
-/// the matcher `inner`.
+/// Maximum number of similar keys listed when the expected key is missing.
+const MAX_SIMILAR_KEYS: usize = 3;
+
+/// Matches a reference to a map containing the given `key` whose value is
+/// matched by the matcher `inner`.
+///
+/// This supports [`HashMap`][std::collections::HashMap],
+/// [`BTreeMap`][std::collections::BTreeMap], and any other type implementing
+/// [`MapLike`].
 ///
 /// ```
 /// # use googletest::prelude::*;
@@ -44,6 +51,13 @@ use std::hash::Hash;
 /// # should_fail_2().unwrap_err();
 /// ```
 ///
+/// If the key is missing, the failure message lists the existing keys whose
+/// `Debug` representation is closest to that of `key`, which helps spotting
+/// typos.
+///
+/// See also [`has_entries!`][crate::matchers::has_entries] to match several
+/// entries at once.
+///
 /// Note: One could obtain the same effect by using `contains` and a
 /// `Matcher<(&Key, &Value)>`:
 ///
@@ -61,25 +75,74 @@ pub fn has_entry<KeyT, MatcherT>(key: KeyT, inner: MatcherT) -> HasEntryMatcher<
     HasEntryMatcher { key, inner }
 }
 
+/// Matches a reference to a map containing all the given entries.
+///
+/// Each entry is written `key => matcher`, where `matcher` is matched against
+/// a reference to the value of `key`, as in [`has_entry`]. A plain value may be
+/// passed instead of a matcher, in which case it is matched for equality. The
+/// map may contain further entries.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::BTreeMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = BTreeMap::from([("host", "localhost"), ("port", "8080"), ("user", "root")]);
+/// verify_that!(value, has_entries!{"host" => &"localhost", "port" => ends_with("80")})?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = BTreeMap::from([("host", "localhost"), ("port", "8080"), ("user", "root")]);
+/// verify_that!(value, has_entries!{"host" => &"localhost", "prot" => &"8080"})?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The failure message lists only the entries which do not match, and the
+/// similar existing keys of missing ones:
+///
+/// ```text
+/// Actual: {"host": "localhost", "port": "8080", "user": "root"},
+///   which doesn't contain key "prot"
+///     similar existing keys: "port"
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __has_entries {
+    ($($key:expr => $matcher:expr),* $(,)?) => {{
+        $crate::matchers::all![$(
+            $crate::matchers::has_entry(
+                $key,
+                $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
+                    $matcher
+                ),
+            )
+        ),*]
+    }};
+}
+
 #[derive(MatcherBase)]
 pub struct HasEntryMatcher<KeyT, MatcherT> {
     key: KeyT,
     inner: MatcherT,
 }
 
-impl<'a, KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<&'a ValueT>>
-    Matcher<&'a HashMap<KeyT, ValueT>> for HasEntryMatcher<KeyT, MatcherT>
+impl<'a, MapT: MapLike<Key = KeyT> + Debug, KeyT: Debug, MatcherT: Matcher<&'a MapT::Value>>
+    Matcher<&'a MapT> for HasEntryMatcher<KeyT, MatcherT>
+where
+    MapT::Value: Debug,
 {
-    fn matches(&self, actual: &'a HashMap<KeyT, ValueT>) -> MatcherResult {
-        if let Some(value) = actual.get(&self.key) {
+    fn matches(&self, actual: &'a MapT) -> MatcherResult {
+        if let Some(value) = actual.lookup(&self.key) {
             self.inner.matches(value)
         } else {
             MatcherResult::NoMatch
         }
     }
 
-    fn explain_match(&self, actual: &'a HashMap<KeyT, ValueT>) -> Description {
-        if let Some(value) = actual.get(&self.key) {
+    fn explain_match(&self, actual: &'a MapT) -> Description {
+        if let Some(value) = actual.lookup(&self.key) {
             format!(
                 "which contains key {:?}, but is mapped to value {:#?}, {}",
                 self.key,
@@ -88,7 +151,15 @@ impl<'a, KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<&'a ValueT>>
             )
             .into()
         } else {
-            format!("which doesn't contain key {:?}", self.key).into()
+            let description =
+                Description::new().text(format!("which doesn't contain key {:?}", self.key));
+            let similar_keys = similar_keys(&self.key, actual.entries().map(|(key, _)| key));
+            if similar_keys.is_empty() {
+                description
+            } else {
+                description
+                    .nested(format!("similar existing keys: {}", similar_keys.join(", ")).into())
+            }
         }
     }
 
@@ -111,11 +182,44 @@ impl<'a, KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<&'a ValueT>>
     }
 }
 
+/// Returns the `Debug` representations of up to [`MAX_SIMILAR_KEYS`] keys
+/// closest to `key`, nearest first.
+///
+/// Keys are compared by the edit distance of their `Debug` representations.
+/// Keys which differ from `key` in more than half of the characters are
+/// omitted.
+fn similar_keys<'a, KeyT: Debug + 'a>(
+    key: &KeyT,
+    keys: impl Iterator<Item = &'a KeyT>,
+) -> Vec<String> {
+    let key = format!("{key:?}");
+    let mut candidates = keys
+        .filter_map(|candidate| {
+            let candidate = format!("{candidate:?}");
+            let distance = match edit_distance::edit_list(
+                candidate.chars(),
+                key.chars(),
+                edit_distance::Mode::Exact,
+            ) {
+                Difference::Equal => 0,
+                Difference::Editable(edits) => {
+                    edits.iter().filter(|edit| !matches!(edit, Edit::Both(_))).count()
+                }
+                Difference::Unrelated => return None,
+            };
+            let max_distance = key.chars().count().max(candidate.chars().count()) / 2;
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort();
+    candidates.into_iter().take(MAX_SIMILAR_KEYS).map(|(_, candidate)| candidate).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
     use indoc::indoc;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     #[test]
     fn has_entry_does_not_match_empty_hash_map() -> Result<()> {
@@ -174,4 +278,70 @@ mod tests {
             ))))
         )
     }
+
+    #[test]
+    fn has_entry_matches_btree_map_with_value() -> Result<()> {
+        let value = BTreeMap::from([(0, 0), (1, 1)]);
+        verify_that!(value, has_entry(1, eq(&1)))
+    }
+
+    #[test]
+    fn has_entry_lists_similar_keys_when_key_is_not_present() -> Result<()> {
+        let result = verify_that!(
+            BTreeMap::from([("host", 1), ("port", 2), ("user", 3)]),
+            has_entry("prot", eq(&2))
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: {"host": 1, "port": 2, "user": 3},
+                  which doesn't contain key "prot"
+                    similar existing keys: "port"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn has_entries_matches_map_with_all_entries() -> Result<()> {
+        let value = BTreeMap::from([(0, 0), (1, 1), (2, 2)]);
+        verify_that!(value, has_entries! {0 => eq(&0), 2 => gt(&1)})
+    }
+
+    #[test]
+    fn has_entries_accepts_plain_values() -> Result<()> {
+        let value = HashMap::from([("a", 1), ("b", 2)]);
+        verify_that!(value, has_entries! {"a" => &1, "b" => &2})
+    }
+
+    #[test]
+    fn has_entries_does_not_match_map_missing_one_entry() -> Result<()> {
+        let value = BTreeMap::from([(0, 0), (1, 1)]);
+        verify_that!(value, not(has_entries! {0 => eq(&0), 2 => eq(&2)}))
+    }
+
+    #[test]
+    fn has_entries_explains_only_mismatching_entries() -> Result<()> {
+        let result = verify_that!(
+            BTreeMap::from([(0, 0), (1, 1), (2, 2)]),
+            has_entries! {0 => eq(&0), 1 => eq(&2), 3 => eq(&3)}
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: has all the following properties:
+                  * contains key 0, which value is equal to 0
+                  * contains key 1, which value is equal to 2
+                  * contains key 3, which value is equal to 3
+                Actual: {0: 0, 1: 1, 2: 2},
+                  * which contains key 1, but is mapped to value 1, which isn't equal to 2
+                  * which doesn't contain key 3
+                "
+            ))))
+        )
+    }
 }
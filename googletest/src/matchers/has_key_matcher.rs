@@ -0,0 +1,121 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use crate::matcher_support::MapLike;
+use std::fmt::Debug;
+
+/// Matches a reference to a map containing a key matched by `inner`.
+///
+/// This supports [`HashMap`][std::collections::HashMap],
+/// [`BTreeMap`][std::collections::BTreeMap], and any other type implementing
+/// [`MapLike`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::BTreeMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = BTreeMap::from([("host", "localhost"), ("port", "8080")]);
+/// verify_that!(value, has_key(eq(&"port")))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = BTreeMap::from([("host", "localhost"), ("port", "8080")]);
+/// verify_that!(value, has_key(starts_with("user")))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_key<MatcherT>(inner: MatcherT) -> HasKeyMatcher<MatcherT> {
+    HasKeyMatcher { inner }
+}
+
+#[derive(MatcherBase)]
+pub struct HasKeyMatcher<MatcherT> {
+    inner: MatcherT,
+}
+
+impl<'a, MapT: MapLike + Debug, MatcherT: Matcher<&'a MapT::Key>> Matcher<&'a MapT>
+    for HasKeyMatcher<MatcherT>
+where
+    MapT::Key: Debug,
+{
+    fn matches(&self, actual: &'a MapT) -> MatcherResult {
+        actual.entries().any(|(key, _)| self.inner.matches(key).is_match()).into()
+    }
+
+    fn explain_match(&self, actual: &'a MapT) -> Description {
+        match actual.entries().find(|(key, _)| self.inner.matches(key).is_match()) {
+            Some((key, _)) => format!("which contains matching key {key:?}").into(),
+            None => "which doesn't contain a matching key".into(),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => {
+                format!("contains a key which {}", self.inner.describe(MatcherResult::Match)).into()
+            }
+            MatcherResult::NoMatch => {
+                format!("doesn't contain a key which {}", self.inner.describe(MatcherResult::Match))
+                    .into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn has_key_matches_hash_map_with_key() -> Result<()> {
+        verify_that!(HashMap::from([(1, "a"), (2, "b")]), has_key(eq(&2)))
+    }
+
+    #[test]
+    fn has_key_matches_btree_map_with_key() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "a"), (2, "b")]), has_key(gt(&1)))
+    }
+
+    #[test]
+    fn has_key_does_not_match_map_without_key() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "a")]), not(has_key(eq(&2))))
+    }
+
+    #[test]
+    fn has_key_does_not_match_empty_map() -> Result<()> {
+        verify_that!(BTreeMap::<i32, i32>::new(), not(has_key(anything())))
+    }
+
+    #[test]
+    fn has_key_shows_correct_message_when_no_key_matches() -> Result<()> {
+        let result = verify_that!(BTreeMap::from([(1, "a")]), has_key(gt(&1)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: contains a key which is greater than 1
+                Actual: {1: "a"},
+                  which doesn't contain a matching key
+                "#
+            ))))
+        )
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use crate::matcher_support::MapLike;
+use std::fmt::Debug;
+
+/// Matches a reference to a map containing a value matched by `inner`.
+///
+/// This supports [`HashMap`][std::collections::HashMap],
+/// [`BTreeMap`][std::collections::BTreeMap], and any other type implementing
+/// [`MapLike`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::BTreeMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = BTreeMap::from([("host", "localhost"), ("port", "8080")]);
+/// verify_that!(value, has_value(eq(&"localhost")))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = BTreeMap::from([("host", "localhost"), ("port", "8080")]);
+/// verify_that!(value, has_value(eq(&"example.com")))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_value<MatcherT>(inner: MatcherT) -> HasValueMatcher<MatcherT> {
+    HasValueMatcher { inner }
+}
+
+#[derive(MatcherBase)]
+pub struct HasValueMatcher<MatcherT> {
+    inner: MatcherT,
+}
+
+impl<'a, MapT: MapLike + Debug, MatcherT: Matcher<&'a MapT::Value>> Matcher<&'a MapT>
+    for HasValueMatcher<MatcherT>
+where
+    MapT::Key: Debug,
+    MapT::Value: Debug,
+{
+    fn matches(&self, actual: &'a MapT) -> MatcherResult {
+        actual.entries().any(|(_, value)| self.inner.matches(value).is_match()).into()
+    }
+
+    fn explain_match(&self, actual: &'a MapT) -> Description {
+        match actual.entries().find(|(_, value)| self.inner.matches(value).is_match()) {
+            Some((key, value)) => {
+                format!("which contains key {key:?} mapped to matching value {value:?}").into()
+            }
+            None => "which doesn't contain a matching value".into(),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => {
+                format!("contains a value which {}", self.inner.describe(MatcherResult::Match))
+                    .into()
+            }
+            MatcherResult::NoMatch => format!(
+                "doesn't contain a value which {}",
+                self.inner.describe(MatcherResult::Match)
+            )
+            .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn has_value_matches_hash_map_with_value() -> Result<()> {
+        verify_that!(HashMap::from([(1, "a"), (2, "b")]), has_value(eq(&"b")))
+    }
+
+    #[test]
+    fn has_value_matches_btree_map_with_value() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "a"), (2, "b")]), has_value(starts_with("a")))
+    }
+
+    #[test]
+    fn has_value_does_not_match_map_without_value() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "a")]), not(has_value(eq(&"b"))))
+    }
+
+    #[test]
+    fn has_value_explains_matching_entry() -> Result<()> {
+        let result = verify_that!(BTreeMap::from([(1, "a")]), not(has_value(eq(&"a"))));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: doesn't contain a value which is equal to "a"
+                Actual: {1: "a"},
+                  which contains key 1 mapped to matching value "a"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn has_value_shows_correct_message_when_no_value_matches() -> Result<()> {
+        let result = verify_that!(BTreeMap::from([(1, "a")]), has_value(eq(&"b")));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: contains a value which is equal to "b"
+                Actual: {1: "a"},
+                  which doesn't contain a matching value
+                "#
+            ))))
+        )
+    }
+}
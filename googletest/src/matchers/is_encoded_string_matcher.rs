@@ -60,7 +60,7 @@ pub struct IsEncodedStringMatcher<InnerMatcherT> {
     inner: InnerMatcherT,
 }
 
-impl<ActualT: AsRef<[u8]> + Debug + Copy, InnerMatcherT> Matcher<ActualT>
+impl<ActualT: AsRef<[u8]> + Debug + Clone, InnerMatcherT> Matcher<ActualT>
     for IsEncodedStringMatcher<InnerMatcherT>
 where
     InnerMatcherT: for<'a> Matcher<&'a str>,
@@ -35,7 +35,7 @@ pub struct IsMatcher<'a, InnerMatcherT> {
     inner: InnerMatcherT,
 }
 
-impl<'a, ActualT: Debug + Copy, InnerMatcherT: Matcher<ActualT>> Matcher<ActualT>
+impl<'a, ActualT: Debug + Clone, InnerMatcherT: Matcher<ActualT>> Matcher<ActualT>
     for IsMatcher<'a, InnerMatcherT>
 {
     fn matches(&self, actual: ActualT) -> MatcherResult {
@@ -62,4 +62,12 @@ impl<'a, ActualT: Debug + Copy, InnerMatcherT: Matcher<ActualT>> Matcher<ActualT
     fn explain_match(&self, actual: ActualT) -> Description {
         self.inner.explain_match(actual)
     }
+
+    fn match_and_explain(
+        &self,
+        actual: ActualT,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        self.inner.match_and_explain(actual, then)
+    }
 }
@@ -27,7 +27,7 @@ pub fn is_nan() -> IsNanMatcher {
 #[derive(MatcherBase)]
 pub struct IsNanMatcher;
 
-impl<T: Float + Debug + Copy> Matcher<T> for IsNanMatcher {
+impl<T: Float + Debug + Clone> Matcher<T> for IsNanMatcher {
     fn matches(&self, actual: T) -> MatcherResult {
         actual.is_nan().into()
     }
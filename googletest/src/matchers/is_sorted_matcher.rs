@@ -0,0 +1,379 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// Matches a container whose elements are sorted in ascending order, that is,
+/// each element is less than or equal to the next one.
+///
+/// The actual value must implement [`IntoIterator`] with an `Item` type
+/// implementing [`PartialOrd`]. This includes `&Vec`, arrays, and slices.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 2, 3], is_sorted())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 3, 2], is_sorted())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// When the match fails, the explanation names the first pair of adjacent
+/// elements which are out of order:
+///
+/// ```text
+/// Expected: is sorted in ascending order
+/// Actual: [1, 3, 2],
+///   whose elements #1 and #2 are out of order: 3 is followed by 2
+/// ```
+pub fn is_sorted() -> IsSortedMatcher<Ascending> {
+    IsSortedMatcher { order: Ascending }
+}
+
+/// Matches a container whose elements are sorted in strictly ascending order,
+/// that is, each element is less than the next one.
+///
+/// Unlike [`is_sorted`], this does not match containers with two equal
+/// adjacent elements.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], is_strictly_increasing())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 2, 3], is_strictly_increasing())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_strictly_increasing() -> IsSortedMatcher<StrictlyAscending> {
+    IsSortedMatcher { order: StrictlyAscending }
+}
+
+/// Matches a container whose elements are sorted in descending order, that
+/// is, each element is greater than or equal to the next one.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![3, 2, 2, 1], is_sorted_descending())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![3, 1, 2], is_sorted_descending())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_sorted_descending() -> IsSortedMatcher<Descending> {
+    IsSortedMatcher { order: Descending }
+}
+
+/// Matches a container whose elements are sorted according to the comparator
+/// `compare`, that is, `compare` never returns [`Ordering::Greater`] for an
+/// element and the next one.
+///
+/// The comparator receives references to the items of the container. For a
+/// `&Vec<T>`, these are of type `&&T`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let value = vec!["apple", "Banana", "cherry"];
+/// verify_that!(
+///     value,
+///     is_sorted_by(|a: &&&str, b: &&&str| a.to_lowercase().cmp(&b.to_lowercase()))
+/// )?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub fn is_sorted_by<CompareT>(compare: CompareT) -> IsSortedMatcher<ByComparator<CompareT>> {
+    IsSortedMatcher { order: ByComparator(compare) }
+}
+
+/// Matches a container whose elements are sorted in ascending order of the
+/// key extracted by `key`.
+///
+/// The key function receives references to the items of the container. For a
+/// `&Vec<T>`, these are of type `&&T`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let value = vec!["fig", "pear", "apple"];
+/// verify_that!(value, is_sorted_by_key(|name: &&&str| name.len()))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub fn is_sorted_by_key<KeyFnT>(key: KeyFnT) -> IsSortedMatcher<ByKey<KeyFnT>> {
+    IsSortedMatcher { order: ByKey(key) }
+}
+
+/// A matcher which matches a container whose elements are in the order
+/// defined by `OrderT`.
+///
+/// See [`is_sorted`], [`is_strictly_increasing`], [`is_sorted_descending`],
+/// [`is_sorted_by`], and [`is_sorted_by_key`].
+#[derive(MatcherBase)]
+pub struct IsSortedMatcher<OrderT> {
+    order: OrderT,
+}
+
+/// An order in which [`IsSortedMatcher`] expects the elements of a container
+/// to be.
+pub trait SortOrder<T> {
+    /// Returns whether `first`, followed immediately by `second`, is allowed
+    /// in this order.
+    fn in_order(&self, first: &T, second: &T) -> bool;
+
+    /// Describes this order, e.g. "in ascending order".
+    fn describe(&self) -> &'static str;
+}
+
+/// The order of [`is_sorted`].
+pub struct Ascending;
+
+impl<T: PartialOrd> SortOrder<T> for Ascending {
+    fn in_order(&self, first: &T, second: &T) -> bool {
+        first <= second
+    }
+
+    fn describe(&self) -> &'static str {
+        "in ascending order"
+    }
+}
+
+/// The order of [`is_strictly_increasing`].
+pub struct StrictlyAscending;
+
+impl<T: PartialOrd> SortOrder<T> for StrictlyAscending {
+    fn in_order(&self, first: &T, second: &T) -> bool {
+        first < second
+    }
+
+    fn describe(&self) -> &'static str {
+        "in strictly increasing order"
+    }
+}
+
+/// The order of [`is_sorted_descending`].
+pub struct Descending;
+
+impl<T: PartialOrd> SortOrder<T> for Descending {
+    fn in_order(&self, first: &T, second: &T) -> bool {
+        first >= second
+    }
+
+    fn describe(&self) -> &'static str {
+        "in descending order"
+    }
+}
+
+/// The order of [`is_sorted_by`].
+pub struct ByComparator<CompareT>(CompareT);
+
+impl<T, CompareT: Fn(&T, &T) -> Ordering> SortOrder<T> for ByComparator<CompareT> {
+    fn in_order(&self, first: &T, second: &T) -> bool {
+        (self.0)(first, second) != Ordering::Greater
+    }
+
+    fn describe(&self) -> &'static str {
+        "according to the given comparator"
+    }
+}
+
+/// The order of [`is_sorted_by_key`].
+pub struct ByKey<KeyFnT>(KeyFnT);
+
+impl<T, KeyT: PartialOrd, KeyFnT: Fn(&T) -> KeyT> SortOrder<T> for ByKey<KeyFnT> {
+    fn in_order(&self, first: &T, second: &T) -> bool {
+        (self.0)(first) <= (self.0)(second)
+    }
+
+    fn describe(&self) -> &'static str {
+        "by the given key"
+    }
+}
+
+impl<OrderT> IsSortedMatcher<OrderT> {
+    /// Returns the index of the first element which is out of order with the
+    /// next one, together with both elements.
+    fn first_violation<ElementT>(
+        &self,
+        actual: impl IntoIterator<Item = ElementT>,
+    ) -> Option<(usize, ElementT, ElementT)>
+    where
+        OrderT: SortOrder<ElementT>,
+    {
+        let mut elements = actual.into_iter();
+        let mut previous = elements.next()?;
+        for (index, element) in elements.enumerate() {
+            if !self.order.in_order(&previous, &element) {
+                return Some((index, previous, element));
+            }
+            previous = element;
+        }
+        None
+    }
+}
+
+impl<ElementT: Debug, ActualT: Debug + Clone, OrderT> Matcher<ActualT> for IsSortedMatcher<OrderT>
+where
+    ActualT: IntoIterator<Item = ElementT>,
+    OrderT: SortOrder<ElementT>,
+{
+    fn matches(&self, actual: ActualT) -> MatcherResult {
+        self.first_violation(actual).is_none().into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => format!("is sorted {}", self.order.describe()).into(),
+            MatcherResult::NoMatch => format!("isn't sorted {}", self.order.describe()).into(),
+        }
+    }
+
+    fn explain_match(&self, actual: ActualT) -> Description {
+        match self.first_violation(actual) {
+            Some((index, first, second)) => format!(
+                "whose elements #{index} and #{} are out of order: {first:?} is followed by {second:?}",
+                index + 1
+            )
+            .into(),
+            None => format!("which is sorted {}", self.order.describe()).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn is_sorted_matches_empty_vec() -> Result<()> {
+        let value: Vec<i32> = vec![];
+        verify_that!(value, is_sorted())
+    }
+
+    #[test]
+    fn is_sorted_matches_vec_with_one_element() -> Result<()> {
+        verify_that!(vec![1], is_sorted())
+    }
+
+    #[test]
+    fn is_sorted_matches_sorted_vec_with_duplicates() -> Result<()> {
+        verify_that!(vec![1, 2, 2, 3], is_sorted())
+    }
+
+    #[test]
+    fn is_sorted_matches_sorted_array() -> Result<()> {
+        verify_that!([1, 2, 3], is_sorted())
+    }
+
+    #[test]
+    fn is_sorted_matches_btree_set() -> Result<()> {
+        verify_that!(BTreeSet::from([3, 1, 2]), is_sorted())
+    }
+
+    #[test]
+    fn is_sorted_does_not_match_unsorted_vec() -> Result<()> {
+        verify_that!(vec![1, 3, 2], not(is_sorted()))
+    }
+
+    #[test]
+    fn is_strictly_increasing_does_not_match_vec_with_duplicates() -> Result<()> {
+        verify_that!(vec![1, 2, 2, 3], not(is_strictly_increasing()))
+    }
+
+    #[test]
+    fn is_strictly_increasing_matches_increasing_vec() -> Result<()> {
+        verify_that!(vec![1, 2, 3], is_strictly_increasing())
+    }
+
+    #[test]
+    fn is_sorted_descending_matches_descending_vec() -> Result<()> {
+        verify_that!(vec![3, 2, 2, 1], is_sorted_descending())
+    }
+
+    #[test]
+    fn is_sorted_descending_does_not_match_ascending_vec() -> Result<()> {
+        verify_that!(vec![1, 2, 3], not(is_sorted_descending()))
+    }
+
+    #[test]
+    fn is_sorted_by_matches_vec_sorted_by_comparator() -> Result<()> {
+        verify_that!(vec![3, 2, 1], is_sorted_by(|a: &&i32, b: &&i32| b.cmp(a)))
+    }
+
+    #[test]
+    fn is_sorted_by_does_not_match_vec_not_sorted_by_comparator() -> Result<()> {
+        verify_that!(vec![1, 2, 3], not(is_sorted_by(|a: &&i32, b: &&i32| b.cmp(a))))
+    }
+
+    #[test]
+    fn is_sorted_by_key_matches_vec_sorted_by_key() -> Result<()> {
+        verify_that!(vec![-1, 2, -3], is_sorted_by_key(|a: &&i32| a.abs()))
+    }
+
+    #[test]
+    fn is_sorted_by_key_does_not_match_vec_not_sorted_by_key() -> Result<()> {
+        verify_that!(vec![1, -3, 2], not(is_sorted_by_key(|a: &&i32| a.abs())))
+    }
+
+    #[test]
+    fn is_sorted_describes_itself() -> Result<()> {
+        verify_that!(
+            Matcher::<&Vec<i32>>::describe(&is_sorted(), MatcherResult::NoMatch),
+            displays_as(eq("isn't sorted in ascending order"))
+        )
+    }
+
+    #[test]
+    fn is_sorted_explains_first_violation() -> Result<()> {
+        verify_that!(
+            is_sorted().explain_match(&vec![1, 4, 3, 2]),
+            displays_as(eq("whose elements #1 and #2 are out of order: 4 is followed by 3"))
+        )
+    }
+
+    #[test]
+    fn is_strictly_increasing_explains_equal_elements() -> Result<()> {
+        let result = verify_that!(vec![1, 2, 2], is_strictly_increasing());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Expected: is sorted in strictly increasing order\n\
+                 Actual: [1, 2, 2],\n  \
+                 whose elements #1 and #2 are out of order: 2 is followed by 2"
+            )))
+        )
+    }
+}
@@ -0,0 +1,404 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// Wraps `iterator` so that container matchers consume it lazily.
+///
+/// Container matchers such as [`each`][crate::matchers::each],
+/// [`contains`][crate::matchers::contains], and
+/// [`elements_are!`][crate::matchers::elements_are] require an actual value
+/// which implements `Debug` and `Clone` and which can be iterated over several
+/// times. The returned [`Lazily`] satisfies these requirements for any
+/// iterator, including single-pass iterators and iterators over closures: it
+/// pulls elements from `iterator` only when a matcher asks for them, and
+/// remembers them so that they can be iterated over again, for instance to
+/// explain a mismatch.
+///
+/// The matchers stop consuming elements as soon as the outcome is decided, so
+/// that [`contains`][crate::matchers::contains] can find an element in an
+/// infinite iterator. The `Debug` output, which appears in assertion failure
+/// messages, only shows the elements consumed so far, followed by `..` if
+/// there may be more, or by `.. (limit n reached)` if the iteration was
+/// stopped by [`Lazily::up_to`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let squares = (1..).map(|n| n * n);
+/// verify_that!(lazily(squares), contains(eq(49)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Explaining a mismatch may require consuming the whole iterator, so use
+/// [`Lazily::up_to`] to bound the number of elements consumed from an
+/// infinite iterator:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_fail() -> Result<()> {
+/// let squares = (1..).map(|n| n * n);
+/// verify_that!(lazily(squares).up_to(100), each(lt(50)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_fail().unwrap_err();
+/// ```
+pub fn lazily<IteratorT: IntoIterator>(iterator: IteratorT) -> Lazily<IteratorT::IntoIter> {
+    Lazily {
+        state: Rc::new(RefCell::new(LazyState {
+            iterator: iterator.into_iter(),
+            consumed: vec![],
+            exhausted: false,
+            limit: None,
+            limit_reached: false,
+        })),
+    }
+}
+
+/// An iterator whose elements are consumed lazily and remembered, so that it
+/// can be iterated over several times.
+///
+/// Clones share the same underlying iterator. See [`lazily`].
+pub struct Lazily<IteratorT: Iterator> {
+    state: Rc<RefCell<LazyState<IteratorT>>>,
+}
+
+struct LazyState<IteratorT: Iterator> {
+    iterator: IteratorT,
+    consumed: Vec<IteratorT::Item>,
+    exhausted: bool,
+    limit: Option<usize>,
+    limit_reached: bool,
+}
+
+impl<IteratorT: Iterator> Lazily<IteratorT> {
+    /// Stops the iteration after `limit` elements, as if the underlying
+    /// iterator ended there.
+    pub fn up_to(self, limit: usize) -> Self {
+        self.state.borrow_mut().limit = Some(limit);
+        self
+    }
+}
+
+impl<IteratorT: Iterator> LazyState<IteratorT> {
+    /// Returns the element at `index`, consuming elements from the underlying
+    /// iterator up to it if needed.
+    fn get(&mut self, index: usize) -> Option<IteratorT::Item>
+    where
+        IteratorT::Item: Clone,
+    {
+        while self.consumed.len() <= index {
+            if self.exhausted {
+                return None;
+            }
+            if self.limit.is_some_and(|limit| self.consumed.len() >= limit) {
+                self.limit_reached = true;
+                return None;
+            }
+            match self.iterator.next() {
+                Some(element) => self.consumed.push(element),
+                None => {
+                    self.exhausted = true;
+                    return None;
+                }
+            }
+        }
+        Some(self.consumed[index].clone())
+    }
+}
+
+impl<IteratorT: Iterator> Clone for Lazily<IteratorT> {
+    fn clone(&self) -> Self {
+        Self { state: Rc::clone(&self.state) }
+    }
+}
+
+impl<IteratorT: Iterator> Debug for Lazily<IteratorT>
+where
+    IteratorT::Item: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        struct Rest(Option<usize>);
+        impl Debug for Rest {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self.0 {
+                    Some(limit) => write!(f, ".. (limit {limit} reached)"),
+                    None => write!(f, ".."),
+                }
+            }
+        }
+
+        let state = self.state.borrow();
+        let mut list = f.debug_list();
+        list.entries(&state.consumed);
+        if !state.exhausted {
+            list.entry(&Rest(state.limit.filter(|_| state.limit_reached)));
+        }
+        list.finish()
+    }
+}
+
+impl<IteratorT: Iterator> IntoIterator for Lazily<IteratorT>
+where
+    IteratorT::Item: Clone,
+{
+    type Item = IteratorT::Item;
+    type IntoIter = LazilyIter<IteratorT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LazilyIter { state: self.state, index: 0 }
+    }
+}
+
+impl<IteratorT: Iterator> IntoIterator for &Lazily<IteratorT>
+where
+    IteratorT::Item: Clone,
+{
+    type Item = IteratorT::Item;
+    type IntoIter = LazilyIter<IteratorT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.clone().into_iter()
+    }
+}
+
+/// An iterator over the elements of a [`Lazily`], starting from its first
+/// element.
+pub struct LazilyIter<IteratorT: Iterator> {
+    state: Rc<RefCell<LazyState<IteratorT>>>,
+    index: usize,
+}
+
+impl<IteratorT: Iterator> Iterator for LazilyIter<IteratorT>
+where
+    IteratorT::Item: Clone,
+{
+    type Item = IteratorT::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.state.borrow_mut().get(self.index)?;
+        self.index += 1;
+        Some(element)
+    }
+}
+
+/// Matches an iterator whose elements, consumed lazily, are matched by the
+/// container matcher `inner`.
+///
+/// The actual value must be an iterator implementing `Clone` and `Debug`, such
+/// as a range or an iterator over the elements of a collection. It is cloned
+/// and wrapped in a [`Lazily`] before being passed to `inner`, so that `inner`
+/// only consumes the elements it needs. This makes it possible to match
+/// infinite iterators:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.., iterates_as(contains(eq(1000))))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(1.., iterates_as(elements_are![eq(1), eq(3), ..]).up_to(10))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// When the match fails, the explanation shows the elements which were
+/// consumed. Explaining a mismatch may require consuming the whole iterator, so
+/// use [`IteratesAsMatcher::up_to`] to bound the number of elements consumed
+/// from an infinite iterator.
+///
+/// To match iterators which do not implement `Clone` or `Debug`, wrap them
+/// with [`lazily`] instead.
+pub fn iterates_as<MatcherT>(inner: MatcherT) -> IteratesAsMatcher<MatcherT> {
+    IteratesAsMatcher { inner, limit: None }
+}
+
+/// A matcher which matches an iterator whose elements are matched by `inner`.
+///
+/// See [`iterates_as`].
+#[derive(MatcherBase)]
+pub struct IteratesAsMatcher<MatcherT> {
+    inner: MatcherT,
+    limit: Option<usize>,
+}
+
+impl<MatcherT> IteratesAsMatcher<MatcherT> {
+    /// Stops the iteration after `limit` elements, as if the iterator ended
+    /// there.
+    pub fn up_to(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn lazily<IteratorT: Iterator + Clone>(&self, actual: &IteratorT) -> Lazily<IteratorT> {
+        let lazily = lazily(actual.clone());
+        match self.limit {
+            Some(limit) => lazily.up_to(limit),
+            None => lazily,
+        }
+    }
+}
+
+impl<'a, IteratorT, MatcherT> Matcher<&'a IteratorT> for IteratesAsMatcher<MatcherT>
+where
+    IteratorT: Iterator + Clone + Debug,
+    IteratorT::Item: Debug + Clone,
+    MatcherT: Matcher<Lazily<IteratorT>>,
+{
+    fn matches(&self, actual: &'a IteratorT) -> MatcherResult {
+        self.inner.matches(self.lazily(actual))
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        format!("is an iterator which {}", self.inner.describe(matcher_result)).into()
+    }
+
+    fn explain_match(&self, actual: &'a IteratorT) -> Description {
+        let lazily = self.lazily(actual);
+        let explanation = self.inner.explain_match(lazily.clone());
+        format!("which yields {lazily:?}, {explanation}").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::cell::Cell;
+
+    /// A single-pass iterator which implements neither `Clone` nor `Debug`.
+    struct Countdown(u32);
+
+    impl Iterator for Countdown {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            self.0 = self.0.checked_sub(1)?;
+            Some(self.0)
+        }
+    }
+
+    #[test]
+    fn lazily_matches_single_pass_iterator() -> Result<()> {
+        verify_that!(lazily(Countdown(3)), elements_are![eq(2), eq(1), eq(0)])
+    }
+
+    #[test]
+    fn lazily_stops_consuming_on_match() -> Result<()> {
+        let consumed = Cell::new(0);
+        let iterator = (0..).inspect(|_| consumed.set(consumed.get() + 1));
+
+        verify_that!(lazily(iterator), contains(eq(5)))?;
+        verify_that!(consumed.get(), eq(6))
+    }
+
+    #[test]
+    fn lazily_stops_consuming_on_mismatch() -> Result<()> {
+        let consumed = Cell::new(0);
+        let iterator = (0..).inspect(|_| consumed.set(consumed.get() + 1));
+
+        verify_that!(lazily(iterator), not(each(lt(3))))?;
+        verify_that!(consumed.get(), eq(4))
+    }
+
+    #[test]
+    fn lazily_consumes_each_element_once() -> Result<()> {
+        let consumed = Cell::new(0);
+        let iterator = (0..3).inspect(|_| consumed.set(consumed.get() + 1));
+        let lazily = lazily(iterator);
+
+        verify_that!(lazily, elements_are![eq(0), eq(1), eq(2)])?;
+        verify_that!(lazily, each(lt(3)))?;
+        verify_that!(consumed.get(), eq(3))
+    }
+
+    #[test]
+    fn lazily_with_limit_ends_infinite_iterator() -> Result<()> {
+        verify_that!(lazily(0..).up_to(3), elements_are![eq(0), eq(1), eq(2)])
+    }
+
+    #[test]
+    fn lazily_shows_consumed_prefix_in_failure_message() -> Result<()> {
+        let result = verify_that!(lazily((1..).map(|n| n * n)).up_to(5), each(lt(10)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: only contains elements that is less than 10
+                Actual: [1, 4, 9, 16, ..],
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn lazily_shows_that_limit_was_reached() -> Result<()> {
+        let lazily = lazily(0..).up_to(3);
+
+        verify_that!((&lazily).into_iter().count(), eq(3))?;
+        verify_that!(format!("{lazily:?}"), eq("[0, 1, 2, .. (limit 3 reached)]"))
+    }
+
+    #[test]
+    fn lazily_shows_all_elements_of_exhausted_iterator() -> Result<()> {
+        let lazily = lazily(Countdown(2));
+
+        verify_that!((&lazily).into_iter().count(), eq(2))?;
+        verify_that!(format!("{lazily:?}"), eq("[1, 0]"))
+    }
+
+    #[test]
+    fn iterates_as_matches_infinite_iterator() -> Result<()> {
+        verify_that!(1.., iterates_as(contains(eq(1000))))
+    }
+
+    #[test]
+    fn iterates_as_does_not_match_with_limit() -> Result<()> {
+        verify_that!(1.., not(iterates_as(contains(eq(1000))).up_to(10)))
+    }
+
+    #[test]
+    fn iterates_as_matches_iterator_over_collection() -> Result<()> {
+        let value = [1, 2, 3];
+        verify_that!(
+            value.iter().map(|n| n * 2).rev(),
+            iterates_as(elements_are![eq(6), eq(4), eq(2)])
+        )
+    }
+
+    #[test]
+    fn iterates_as_explains_with_consumed_prefix() -> Result<()> {
+        let result = verify_that!(1.., iterates_as(elements_are![eq(1), eq(3), ..]).up_to(10));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: 1..,
+                  which yields [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, .. (limit 10 reached)], where element #1 is 2, which isn't equal to 3, for matcher #1
+                "
+            ))))
+        )
+    }
+}
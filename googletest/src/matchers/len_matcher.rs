@@ -57,7 +57,7 @@ pub struct LenMatcher<E> {
     expected: E,
 }
 
-impl<T: Debug + Copy, E: Matcher<usize>> Matcher<T> for LenMatcher<E>
+impl<T: Debug + Clone, E: Matcher<usize>> Matcher<T> for LenMatcher<E>
 where
     T: IntoIterator,
 {
@@ -172,7 +172,7 @@ mod tests {
     fn len_matcher_explain_match() -> Result<()> {
         #[derive(MatcherBase)]
         struct TestMatcher;
-        impl<T: Debug + Copy> Matcher<T> for TestMatcher {
+        impl<T: Debug + Clone> Matcher<T> for TestMatcher {
             fn matches(&self, _: T) -> MatcherResult {
                 false.into()
             }
@@ -0,0 +1,110 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module.
+#![doc(hidden)]
+
+use crate::{
+    description::Description,
+    matcher::{explain_with_match_and_explain, Matcher, MatcherBase, MatcherResult},
+};
+use std::fmt::Debug;
+
+/// Matcher created by [`MatcherBase::map_actual`].
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[derive(MatcherBase)]
+pub struct MapActualMatcher<InnerMatcherT, MapperT> {
+    inner: InnerMatcherT,
+    label: &'static str,
+    mapper: MapperT,
+}
+
+impl<InnerMatcherT, MapperT> MapActualMatcher<InnerMatcherT, MapperT> {
+    pub fn new(inner: InnerMatcherT, label: &'static str, mapper: MapperT) -> Self {
+        Self { inner, label, mapper }
+    }
+}
+
+impl<T: Debug + Clone, U: Debug + Clone, InnerMatcherT: Matcher<U>, MapperT: Fn(T) -> U> Matcher<T>
+    for MapActualMatcher<InnerMatcherT, MapperT>
+{
+    fn matches(&self, actual: T) -> MatcherResult {
+        self.inner.matches((self.mapper)(actual))
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        format!("whose {} {}", self.label, self.inner.describe(matcher_result)).into()
+    }
+
+    fn explain_match(&self, actual: T) -> Description {
+        explain_with_match_and_explain(self, actual)
+    }
+
+    fn match_and_explain(
+        &self,
+        actual: T,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        let mapped = (self.mapper)(actual);
+        self.inner.match_and_explain(mapped.clone(), &mut |result, explanation| {
+            then(result, &|| {
+                format!("whose {} is {mapped:?}, {}", self.label, explanation()).into()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_mapped_value() -> Result<()> {
+        verify_that!(vec![1, 2, 3], eq(3).map_actual("length", |v: &Vec<i32>| v.len()))
+    }
+
+    #[test]
+    fn does_not_match_when_mapped_value_does_not_match() -> Result<()> {
+        verify_that!(vec![1, 2], not(eq(3).map_actual("length", |v: &Vec<i32>| v.len())))
+    }
+
+    #[test]
+    fn describes_with_label() -> Result<()> {
+        let matcher = eq(3).map_actual("length", |v: &Vec<i32>| v.len());
+
+        verify_that!(
+            Matcher::<&Vec<i32>>::describe(&matcher, MatcherResult::NoMatch),
+            displays_as(eq("whose length isn't equal to 3"))
+        )
+    }
+
+    #[test]
+    fn explains_with_mapped_value() -> Result<()> {
+        let result = verify_that!(vec![1, 2], eq(3).map_actual("length", |v: &Vec<i32>| v.len()));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: whose length is equal to 3
+                Actual: [1, 2],
+                  whose length is 2, which isn't equal to 3"
+            ))))
+        )
+    }
+}
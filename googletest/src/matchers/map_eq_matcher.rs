@@ -0,0 +1,257 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use crate::matcher_support::edit_distance;
+use crate::matcher_support::summarize_diff::create_diff;
+use crate::matcher_support::MapLike;
+use std::fmt::Debug;
+
+/// Matches a reference to a map with exactly the same entries as `expected`.
+///
+/// This is similar to [`eq`][crate::matchers::eq], except that the failure
+/// message compares the maps key by key rather than diffing their `Debug`
+/// output, which is of little use for maps such as
+/// [`HashMap`][std::collections::HashMap] whose iteration order is
+/// unspecified. It lists the missing keys, the unexpected keys, and the keys
+/// whose values changed, in ascending key order:
+///
+/// ```text
+/// Expected: is equal to {"host": "localhost", "port": "8080"}
+/// Actual: {"port": "80", "user": "root", "host": "localhost"},
+///   which has different entries:
+///     * key "port" is mapped to "80", but "8080" was expected
+///     * key "user" is unexpected, mapped to "root"
+/// ```
+///
+/// Values whose `Debug` output spans several lines are compared with a
+/// line-by-line diff.
+///
+/// The actual and expected maps may be of different types, for instance a
+/// [`HashMap`][std::collections::HashMap] and a
+/// [`BTreeMap`][std::collections::BTreeMap], as long as they implement
+/// [`MapLike`] with the same key and value types. The keys must implement
+/// [`Ord`] so that the failure message can list them in order.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::{BTreeMap, HashMap};
+/// # fn should_pass() -> Result<()> {
+/// let value = HashMap::from([("host", "localhost"), ("port", "8080")]);
+/// verify_that!(value, map_eq(BTreeMap::from([("host", "localhost"), ("port", "8080")])))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = HashMap::from([("host", "localhost"), ("port", "8080")]);
+/// verify_that!(value, map_eq(HashMap::from([("host", "localhost")])))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn map_eq<ExpectedMapT>(expected: ExpectedMapT) -> MapEqMatcher<ExpectedMapT> {
+    MapEqMatcher { expected }
+}
+
+/// A matcher which matches a map with the same entries as `expected`.
+///
+/// See [`map_eq`].
+#[derive(MatcherBase)]
+pub struct MapEqMatcher<ExpectedMapT> {
+    expected: ExpectedMapT,
+}
+
+impl<'a, KeyT, ValueT, ActualMapT, ExpectedMapT> Matcher<&'a ActualMapT>
+    for MapEqMatcher<ExpectedMapT>
+where
+    KeyT: Debug + Ord,
+    ValueT: Debug + PartialEq,
+    ActualMapT: MapLike<Key = KeyT, Value = ValueT> + Debug,
+    ExpectedMapT: MapLike<Key = KeyT, Value = ValueT> + Debug,
+{
+    fn matches(&self, actual: &'a ActualMapT) -> MatcherResult {
+        (actual.entries().count() == self.expected.entries().count()
+            && self.expected.entries().all(|(key, value)| actual.lookup(key) == Some(value)))
+        .into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => format!("is equal to {:?}", self.expected).into(),
+            MatcherResult::NoMatch => format!("isn't equal to {:?}", self.expected).into(),
+        }
+    }
+
+    fn explain_match(&self, actual: &'a ActualMapT) -> Description {
+        let mut differences = self
+            .expected
+            .entries()
+            .filter_map(|(key, expected)| match actual.lookup(key) {
+                None => Some((key, EntryDifference::Missing(expected))),
+                Some(actual) if actual != expected => {
+                    Some((key, EntryDifference::Changed { actual, expected }))
+                }
+                Some(_) => None,
+            })
+            .chain(
+                actual
+                    .entries()
+                    .filter(|(key, _)| self.expected.lookup(key).is_none())
+                    .map(|(key, actual)| (key, EntryDifference::Unexpected(actual))),
+            )
+            .collect::<Vec<_>>();
+        if differences.is_empty() {
+            return "which has the same entries".into();
+        }
+        differences.sort_by_key(|(key, _)| *key);
+        Description::new().text("which has different entries:").nested(
+            differences
+                .into_iter()
+                .map(|(key, difference)| difference.describe(key))
+                .collect::<Description>()
+                .bullet_list(),
+        )
+    }
+}
+
+/// How the entry of a key differs between the actual and expected maps.
+enum EntryDifference<'a, ValueT> {
+    Missing(&'a ValueT),
+    Unexpected(&'a ValueT),
+    Changed { actual: &'a ValueT, expected: &'a ValueT },
+}
+
+impl<'a, ValueT: Debug> EntryDifference<'a, ValueT> {
+    fn describe(&self, key: &impl Debug) -> Description {
+        match self {
+            EntryDifference::Missing(expected) => {
+                format!("key {key:?} is missing, expected to be mapped to {expected:?}").into()
+            }
+            EntryDifference::Unexpected(actual) => {
+                format!("key {key:?} is unexpected, mapped to {actual:?}").into()
+            }
+            EntryDifference::Changed { actual, expected } => {
+                let actual_debug = format!("{actual:#?}");
+                let expected_debug = format!("{expected:#?}");
+                let diff = create_diff(&actual_debug, &expected_debug, edit_distance::Mode::Exact);
+                if diff.is_empty() {
+                    format!("key {key:?} is mapped to {actual:?}, but {expected:?} was expected")
+                        .into()
+                } else {
+                    format!("key {key:?} is mapped to a different value:{diff}").into()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn map_eq_matches_equal_hash_maps() -> Result<()> {
+        verify_that!(
+            HashMap::from([(1, "a"), (2, "b")]),
+            map_eq(HashMap::from([(2, "b"), (1, "a")]))
+        )
+    }
+
+    #[test]
+    fn map_eq_matches_hash_map_against_btree_map() -> Result<()> {
+        verify_that!(
+            HashMap::from([(1, "a"), (2, "b")]),
+            map_eq(BTreeMap::from([(1, "a"), (2, "b")]))
+        )
+    }
+
+    #[test]
+    fn map_eq_does_not_match_map_with_extra_entry() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "a"), (2, "b")]), not(map_eq(BTreeMap::from([(1, "a")]))))
+    }
+
+    #[test]
+    fn map_eq_does_not_match_map_with_missing_entry() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "a")]), not(map_eq(BTreeMap::from([(1, "a"), (2, "b")]))))
+    }
+
+    #[test]
+    fn map_eq_does_not_match_map_with_changed_value() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "a")]), not(map_eq(BTreeMap::from([(1, "b")]))))
+    }
+
+    #[test]
+    fn map_eq_explains_differences_in_key_order() -> Result<()> {
+        let result = verify_that!(
+            BTreeMap::from([(4, "d"), (2, "x"), (1, "a"), (5, "e")]),
+            map_eq(HashMap::from([(1, "a"), (2, "b"), (3, "c")]))
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: {1: "a", 2: "x", 4: "d", 5: "e"},
+                  which has different entries:
+                    * key 2 is mapped to "x", but "b" was expected
+                    * key 3 is missing, expected to be mapped to "c"
+                    * key 4 is unexpected, mapped to "d"
+                    * key 5 is unexpected, mapped to "e"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn map_eq_shows_diff_of_multiline_values() -> Result<()> {
+        let result = verify_that!(
+            BTreeMap::from([("key", vec![1, 2, 3])]),
+            map_eq(BTreeMap::from([("key", vec![1, 4, 3])]))
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: {"key": [1, 2, 3]},
+                  which has different entries:
+                    * key "key" is mapped to a different value:
+                      Difference(-actual / +expected):
+                       [
+                           1,
+                      -    2,
+                      ?    ^
+                      +    4,
+                      ?    ^
+                           3,
+                       ]
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn map_eq_describes_expected_map() -> Result<()> {
+        let matcher = map_eq(BTreeMap::from([(1, "a")]));
+
+        verify_that!(
+            Matcher::<&BTreeMap<i32, &str>>::describe(&matcher, MatcherResult::Match),
+            displays_as(eq(r#"is equal to {1: "a"}"#))
+        )
+    }
+}
@@ -159,10 +159,10 @@
 /// #     .unwrap();
 /// ```
 ///
-/// **Important**: The method should be pure function with a deterministic
-/// output and no side effects. In particular, in the event of an assertion
-/// failure, it will be invoked a second time, with the assertion failure output
-/// reflecting the *second* invocation.
+/// The method is invoked once per assertion, and the assertion failure output
+/// reflects that invocation. Container matchers such as
+/// [`each`][crate::matchers::each] may invoke it again to explain a
+/// mismatching element.
 ///
 /// These may also include extra litteral parameters you pass in:
 ///
@@ -906,7 +906,7 @@ pub mod internal {
         }
     }
 
-    impl<T: Debug + Copy> Matcher<T> for PatternOnlyMatcher<T> {
+    impl<T: Debug + Clone> Matcher<T> for PatternOnlyMatcher<T> {
         fn matches(&self, actual: T) -> crate::matcher::MatcherResult {
             (self.matcher_function)(&actual).into()
         }
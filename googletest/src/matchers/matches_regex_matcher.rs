@@ -23,6 +23,8 @@ use std::ops::Deref;
 ///
 /// This is similar to [`contains_regex`][crate::matchers::contains_regex],
 /// except that the match must cover the whole string and not a substring.
+/// To also match the text of capture groups, use
+/// [`matches_regex_captures`][crate::matchers::matches_regex_captures].
 ///
 /// Both the actual value and the expected regular expression may be either a
 /// `String` or a string reference.
@@ -81,7 +83,7 @@ pub struct MatchesRegexMatcher<PatternT: Deref<Target = str>> {
 impl<PatternT, ActualT> Matcher<ActualT> for MatchesRegexMatcher<PatternT>
 where
     PatternT: Deref<Target = str>,
-    ActualT: AsRef<str> + Debug + Copy,
+    ActualT: AsRef<str> + Debug + Clone,
 {
     fn matches(&self, actual: ActualT) -> MatcherResult {
         self.regex.is_match(actual.as_ref()).into()
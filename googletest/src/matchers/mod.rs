@@ -15,14 +15,18 @@
 //! All built-in matchers of this crate are in submodules of this module.
 
 mod all_matcher;
+mod all_near_matcher;
 mod any_matcher;
 mod anything_matcher;
+mod boxed_matcher;
 mod char_count_matcher;
 mod conjunction_matcher;
 mod container_eq_matcher;
 mod contains_matcher;
 mod contains_regex_matcher;
+mod contains_subsequence_matcher;
 mod derefs_to_matcher;
+mod described_as_matcher;
 mod disjunction_matcher;
 mod display_matcher;
 mod each_matcher;
@@ -30,16 +34,24 @@ mod elements_are_matcher;
 mod empty_matcher;
 mod eq_matcher;
 mod err_matcher;
+mod explained_by_matcher;
 mod field_matcher;
 mod ge_matcher;
+mod golden_file_matcher;
 mod gt_matcher;
 mod has_entry_matcher;
+mod has_key_matcher;
+mod has_value_matcher;
 mod is_encoded_string_matcher;
 mod is_matcher;
 mod is_nan_matcher;
+mod is_sorted_matcher;
+mod iterates_as_matcher;
 mod le_matcher;
 mod len_matcher;
 mod lt_matcher;
+mod map_actual_matcher;
+mod map_eq_matcher;
 mod matches_pattern;
 mod matches_regex_matcher;
 mod near_matcher;
@@ -50,15 +62,21 @@ mod points_to_matcher;
 mod pointwise_matcher;
 mod predicate_matcher;
 mod property_matcher;
+mod regex_captures_matcher;
 mod result_of_matcher;
+mod snapshot_matcher;
 mod some_matcher;
 mod str_matcher;
+mod struct_matcher;
 mod subset_of_matcher;
 mod superset_of_matcher;
 mod tuple_matcher;
 mod unordered_elements_are_matcher;
+mod unordered_eq_matcher;
 
+pub use all_near_matcher::{all_approx_eq, all_near, AllNearMatcher, FloatArray};
 pub use anything_matcher::anything;
+pub use boxed_matcher::BoxedMatcher;
 pub use char_count_matcher::char_count;
 pub use container_eq_matcher::container_eq;
 pub use contains_matcher::{contains, ContainsMatcher};
@@ -70,13 +88,21 @@ pub use empty_matcher::empty;
 pub use eq_matcher::{eq, EqMatcher};
 pub use err_matcher::err;
 pub use ge_matcher::ge;
+pub use golden_file_matcher::{eq_file, eq_file_bytes, EqFileBytesMatcher};
 pub use gt_matcher::gt;
 pub use has_entry_matcher::has_entry;
+pub use has_key_matcher::has_key;
+pub use has_value_matcher::has_value;
 pub use is_encoded_string_matcher::is_utf8_string;
 pub use is_nan_matcher::is_nan;
+pub use is_sorted_matcher::{
+    is_sorted, is_sorted_by, is_sorted_by_key, is_sorted_descending, is_strictly_increasing,
+};
+pub use iterates_as_matcher::{iterates_as, lazily, IteratesAsMatcher, Lazily, LazilyIter};
 pub use le_matcher::le;
 pub use len_matcher::len;
 pub use lt_matcher::lt;
+pub use map_eq_matcher::{map_eq, MapEqMatcher};
 pub use matches_regex_matcher::matches_regex;
 pub use near_matcher::{approx_eq, near, NearMatcher};
 pub use none_matcher::none;
@@ -84,19 +110,27 @@ pub use not_matcher::not;
 pub use ok_matcher::ok;
 pub use points_to_matcher::points_to;
 pub use predicate_matcher::{predicate, PredicateMatcher};
+pub use regex_captures_matcher::{
+    matches_regex_captures, CaptureMatchers, MatchesRegexCapturesMatcher,
+};
+pub use snapshot_matcher::{DebugRendering, DisplayRendering, SnapshotMatcher, SnapshotRendering};
 pub use some_matcher::some;
 pub use str_matcher::{
     contains_substring, ends_with, starts_with, StrMatcher, StrMatcherConfigurator,
 };
 pub use subset_of_matcher::subset_of;
 pub use superset_of_matcher::superset_of;
+pub use unordered_eq_matcher::{unordered_eq, UnorderedEqMatcher};
 
 // Reexport and unmangle the macros.
 #[doc(inline)]
 pub use crate::{
-    __all as all, __any as any, __contains_each as contains_each, __elements_are as elements_are,
-    __field as field, __is_contained_in as is_contained_in, __matches_pattern as matches_pattern,
-    __pat as pat, __pointwise as pointwise, __property as property, __result_of as result_of,
+    __all as all, __any as any, __captures as captures,
+    __contains_contiguous as contains_contiguous, __contains_each as contains_each,
+    __contains_subsequence as contains_subsequence, __elements_are as elements_are,
+    __field as field, __has_entries as has_entries, __is_contained_in as is_contained_in,
+    __matches_pattern as matches_pattern, __matches_snapshot as matches_snapshot, __pat as pat,
+    __pointwise as pointwise, __property as property, __result_of as result_of,
     __result_of_ref as result_of_ref, __unordered_elements_are as unordered_elements_are,
 };
 
@@ -106,15 +140,28 @@ pub use crate::{
 // should only be used through their respective macros.
 #[doc(hidden)]
 pub mod __internal_unstable_do_not_depend_on_these {
+    pub use super::all_matcher::internal::all_of;
+    pub use super::any_matcher::internal::any_of;
     pub use super::conjunction_matcher::ConjunctionMatcher;
+    pub use super::contains_subsequence_matcher::internal::{
+        ContainsContiguous, ContainsSubsequence,
+    };
+    pub use super::described_as_matcher::DescribedAsMatcher;
     pub use super::disjunction_matcher::DisjunctionMatcher;
-    pub use super::elements_are_matcher::internal::ElementsAre;
+    pub use super::elements_are_matcher::internal::{
+        ElementPattern, ElementsAre, MatcherPatternKind, MatcherPatternTag, PatternWrapper, RestTag,
+    };
+    pub use super::explained_by_matcher::ExplainedByMatcher;
     pub use super::field_matcher::internal::field_matcher;
     pub use super::is_matcher::is;
+    pub use super::map_actual_matcher::MapActualMatcher;
     pub use super::matches_pattern::internal::pattern_only;
     pub use super::pointwise_matcher::internal::PointwiseMatcher;
     pub use super::property_matcher::internal::{property_matcher, property_ref_matcher};
+    pub use super::regex_captures_matcher::ParsedCapture;
     pub use super::result_of_matcher::internal::{result_of, result_of_ref};
+    pub use super::snapshot_matcher::SnapshotMatcher;
+    pub use super::struct_matcher::internal::StructMatcher;
     pub use super::unordered_elements_are_matcher::internal::{
         Requirements, UnorderedElementsAreMatcher,
     };
@@ -110,15 +110,15 @@ use std::fmt::Debug;
 /// # }
 /// # should_pass().unwrap();
 /// ```
+///
+/// The tolerance can be replaced by a distance in units in the last place
+/// ([`NearMatcher::within_ulps`]), a relative error
+/// ([`NearMatcher::within_relative`]), or either an absolute or a relative
+/// error ([`NearMatcher::abs_or_rel`]).
 #[track_caller]
 pub fn near<T: Debug + Float + Copy>(expected: T, max_abs_error: T) -> NearMatcher<T> {
-    if max_abs_error.is_nan() {
-        panic!("max_abs_error must not be NaN");
-    }
-    if max_abs_error < T::zero() {
-        panic!("max_abs_error must be non-negative");
-    }
-    NearMatcher { expected, max_abs_error, nans_are_equal: false }
+    check_tolerance(max_abs_error, "max_abs_error");
+    NearMatcher { expected, tolerance: Tolerance::Absolute(max_abs_error), nans_are_equal: false }
 }
 
 /// Matches a value approximately equal to `expected`.
@@ -135,7 +135,7 @@ pub fn approx_eq<T: Debug + Float + FloatConst + Copy>(expected: T) -> NearMatch
     let five_bits_of_mantissa = (T::one() + T::one()).powi(5);
     let abs_tolerance = five_bits_of_mantissa * T::epsilon();
     let max_abs_error = T::max(expected.abs() * abs_tolerance, abs_tolerance);
-    NearMatcher { expected, max_abs_error, nans_are_equal: false }
+    NearMatcher { expected, tolerance: Tolerance::Absolute(max_abs_error), nans_are_equal: false }
 }
 
 /// A matcher which matches floating-point numbers approximately equal to its
@@ -143,10 +143,25 @@ pub fn approx_eq<T: Debug + Float + FloatConst + Copy>(expected: T) -> NearMatch
 #[derive(MatcherBase)]
 pub struct NearMatcher<T: Debug> {
     expected: T,
-    max_abs_error: T,
+    tolerance: Tolerance<T>,
     nans_are_equal: bool,
 }
 
+/// The maximum distance between the actual and expected values accepted by a
+/// [`NearMatcher`].
+enum Tolerance<T> {
+    /// The maximum absolute error.
+    Absolute(T),
+    /// The maximum error relative to the magnitude of the expected value.
+    Relative(T),
+    /// The maximum absolute error and, alternatively, the maximum relative
+    /// error.
+    AbsoluteOrRelative(T, T),
+    /// The maximum number of representable values between the actual and
+    /// expected values.
+    Ulps(u64),
+}
+
 impl<T: Debug> NearMatcher<T> {
     /// Configures this instance to treat two NaNs as equal.
     ///
@@ -168,6 +183,161 @@ impl<T: Debug> NearMatcher<T> {
     }
 }
 
+impl<T: Debug + Float + Copy> NearMatcher<T> {
+    /// Configures this instance to match values at most `max_ulps` units in
+    /// the last place away from the expected value, replacing its tolerance.
+    ///
+    /// The distance in ULPs is the number of representable floating point
+    /// numbers between the two values, so that consecutive floating point
+    /// numbers are 1 ULP apart and `0.0` and `-0.0` are 0 ULPs apart. This
+    /// accepts errors proportional to the magnitude of the expected value, as
+    /// `FloatEq` and `DoubleEq` in C++ GoogleTest, which use 4 ULPs.
+    ///
+    /// Unlike with an absolute tolerance, an infinity matches the infinity
+    /// of the same sign.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(0.1 + 0.2, near(0.3, 0.0).within_ulps(4))?; // Passes
+    /// verify_that!(f64::INFINITY, near(f64::INFINITY, 0.0).within_ulps(4))?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!(1.001, near(1.0, 0.0).within_ulps(4))?; // Fails
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    pub fn within_ulps(mut self, max_ulps: u64) -> Self {
+        self.tolerance = Tolerance::Ulps(max_ulps);
+        self
+    }
+
+    /// Configures this instance to match values whose distance to the expected
+    /// value is at most `max_rel_error` times the magnitude of the expected
+    /// value, replacing its tolerance.
+    ///
+    /// The value `max_rel_error` may not be NaN and must be non-negative. This
+    /// method panics otherwise.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(1010.0, near(1000.0, 0.0).within_relative(0.01))?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!(1.1, near(1.0, 0.0).within_relative(0.01))?; // Fails
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    #[track_caller]
+    pub fn within_relative(mut self, max_rel_error: T) -> Self {
+        check_tolerance(max_rel_error, "max_rel_error");
+        self.tolerance = Tolerance::Relative(max_rel_error);
+        self
+    }
+
+    /// Configures this instance to match values within `max_abs_error` of the
+    /// expected value, or whose distance to the expected value is at most
+    /// `max_rel_error` times its magnitude, replacing its tolerance.
+    ///
+    /// This allows a relative tolerance for large values while still accepting
+    /// small errors around zero, where any relative tolerance is too strict.
+    ///
+    /// The values `max_abs_error` and `max_rel_error` may not be NaN and must
+    /// be non-negative. This method panics otherwise.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(1e-10, near(0.0, 0.0).abs_or_rel(1e-9, 1e-6))?; // Passes
+    /// verify_that!(1000.0001, near(1000.0, 0.0).abs_or_rel(1e-9, 1e-6))?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    #[track_caller]
+    pub fn abs_or_rel(mut self, max_abs_error: T, max_rel_error: T) -> Self {
+        check_tolerance(max_abs_error, "max_abs_error");
+        check_tolerance(max_rel_error, "max_rel_error");
+        self.tolerance = Tolerance::AbsoluteOrRelative(max_abs_error, max_rel_error);
+        self
+    }
+
+    fn describe_tolerance(&self) -> String {
+        match self.tolerance {
+            Tolerance::Absolute(max_abs_error) => {
+                format!("within {max_abs_error:?} of {:?}", self.expected)
+            }
+            Tolerance::Relative(max_rel_error) => {
+                format!("within a relative error of {max_rel_error:?} of {:?}", self.expected)
+            }
+            Tolerance::AbsoluteOrRelative(max_abs_error, max_rel_error) => format!(
+                "within {max_abs_error:?} or a relative error of {max_rel_error:?} of {:?}",
+                self.expected
+            ),
+            Tolerance::Ulps(max_ulps) => format!("within {max_ulps} ULPs of {:?}", self.expected),
+        }
+    }
+
+    fn relative_error(&self, actual: T) -> T {
+        (actual - self.expected).abs() / self.expected.abs()
+    }
+}
+
+#[track_caller]
+fn check_tolerance<T: Float>(tolerance: T, name: &str) {
+    if tolerance.is_nan() {
+        panic!("{name} must not be NaN");
+    }
+    if tolerance < T::zero() {
+        panic!("{name} must be non-negative");
+    }
+}
+
+/// Returns the position of `value` in the ordered sequence of the floating
+/// point numbers of its type, with `0.0` and `-0.0` both at position 0.
+///
+/// The difference between the positions of two values is their distance in
+/// units in the last place. Returns `None` for NaN.
+fn ulp_position<T: Float>(value: T) -> Option<i128> {
+    if value.is_nan() {
+        return None;
+    }
+    // All integer_decode results are exact: the value is mantissa *
+    // 2^exponent. The smallest positive normal value has the mantissa
+    // 2^(p - 1) where p is the precision, and subnormal values have a smaller
+    // exponent, which `integer_decode` compensates with a larger mantissa.
+    let (_, one_exponent) = decode(T::one());
+    let mantissa_bits = -one_exponent as u32;
+    let (_, normal_exponent) = decode(T::min_positive_value());
+    let (mantissa, exponent, sign) = value.integer_decode();
+    let position = if mantissa == 0 {
+        0
+    } else if exponent < normal_exponent {
+        (mantissa >> (normal_exponent - exponent)) as i128
+    } else {
+        (((exponent - normal_exponent) as i128) << mantissa_bits) + mantissa as i128
+    };
+    Some(if sign < 0 { -position } else { position })
+}
+
+fn decode<T: Float>(value: T) -> (u64, i16) {
+    let (mantissa, exponent, _) = value.integer_decode();
+    (mantissa, exponent)
+}
+
+/// Returns the distance in units in the last place between `a` and `b`, or
+/// `None` if either is NaN.
+fn ulp_distance<T: Float>(a: T, b: T) -> Option<u128> {
+    Some(ulp_position(a)?.abs_diff(ulp_position(b)?))
+}
+
 impl<T: Debug + Float + Copy> Matcher<T> for NearMatcher<T> {
     fn matches(&self, actual: T) -> MatcherResult {
         if self.nans_are_equal && self.expected.is_nan() && actual.is_nan() {
@@ -175,22 +345,47 @@ impl<T: Debug + Float + Copy> Matcher<T> for NearMatcher<T> {
         }
 
         let delta = actual - self.expected;
-        if delta >= -self.max_abs_error && delta <= self.max_abs_error {
-            MatcherResult::Match
-        } else {
-            MatcherResult::NoMatch
+        let within_abs = |max_abs_error: T| delta >= -max_abs_error && delta <= max_abs_error;
+        let within_rel = |max_rel_error: T| delta.abs() <= max_rel_error * self.expected.abs();
+        match self.tolerance {
+            Tolerance::Absolute(max_abs_error) => within_abs(max_abs_error),
+            Tolerance::Relative(max_rel_error) => within_rel(max_rel_error),
+            Tolerance::AbsoluteOrRelative(max_abs_error, max_rel_error) => {
+                within_abs(max_abs_error) || within_rel(max_rel_error)
+            }
+            Tolerance::Ulps(max_ulps) => ulp_distance(actual, self.expected)
+                .is_some_and(|distance| distance <= max_ulps as u128),
         }
+        .into()
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         match matcher_result {
-            MatcherResult::Match => {
-                format!("is within {:?} of {:?}", self.max_abs_error, self.expected).into()
-            }
-            MatcherResult::NoMatch => {
-                format!("isn't within {:?} of {:?}", self.max_abs_error, self.expected).into()
+            MatcherResult::Match => format!("is {}", self.describe_tolerance()).into(),
+            MatcherResult::NoMatch => format!("isn't {}", self.describe_tolerance()).into(),
+        }
+    }
+
+    fn explain_match(&self, actual: T) -> Description {
+        if actual.is_nan() || self.expected.is_nan() {
+            return format!("which {}", self.describe(self.matches(actual))).into();
+        }
+        match self.tolerance {
+            Tolerance::Absolute(_) => format!("which {}", self.describe(self.matches(actual))),
+            Tolerance::Relative(_) => {
+                format!("which has a relative error of {:?}", self.relative_error(actual))
             }
+            Tolerance::AbsoluteOrRelative(..) => format!(
+                "which has an absolute error of {:?} and a relative error of {:?}",
+                (actual - self.expected).abs(),
+                self.relative_error(actual)
+            ),
+            Tolerance::Ulps(_) => match ulp_distance(actual, self.expected) {
+                Some(distance) => format!("which is {distance} ULPs away"),
+                None => format!("which {}", self.describe(self.matches(actual))),
+            },
         }
+        .into()
     }
 }
 
@@ -346,4 +541,122 @@ mod tests {
     fn approx_eq_does_not_match_distant_number() -> Result<()> {
         verify_that!(0.0f64, not(approx_eq(1.0f64)))
     }
+
+    #[test]
+    fn within_ulps_matches_adjacent_number() -> Result<()> {
+        verify_that!(1.0f64 + f64::EPSILON, near(1.0f64, 0.0).within_ulps(1))
+    }
+
+    #[test]
+    fn within_ulps_does_not_match_number_too_many_ulps_away() -> Result<()> {
+        verify_that!(1.0f64 + 2.0 * f64::EPSILON, not(near(1.0f64, 0.0).within_ulps(1)))
+    }
+
+    #[test]
+    fn within_ulps_counts_across_zero() -> Result<()> {
+        let smallest = f32::from_bits(1);
+
+        verify_that!(smallest, near(-smallest, 0.0).within_ulps(2))?;
+        verify_that!(smallest, not(near(-smallest, 0.0).within_ulps(1)))
+    }
+
+    #[test]
+    fn within_ulps_counts_across_subnormal_boundary() -> Result<()> {
+        let below_normal = f64::from_bits(f64::MIN_POSITIVE.to_bits() - 1);
+
+        verify_that!(below_normal, near(f64::MIN_POSITIVE, 0.0).within_ulps(1))?;
+        verify_that!(f64::MIN_POSITIVE, not(near(0.0, 0.0).within_ulps(1 << 51)))
+    }
+
+    #[test]
+    fn within_ulps_matches_negative_zero_to_zero() -> Result<()> {
+        verify_that!(-0.0f64, near(0.0f64, 0.0).within_ulps(0))
+    }
+
+    #[test]
+    fn within_ulps_matches_infinity_to_infinity() -> Result<()> {
+        verify_that!(f64::INFINITY, near(f64::INFINITY, 0.0).within_ulps(0))
+    }
+
+    #[test]
+    fn within_ulps_does_not_match_nan() -> Result<()> {
+        verify_that!(f64::NAN, not(near(f64::NAN, 0.0).within_ulps(u64::MAX)))
+    }
+
+    #[test]
+    fn within_ulps_explains_ulp_distance() -> Result<()> {
+        let matcher = near(1.0f64, 0.0).within_ulps(2);
+
+        verify_that!(
+            matcher.explain_match(1.0 + 3.0 * f64::EPSILON),
+            displays_as(eq("which is 3 ULPs away"))
+        )
+    }
+
+    #[test]
+    fn within_ulps_describes_itself() -> Result<()> {
+        verify_that!(
+            near(1.0f64, 0.0).within_ulps(4).describe(MatcherResult::NoMatch),
+            displays_as(eq("isn't within 4 ULPs of 1.0"))
+        )
+    }
+
+    #[test]
+    fn within_relative_matches_number_within_relative_error() -> Result<()> {
+        verify_that!(-99.0f64, near(-100.0f64, 0.0).within_relative(0.01))
+    }
+
+    #[test]
+    fn within_relative_does_not_match_number_outside_relative_error() -> Result<()> {
+        verify_that!(0.98f64, not(near(1.0f64, 0.0).within_relative(0.01)))
+    }
+
+    #[test]
+    fn within_relative_explains_relative_error() -> Result<()> {
+        let matcher = near(4.0f64, 0.0).within_relative(0.1);
+
+        verify_that!(
+            matcher.explain_match(5.0),
+            displays_as(eq("which has a relative error of 0.25"))
+        )
+    }
+
+    #[::core::prelude::v1::test]
+    #[should_panic]
+    fn within_relative_panics_if_tolerance_is_negative() {
+        near(0.0, 0.0).within_relative(-1.0);
+    }
+
+    #[test]
+    fn abs_or_rel_matches_within_absolute_error() -> Result<()> {
+        verify_that!(1e-10f64, near(0.0f64, 0.0).abs_or_rel(1e-9, 1e-6))
+    }
+
+    #[test]
+    fn abs_or_rel_matches_within_relative_error() -> Result<()> {
+        verify_that!(1e6f64 + 0.5, near(1e6f64, 0.0).abs_or_rel(1e-9, 1e-6))
+    }
+
+    #[test]
+    fn abs_or_rel_does_not_match_outside_both_errors() -> Result<()> {
+        verify_that!(2.0f64, not(near(1.0f64, 0.0).abs_or_rel(0.5, 0.5)))
+    }
+
+    #[test]
+    fn abs_or_rel_describes_itself() -> Result<()> {
+        verify_that!(
+            near(1.0f64, 0.0).abs_or_rel(0.5, 0.25).describe(MatcherResult::Match),
+            displays_as(eq("is within 0.5 or a relative error of 0.25 of 1.0"))
+        )
+    }
+
+    #[test]
+    fn abs_or_rel_explains_both_errors() -> Result<()> {
+        let matcher = near(4.0f64, 0.0).abs_or_rel(0.5, 0.1);
+
+        verify_that!(
+            matcher.explain_match(5.0),
+            displays_as(eq("which has an absolute error of 1.0 and a relative error of 0.25"))
+        )
+    }
 }
@@ -38,7 +38,7 @@ pub fn none() -> NoneMatcher {
 #[derive(MatcherBase)]
 pub struct NoneMatcher;
 
-impl<T: Debug + Copy> Matcher<Option<T>> for NoneMatcher {
+impl<T: Debug + Clone> Matcher<Option<T>> for NoneMatcher {
     fn matches(&self, actual: Option<T>) -> MatcherResult {
         actual.is_none().into()
     }
@@ -14,6 +14,7 @@
 
 use crate::{
     description::Description,
+    internal::update_scope::with_updates_allowed,
     matcher::{Matcher, MatcherBase, MatcherResult},
 };
 use std::fmt::Debug;
@@ -42,9 +43,9 @@ pub struct NotMatcher<InnerMatcherT> {
     inner: InnerMatcherT,
 }
 
-impl<T: Debug + Copy, InnerMatcherT: Matcher<T>> Matcher<T> for NotMatcher<InnerMatcherT> {
+impl<T: Debug + Clone, InnerMatcherT: Matcher<T>> Matcher<T> for NotMatcher<InnerMatcherT> {
     fn matches(&self, actual: T) -> MatcherResult {
-        match self.inner.matches(actual) {
+        match with_updates_allowed(false, || self.inner.matches(actual)) {
             MatcherResult::Match => MatcherResult::NoMatch,
             MatcherResult::NoMatch => MatcherResult::Match,
         }
@@ -54,6 +55,21 @@ impl<T: Debug + Copy, InnerMatcherT: Matcher<T>> Matcher<T> for NotMatcher<Inner
         self.inner.explain_match(actual)
     }
 
+    fn match_and_explain(
+        &self,
+        actual: T,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        with_updates_allowed(false, || {
+            self.inner.match_and_explain(actual, &mut |result, explanation| {
+                then(
+                    if result.is_match() { MatcherResult::NoMatch } else { MatcherResult::Match },
+                    explanation,
+                )
+            })
+        })
+    }
+
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         self.inner.describe(if matcher_result.into() {
             MatcherResult::NoMatch
@@ -47,8 +47,8 @@ pub struct OkMatcher<InnerMatcherT> {
     inner: InnerMatcherT,
 }
 
-impl<T: Debug + Copy, E: Debug + Copy, InnerMatcherT: Matcher<T>> Matcher<std::result::Result<T, E>>
-    for OkMatcher<InnerMatcherT>
+impl<T: Debug + Clone, E: Debug + Clone, InnerMatcherT: Matcher<T>>
+    Matcher<std::result::Result<T, E>> for OkMatcher<InnerMatcherT>
 {
     fn matches(&self, actual: std::result::Result<T, E>) -> MatcherResult {
         actual.map(|v| self.inner.matches(v)).unwrap_or(MatcherResult::NoMatch)
@@ -63,6 +63,21 @@ impl<T: Debug + Copy, E: Debug + Copy, InnerMatcherT: Matcher<T>> Matcher<std::r
         }
     }
 
+    fn match_and_explain(
+        &self,
+        actual: std::result::Result<T, E>,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        match actual {
+            Ok(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+                then(result, &|| {
+                    Description::new().text("which is a success").nested(explanation())
+                })
+            }),
+            Err(_) => then(MatcherResult::NoMatch, &|| "which is an error".into()),
+        }
+    }
+
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         match matcher_result {
             MatcherResult::Match => format!(
@@ -95,6 +110,21 @@ impl<'a, T: Debug, E: Debug, InnerMatcherT: Matcher<&'a T>> Matcher<&'a std::res
         }
     }
 
+    fn match_and_explain(
+        &self,
+        actual: &'a std::result::Result<T, E>,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        match actual {
+            Ok(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+                then(result, &|| {
+                    Description::new().text("which is a success").nested(explanation())
+                })
+            }),
+            Err(_) => then(MatcherResult::NoMatch, &|| "which is an error".into()),
+        }
+    }
+
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         match matcher_result {
             MatcherResult::Match => format!(
@@ -44,15 +44,23 @@ pub struct PointsToMatcher<MatcherT> {
 
 impl<'a, ExpectedT, MatcherT> Matcher<&'a ExpectedT> for PointsToMatcher<MatcherT>
 where
-    ExpectedT: Debug + Copy,
+    ExpectedT: Debug + Clone,
     MatcherT: Matcher<ExpectedT>,
 {
     fn matches(&self, actual: &'a ExpectedT) -> MatcherResult {
-        self.expected.matches(*actual)
+        self.expected.matches(actual.clone())
     }
 
     fn explain_match(&self, actual: &'a ExpectedT) -> Description {
-        self.expected.explain_match(*actual)
+        self.expected.explain_match(actual.clone())
+    }
+
+    fn match_and_explain(
+        &self,
+        actual: &'a ExpectedT,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        self.expected.match_and_explain(actual.clone(), then)
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> Description {
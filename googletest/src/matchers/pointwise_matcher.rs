@@ -165,7 +165,7 @@ pub mod internal {
         }
     }
 
-    impl<T: Debug + Copy, MatcherT: Matcher<T>, ContainerT: Copy + Debug> Matcher<ContainerT>
+    impl<T: Debug + Clone, MatcherT: Matcher<T>, ContainerT: Clone + Debug> Matcher<ContainerT>
         for PointwiseMatcher<MatcherT>
     where
         ContainerT: IntoIterator<Item = T>,
@@ -192,8 +192,9 @@ pub mod internal {
             let mut zipped_iterator = zip(actual_iterator, self.matchers.iter());
             let mut mismatches = Vec::new();
             for (idx, (a, e)) in zipped_iterator.by_ref().enumerate() {
-                if e.matches(a).is_no_match() {
-                    mismatches.push(format!("element #{idx} is {a:?}, {}", e.explain_match(a)));
+                if e.matches(a.clone()).is_no_match() {
+                    mismatches
+                        .push(format!("element #{idx} is {a:?}, {}", e.explain_match(a.clone())));
                 }
             }
             if mismatches.is_empty() {
@@ -113,7 +113,7 @@ where
 #[doc(hidden)]
 pub struct NoDescription;
 
-impl<T: Debug + Copy, P> Matcher<T> for PredicateMatcher<P, NoDescription, NoDescription>
+impl<T: Debug + Clone, P> Matcher<T> for PredicateMatcher<P, NoDescription, NoDescription>
 where
     P: Fn(T) -> bool,
 {
@@ -129,7 +129,7 @@ where
     }
 }
 
-impl<T: Debug + Copy, P, D1: PredicateDescription, D2: PredicateDescription> Matcher<T>
+impl<T: Debug + Clone, P, D1: PredicateDescription, D2: PredicateDescription> Matcher<T>
     for PredicateMatcher<P, D1, D2>
 where
     P: Fn(T) -> bool,
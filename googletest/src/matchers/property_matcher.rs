@@ -58,10 +58,11 @@
 /// #    .unwrap();
 /// ```
 ///
-/// **Important**: The method should be pure function with a deterministic
-/// output and no side effects. In particular, in the event of an assertion
-/// failure, it will be invoked a second time, with the assertion failure output
-/// reflecting the *second* invocation.
+/// The method is invoked once per assertion, and the assertion failure output
+/// reflects that invocation, so methods with side effects or expensive
+/// computations can be used. Container matchers such as
+/// [`each`][crate::matchers::each] may invoke it again to explain a
+/// mismatching element.
 ///
 /// The method may also take additional litteral arguments:
 ///
@@ -88,7 +89,7 @@
 /// $TYPE.$PROPERTY\($ARGUMENT\)`.
 ///
 /// The `&` allows to specify whether this matcher matches against an actual of
-/// type `$TYPE` (`$TYPE` must implement `Copy`) or a `&$TYPE`.
+/// type `$TYPE` (`$TYPE` must implement `Clone`) or a `&$TYPE`.
 ///
 /// For instance:
 ///
@@ -123,7 +124,7 @@
 /// ```
 ///
 /// The `ref` allows to bind the property returned value by reference, which is
-/// required if the field type does not implement `Copy`.
+/// required if the field type does not implement `Clone`.
 ///
 /// For instance:
 ///
@@ -233,8 +234,8 @@ pub mod internal {
 
     impl<InnerT, OuterT, MatcherT> Matcher<OuterT> for PropertyMatcher<OuterT, InnerT, MatcherT>
     where
-        InnerT: Debug + Copy,
-        OuterT: Debug + Copy,
+        InnerT: Debug + Clone,
+        OuterT: Debug + Clone,
         MatcherT: Matcher<InnerT>,
     {
         fn matches(&self, actual: OuterT) -> MatcherResult {
@@ -256,10 +257,29 @@ pub mod internal {
                 "whose property `{}` is `{:#?}`, {}",
                 self.property_desc,
                 actual_inner,
-                self.inner.explain_match(actual_inner)
+                self.inner.explain_match(actual_inner.clone())
             )
             .into()
         }
+
+        fn match_and_explain(
+            &self,
+            actual: OuterT,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            let actual_inner = (self.extractor)(&actual);
+            self.inner.match_and_explain(actual_inner.clone(), &mut |result, explanation| {
+                then(result, &|| {
+                    format!(
+                        "whose property `{}` is `{:#?}`, {}",
+                        self.property_desc,
+                        actual_inner,
+                        explanation()
+                    )
+                    .into()
+                })
+            })
+        }
     }
 
     impl<'a, InnerT, OuterT, MatcherT> Matcher<&'a OuterT> for PropertyMatcher<OuterT, InnerT, MatcherT>
@@ -291,6 +311,25 @@ pub mod internal {
             )
             .into()
         }
+
+        fn match_and_explain(
+            &self,
+            actual: &'a OuterT,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            let actual_inner = (self.extractor)(actual);
+            self.inner.match_and_explain(&actual_inner, &mut |result, explanation| {
+                then(result, &|| {
+                    format!(
+                        "whose property `{}` is `{:#?}`, {}",
+                        self.property_desc,
+                        actual_inner,
+                        explanation()
+                    )
+                    .into()
+                })
+            })
+        }
     }
 
     /// **For internal use only. API stablility is not guaranteed!**
@@ -318,7 +357,7 @@ pub mod internal {
 
     impl<
             InnerT: Debug,
-            OuterT: Debug + Copy,
+            OuterT: Debug + Clone,
             MatcherT: for<'a> Matcher<&'a InnerT>,
             ExtractorT: Fn(OuterT) -> InnerT,
         > Matcher<OuterT> for PropertyRefMatcher<ExtractorT, MatcherT>
@@ -346,5 +385,24 @@ pub mod internal {
             )
             .into()
         }
+
+        fn match_and_explain(
+            &self,
+            actual: OuterT,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            let actual_inner = (self.extractor)(actual);
+            self.inner.match_and_explain(&actual_inner, &mut |result, explanation| {
+                then(result, &|| {
+                    format!(
+                        "whose property `{}` is `{:#?}`, {}",
+                        self.property_desc,
+                        actual_inner,
+                        explanation()
+                    )
+                    .into()
+                })
+            })
+        }
     }
 }
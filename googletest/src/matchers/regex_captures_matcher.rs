@@ -0,0 +1,517 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{explain_with_match_and_explain, Matcher, MatcherBase, MatcherResult};
+use crate::matchers::BoxedMatcher;
+use regex::Regex;
+use std::fmt::{Debug, Display};
+use std::ops::Deref;
+
+/// Matches a string the entirety of which matches the given regular
+/// expression, and whose capture groups are matched by the given matchers.
+///
+/// This is similar to [`matches_regex`][crate::matchers::matches_regex],
+/// except that it also checks the text captured by groups of the regular
+/// expression. The matchers may be given as an array or a `Vec`, in which case
+/// the first matcher applies to the first capture group, and so on:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(
+///     "2024-03-01",
+///     matches_regex_captures(r"(\d+)-(\d+)-(\d+)", [eq("2024"), eq("03"), eq("01")])
+/// )?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(
+///     "2024-03-01",
+///     matches_regex_captures(r"(\d+)-(\d+)-\d+", [starts_with("20"), starts_with("1")])
+/// )?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Matchers of different types can be combined by boxing them with
+/// [`MatcherBase::boxed`]. Alternatively, named capture groups can be matched
+/// with [`captures!`][crate::matchers::captures].
+///
+/// Each capture group is passed to its matcher as a `String`. A capture group
+/// which does not participate in the match, such as an alternative which was
+/// not taken, does not match.
+///
+/// Panics if the given `pattern` is not a syntactically valid regular
+/// expression, or if it does not define a capture group which is to be
+/// matched.
+pub fn matches_regex_captures<'a, PatternT: Deref<Target = str>>(
+    pattern: PatternT,
+    captures: impl Into<CaptureMatchers<'a>>,
+) -> MatchesRegexCapturesMatcher<'a> {
+    let regex = Regex::new(&format!("^(?:{})$", pattern.deref())).unwrap();
+    let captures = captures.into();
+    for (group, _) in &captures.groups {
+        let is_defined = match group {
+            CaptureGroup::Index(index) => *index < regex.captures_len(),
+            CaptureGroup::Name(name) => regex.capture_names().any(|n| n == Some(name)),
+        };
+        if !is_defined {
+            panic!("The regular expression {:?} has no capture {group}.", pattern.deref());
+        }
+    }
+    MatchesRegexCapturesMatcher { regex, pattern: pattern.to_string(), captures }
+}
+
+/// Matches named capture groups of a regular expression.
+///
+/// This is used as the second argument of
+/// [`matches_regex_captures`][crate::matchers::matches_regex_captures]. Each
+/// entry maps the name of a capture group to the matcher for the text it
+/// captured:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(
+///     "2024-03-01",
+///     matches_regex_captures(
+///         r"(?<year>\d+)-(?<month>\d+)-(?<day>\d+)",
+///         captures! { "year" => starts_with("20"), "day" => eq("01") }
+///     )
+/// )?;  // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// The captured text is passed to the matcher as a `String`, so a matcher for
+/// another type does not compile, failing with an error such as "can't
+/// compare `String` with `{integer}`":
+///
+/// ```compile_fail
+/// # use googletest::prelude::*;
+/// verify_that!(
+///     "2024-03-01",
+///     matches_regex_captures(r"(?<year>\d+)-\d+-\d+", captures! { "year" => gt(2000) })
+/// )
+/// # .unwrap();
+/// ```
+///
+/// To match the text as another type, name the type with `as`. The text is
+/// then parsed with [`str::parse`], and does not match if it cannot be parsed:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(
+///     "2024-03-01",
+///     matches_regex_captures(
+///         r"(?<year>\d+)-(?<month>\d+)-\d+",
+///         captures! { "year" as u32 => gt(2000), "month" as u32 => le(12) }
+///     )
+/// )?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(
+///     "1999-03-01",
+///     matches_regex_captures(
+///         r"(?<year>\d+)-(?<month>\d+)-\d+",
+///         captures! { "year" as u32 => gt(2000), "month" as u32 => le(12) }
+///     )
+/// )?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __captures {
+    (@matcher $type:ty, $matcher:expr) => {
+        $crate::matcher::MatcherBase::boxed(
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::ParsedCapture::<
+                $type,
+                _,
+            >::new($matcher),
+        )
+    };
+
+    (@matcher , $matcher:expr) => {
+        $crate::matcher::MatcherBase::boxed($matcher)
+    };
+
+    ($($name:literal $(as $type:ty)? => $matcher:expr),* $(,)?) => {
+        $crate::matchers::CaptureMatchers::named(vec![
+            $(($name, $crate::__captures!(@matcher $($type)?, $matcher))),*
+        ])
+    };
+}
+
+/// The matchers for the capture groups of
+/// [`matches_regex_captures`][crate::matchers::matches_regex_captures].
+///
+/// This is created from an array or a `Vec` of matchers for the capture groups
+/// in order, or by [`captures!`][crate::matchers::captures] for named capture
+/// groups.
+pub struct CaptureMatchers<'a> {
+    groups: Vec<(CaptureGroup, BoxedMatcher<'a, String>)>,
+}
+
+impl<'a> CaptureMatchers<'a> {
+    /// Factory only intended for use in the macro `captures!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn named(groups: Vec<(&str, BoxedMatcher<'a, String>)>) -> Self {
+        Self {
+            groups: groups
+                .into_iter()
+                .map(|(name, matcher)| (CaptureGroup::Name(name.to_string()), matcher))
+                .collect(),
+        }
+    }
+}
+
+impl<'a, MatcherT: Matcher<String> + 'a> From<Vec<MatcherT>> for CaptureMatchers<'a> {
+    fn from(matchers: Vec<MatcherT>) -> Self {
+        Self {
+            groups: matchers
+                .into_iter()
+                .enumerate()
+                .map(|(i, matcher)| (CaptureGroup::Index(i + 1), BoxedMatcher::new(matcher)))
+                .collect(),
+        }
+    }
+}
+
+impl<'a, MatcherT: Matcher<String> + 'a, const N: usize> From<[MatcherT; N]>
+    for CaptureMatchers<'a>
+{
+    fn from(matchers: [MatcherT; N]) -> Self {
+        Vec::from(matchers).into()
+    }
+}
+
+enum CaptureGroup {
+    Index(usize),
+    Name(String),
+}
+
+impl Display for CaptureGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureGroup::Index(index) => write!(f, "group {index}"),
+            CaptureGroup::Name(name) => write!(f, "group {name:?}"),
+        }
+    }
+}
+
+/// A matcher matching a string against a regular expression and its capture
+/// groups against inner matchers.
+///
+/// Intended only to be used from the function [`matches_regex_captures`] only.
+/// Should not be referenced by code outside this library.
+#[derive(MatcherBase)]
+pub struct MatchesRegexCapturesMatcher<'a> {
+    regex: Regex,
+    pattern: String,
+    captures: CaptureMatchers<'a>,
+}
+
+impl<'a> MatchesRegexCapturesMatcher<'a> {
+    // Returns the text captured by each group to be matched, or `None` if the
+    // regular expression does not match at all.
+    fn captured_texts(&self, actual: &str) -> Option<Vec<Option<String>>> {
+        let captures = self.regex.captures(actual)?;
+        Some(
+            self.captures
+                .groups
+                .iter()
+                .map(|(group, _)| {
+                    match group {
+                        CaptureGroup::Index(index) => captures.get(*index),
+                        CaptureGroup::Name(name) => captures.name(name),
+                    }
+                    .map(|m| m.as_str().to_string())
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<'a, ActualT: AsRef<str> + Debug + Clone> Matcher<ActualT> for MatchesRegexCapturesMatcher<'a> {
+    fn matches(&self, actual: ActualT) -> MatcherResult {
+        let Some(texts) = self.captured_texts(actual.as_ref()) else {
+            return MatcherResult::NoMatch;
+        };
+        texts
+            .into_iter()
+            .zip(&self.captures.groups)
+            .all(|(text, (_, matcher))| text.is_some_and(|text| matcher.matches(text).is_match()))
+            .into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        let groups = self
+            .captures
+            .groups
+            .iter()
+            .map(|(group, matcher)| {
+                format!("{group}, which {}", matcher.describe(MatcherResult::Match))
+            })
+            .collect::<Description>()
+            .bullet_list()
+            .indent();
+        match matcher_result {
+            MatcherResult::Match => format!(
+                "matches the regular expression {:#?} with capture groups:\n{groups}",
+                self.pattern
+            )
+            .into(),
+            MatcherResult::NoMatch => format!(
+                "doesn't match the regular expression {:#?} with capture groups:\n{groups}",
+                self.pattern
+            )
+            .into(),
+        }
+    }
+
+    fn explain_match(&self, actual: ActualT) -> Description {
+        let Some(texts) = self.captured_texts(actual.as_ref()) else {
+            return format!("which doesn't match the regular expression {:#?}", self.pattern)
+                .into();
+        };
+        let failures = texts
+            .into_iter()
+            .zip(&self.captures.groups)
+            .filter_map(|(text, (group, matcher))| match text {
+                None => Some(format!("{group} didn't participate in the match")),
+                Some(text) => {
+                    let mut failure = None;
+                    matcher.match_and_explain(text.clone(), &mut |result, explanation| {
+                        if result.is_no_match() {
+                            failure = Some(format!("{group} is {text:?}, {}", explanation()));
+                        }
+                    });
+                    failure
+                }
+            })
+            .collect::<Description>();
+        if failures.is_empty() {
+            return format!(
+                "which matches the regular expression {:#?} with all capture groups",
+                self.pattern
+            )
+            .into();
+        }
+        format!(
+            "which matches the regular expression {:#?}, but the capture groups don't:\n{}",
+            self.pattern,
+            failures.bullet_list().indent()
+        )
+        .into()
+    }
+}
+
+/// Matches the text of a capture group parsed as `T`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[derive(MatcherBase)]
+pub struct ParsedCapture<T, MatcherT> {
+    inner: MatcherT,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T, MatcherT> ParsedCapture<T, MatcherT> {
+    pub fn new(inner: MatcherT) -> Self {
+        Self { inner, phantom: std::marker::PhantomData }
+    }
+}
+
+impl<T, MatcherT> Matcher<String> for ParsedCapture<T, MatcherT>
+where
+    T: std::str::FromStr + Debug + Clone,
+    T::Err: Display,
+    MatcherT: Matcher<T>,
+{
+    fn matches(&self, actual: String) -> MatcherResult {
+        match actual.parse::<T>() {
+            Ok(value) => self.inner.matches(value),
+            Err(_) => MatcherResult::NoMatch,
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        format!(
+            "parses as {} which {}",
+            std::any::type_name::<T>(),
+            self.inner.describe(matcher_result)
+        )
+        .into()
+    }
+
+    fn explain_match(&self, actual: String) -> Description {
+        explain_with_match_and_explain(self, actual)
+    }
+
+    fn match_and_explain(
+        &self,
+        actual: String,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        match actual.parse::<T>() {
+            Ok(value) => self.inner.match_and_explain(value, then),
+            Err(e) => then(MatcherResult::NoMatch, &|| {
+                format!("which doesn't parse as {}: {e}", std::any::type_name::<T>()).into()
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_positional_capture_groups() -> Result<()> {
+        let matcher = matches_regex_captures(r"(\w+)@(\w+)\.com", [eq("alice"), eq("example")]);
+
+        verify_that!(matcher.matches("alice@example.com"), eq(MatcherResult::Match))?;
+        verify_that!(matcher.matches("bob@example.com"), eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn does_not_match_string_only_part_of_which_matches() -> Result<()> {
+        let matcher = matches_regex_captures(r"(\d+)", [anything()]);
+
+        verify_that!(matcher.matches("123 apples"), eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn matches_whole_string_against_alternatives() -> Result<()> {
+        let matcher = matches_regex_captures(r"a(\d)|b(\d)", vec![eq("1")]);
+
+        verify_that!(matcher.matches("a1"), eq(MatcherResult::Match))?;
+        verify_that!(matcher.matches("a1b2"), eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn matches_boxed_matchers_of_different_types() -> Result<()> {
+        verify_that!(
+            "v1.22",
+            matches_regex_captures(r"v(\d+)\.(\d+)", [eq("1").boxed(), starts_with("2").boxed()])
+        )
+    }
+
+    #[test]
+    fn matches_named_capture_groups_parsed_as_numbers() -> Result<()> {
+        let matcher = matches_regex_captures(
+            r"(?<year>\d+)-(?<month>\d+)",
+            captures! { "year" as i32 => gt(2000), "month" as u8 => le(12) },
+        );
+
+        verify_that!(matcher.matches("2024-03"), eq(MatcherResult::Match))?;
+        verify_that!(matcher.matches("2024-13"), eq(MatcherResult::NoMatch))?;
+        verify_that!(matcher.matches("2024-300"), eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn does_not_match_group_not_participating_in_match() -> Result<()> {
+        let result = verify_that!(
+            "a1",
+            matches_regex_captures(r"a(?<a>\d)|b(?<b>\d)", captures! { "b" => anything() })
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("* group \"b\" didn't participate in the match")))
+        )
+    }
+
+    #[test]
+    fn describes_capture_groups() -> Result<()> {
+        let matcher = matches_regex_captures(
+            r"(?<year>\d+)-(?<month>\d+)",
+            captures! { "year" as i32 => gt(2000), "month" => eq("03") },
+        );
+
+        verify_that!(
+            Matcher::<&str>::describe(&matcher, MatcherResult::NoMatch),
+            displays_as(eq(indoc!(
+                r#"
+                doesn't match the regular expression "(?<year>\\d+)-(?<month>\\d+)" with capture groups:
+                  * group "year", which parses as i32 which is greater than 2000
+                  * group "month", which is equal to "03""#
+            )))
+        )
+    }
+
+    #[test]
+    fn explains_which_capture_groups_do_not_match() -> Result<()> {
+        let result = verify_that!(
+            "1999-04-xx",
+            matches_regex_captures(
+                r"(?<year>\d+)-(?<month>\d+)-(?<day>\w+)",
+                captures! {
+                    "year" as i32 => gt(2000),
+                    "month" => eq("04"),
+                    "day" as u8 => anything(),
+                }
+            )
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: "1999-04-xx",
+                  which matches the regular expression "(?<year>\\d+)-(?<month>\\d+)-(?<day>\\w+)", but the capture groups don't:
+                    * group "year" is "1999", which is less than or equal to 2000
+                    * group "day" is "xx", which doesn't parse as u8: invalid digit found in string
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn explains_mismatch_of_regular_expression() -> Result<()> {
+        let result = verify_that!("no digits", matches_regex_captures(r"(\d+)", [eq("1")]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "which doesn't match the regular expression \"(\\\\d+)\""
+            )))
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "has no capture group \"day\"")]
+    fn panics_on_undefined_named_group() {
+        let _ = matches_regex_captures(r"(?<year>\d+)", captures! { "day" => anything() });
+    }
+
+    #[test]
+    #[should_panic(expected = "has no capture group 2")]
+    fn panics_on_more_matchers_than_groups() {
+        let _ = matches_regex_captures(r"(\d+)", [anything(), anything()]);
+    }
+}
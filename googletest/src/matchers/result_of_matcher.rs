@@ -15,7 +15,10 @@
 /// Matches a value where the result of `callable` applied to the value matches
 /// the inner matcher.
 ///
-/// The `callable` will be called twice, so make sure it is pure.
+/// The `callable` is called once per assertion, and the failure message
+/// reports the result of that call. Container matchers such as
+/// [`each`][crate::matchers::each] may call it again to explain a mismatching
+/// element, so it should still be free of side effects in that case.
 /// ```
 /// use googletest::prelude::*;
 /// fn should_pass() -> googletest::Result<()> {
@@ -44,7 +47,10 @@ macro_rules! __result_of {
 /// Matches a value where the reference to the result of `callable` applied to
 /// the value matches the inner matcher.
 ///
-/// The `callable` will be called twice, so make sure it is pure.
+/// The `callable` is called once per assertion, and the failure message
+/// reports the result of that call. Container matchers such as
+/// [`each`][crate::matchers::each] may call it again to explain a mismatching
+/// element, so it should still be free of side effects in that case.
 /// ```
 /// use googletest::prelude::*;
 /// fn should_pass_1() -> googletest::Result<()> {
@@ -82,7 +88,7 @@ macro_rules! __result_of_ref {
 #[doc(hidden)]
 pub mod internal {
     use crate::description::Description;
-    use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+    use crate::matcher::{explain_with_match_and_explain, Matcher, MatcherBase, MatcherResult};
     use std::fmt::Debug;
 
     pub fn result_of<Callable, InnerMatcher>(
@@ -100,7 +106,7 @@ pub mod internal {
         callable_description: &'static str,
     }
 
-    impl<I: Copy + Debug, T: Debug + Copy, CallableT: Fn(I) -> T, InnerMatcherT: Matcher<T>>
+    impl<I: Clone + Debug, T: Debug + Clone, CallableT: Fn(I) -> T, InnerMatcherT: Matcher<T>>
         Matcher<I> for ResultOfMatcher<CallableT, InnerMatcherT>
     {
         fn matches(&self, actual: I) -> MatcherResult {
@@ -114,10 +120,22 @@ pub mod internal {
         }
 
         fn explain_match(&self, actual: I) -> Description {
+            explain_with_match_and_explain(self, actual)
+        }
+
+        fn match_and_explain(
+            &self,
+            actual: I,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
             let actual_result = (self.callable)(actual);
-            Description::new()
-                .text(format!("which, results into {actual_result:?}",))
-                .nested(self.describe(self.matches(actual)))
+            self.inner_matcher.match_and_explain(actual_result.clone(), &mut |result, _| {
+                then(result, &|| {
+                    Description::new()
+                        .text(format!("which, results into {actual_result:?}",))
+                        .nested(self.describe(result))
+                })
+            })
         }
     }
 
@@ -136,7 +154,7 @@ pub mod internal {
     }
 
     impl<
-            I: Copy + Debug,
+            I: Clone + Debug,
             T: Debug,
             Callable: Fn(I) -> T,
             InnerMatcherT: for<'a> Matcher<&'a T>,
@@ -153,10 +171,22 @@ pub mod internal {
         }
 
         fn explain_match(&self, actual: I) -> Description {
+            explain_with_match_and_explain(self, actual)
+        }
+
+        fn match_and_explain(
+            &self,
+            actual: I,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
             let actual_result = (self.callable)(actual);
-            Description::new()
-                .text(format!("which, results into {actual_result:?}",))
-                .nested(self.describe(self.matches(actual)))
+            self.inner_matcher.match_and_explain(&actual_result, &mut |result, _| {
+                then(result, &|| {
+                    Description::new()
+                        .text(format!("which, results into {actual_result:?}",))
+                        .nested(self.describe(result))
+                })
+            })
         }
     }
 }
@@ -165,6 +195,7 @@ pub mod internal {
 mod tests {
     use crate::prelude::*;
     use indoc::indoc;
+    use std::cell::Cell;
 
     #[test]
     fn result_of_match_with_value() -> Result<()> {
@@ -437,4 +468,32 @@ mod tests {
             )))
         )
     }
+
+    #[test]
+    fn result_of_calls_callable_once_on_mismatch() -> Result<()> {
+        let calls = Cell::new(0);
+        let next_call = |value: i32| {
+            calls.set(calls.get() + 1);
+            value + calls.get()
+        };
+
+        let result = verify_that!(10, result_of!(next_call, eq(0)));
+
+        verify_that!(calls.get(), eq(1))?;
+        verify_that!(result, err(displays_as(contains_substring("which, results into 11"))))
+    }
+
+    #[test]
+    fn result_of_ref_calls_callable_once_on_mismatch() -> Result<()> {
+        let calls = Cell::new(0);
+        let next_call = |value: i32| {
+            calls.set(calls.get() + 1);
+            value + calls.get()
+        };
+
+        let result = verify_that!(10, result_of_ref!(next_call, eq(&0)));
+
+        verify_that!(calls.get(), eq(1))?;
+        verify_that!(result, err(displays_as(contains_substring("which, results into 11"))))
+    }
 }
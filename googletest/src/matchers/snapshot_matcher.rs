@@ -0,0 +1,563 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::internal::update_scope::updates_allowed;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use crate::matcher_support::{edit_distance, summarize_diff::create_diff};
+use regex::Regex;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::io::ErrorKind;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+/// Matches a value whose rendering equals a snapshot stored in a file.
+///
+/// The snapshot is stored in the directory `snapshots` next to the source file
+/// of the test. Its file name is derived from the name of the source file, the
+/// name of the test, and the index of the snapshot assertion within the test,
+/// such as `snapshots/my_test__renders_report__1.snap` for the first snapshot
+/// assertion of the test `renders_report` in `my_test.rs`. Alternatively, a
+/// name for the snapshot can be given, which replaces the test name and index,
+/// as in `snapshots/snapshot_matcher__report.snap` for this example:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug)]
+/// struct Report {
+///     title: &'static str,
+///     pages: u32,
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// let report = Report { title: "Quarterly", pages: 12 };
+/// verify_that!(report, matches_snapshot!("report"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// The value is rendered with the alternate [`Debug`] formatting `{:#?}`. Use
+/// [`SnapshotMatcher::using_display`] to render it with [`Display`] instead.
+/// Substrings which vary from one run to the next, such as timestamps, can be
+/// replaced before the comparison with [`SnapshotMatcher::redacting`].
+///
+/// If the snapshot is missing or differs, the match fails and the explanation
+/// shows a diff between the rendered value and the snapshot. With
+/// `pages: 13` in the example above, the assertion fails with:
+///
+/// ```text
+/// Value of: report
+/// Expected: matches the snapshot src/matchers/snapshots/snapshot_matcher__report.snap
+/// Actual: Report { title: "Quarterly", pages: 13 },
+///   which doesn't match the snapshot src/matchers/snapshots/snapshot_matcher__report.snap; run with GOOGLETEST_UPDATE_SNAPSHOTS=1 to update it
+///   Difference(-actual / +expected):
+///    Report {
+///        title: "Quarterly",
+///   -    pages: 13,
+///   ?            ^
+///   +    pages: 12,
+///   ?            ^
+///    }
+/// ```
+///
+/// Running the tests with the environment variable
+/// `GOOGLETEST_UPDATE_SNAPSHOTS=1` instead writes the rendered value to the
+/// snapshot file, creating or replacing it, and the assertion passes. Only
+/// assertions write snapshots: a snapshot matcher inside [`not`] or one whose
+/// `matches` is called directly never does. The snapshot files are meant to
+/// be checked in together with the tests.
+///
+/// [`not`]: crate::matchers::not
+///
+/// The test name is taken from the name of the current thread, which the
+/// standard test harness sets to the path of the test. Outside of the test
+/// harness, the module path of the assertion is used.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __matches_snapshot {
+    () => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::SnapshotMatcher::new(
+            env!("CARGO_MANIFEST_DIR"),
+            file!(),
+            module_path!(),
+            ::std::option::Option::None,
+        )
+    };
+
+    ($name:expr $(,)?) => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::SnapshotMatcher::new(
+            env!("CARGO_MANIFEST_DIR"),
+            file!(),
+            module_path!(),
+            ::std::option::Option::Some($name),
+        )
+    };
+}
+
+/// A matcher comparing the rendering of a value with a snapshot stored in a
+/// file.
+///
+/// Intended only to be used from the macro
+/// [`matches_snapshot!`][crate::matchers::matches_snapshot].
+#[derive(MatcherBase)]
+pub struct SnapshotMatcher<RenderingT = DebugRendering> {
+    path: PathBuf,
+    display_path: String,
+    redactions: Vec<Redaction>,
+    rendering: PhantomData<RenderingT>,
+}
+
+// Replaces volatile substrings of the rendered value.
+type Redaction = Box<dyn Fn(&str) -> String>;
+
+/// The rendering of values with the alternate [`Debug`] formatting, which
+/// [`SnapshotMatcher`] uses by default.
+pub struct DebugRendering;
+
+/// The rendering of values with [`Display`], selected by
+/// [`SnapshotMatcher::using_display`].
+pub struct DisplayRendering;
+
+/// A way to render the actual value of a [`SnapshotMatcher`] as text.
+pub trait SnapshotRendering<T> {
+    fn render(actual: &T) -> String;
+}
+
+impl<T: Debug> SnapshotRendering<T> for DebugRendering {
+    fn render(actual: &T) -> String {
+        format!("{actual:#?}")
+    }
+}
+
+impl<T: Display> SnapshotRendering<T> for DisplayRendering {
+    fn render(actual: &T) -> String {
+        format!("{actual}")
+    }
+}
+
+impl SnapshotMatcher {
+    /// Creates a matcher for the snapshot of the current test.
+    ///
+    /// `source_file` is the path given by [`file!`], which is relative to
+    /// `manifest_dir` or one of its ancestors for crates in a workspace.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn new(
+        manifest_dir: &str,
+        source_file: &str,
+        module_path: &str,
+        name: Option<&str>,
+    ) -> Self {
+        let manifest_dir = Path::new(manifest_dir);
+        let source_path = manifest_dir
+            .ancestors()
+            .map(|dir| dir.join(source_file))
+            .find(|path| path.exists())
+            .unwrap_or_else(|| manifest_dir.join(source_file));
+        let source_stem = source_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file_name = match name {
+            Some(name) => format!("{source_stem}__{}.snap", sanitize(name)),
+            None => {
+                let test_name = current_test_name(module_path);
+                let test_name =
+                    test_name.strip_prefix(&format!("{source_stem}::")).unwrap_or(&test_name);
+                let key = format!("{source_stem}__{}", sanitize(test_name));
+                let index = SNAPSHOT_COUNTS.with(|counts| {
+                    let mut counts = counts.borrow_mut();
+                    let count = counts.entry(key.clone()).or_default();
+                    *count += 1;
+                    *count
+                });
+                format!("{key}__{index}.snap")
+            }
+        };
+        let path = source_path.parent().unwrap_or(Path::new("")).join("snapshots").join(file_name);
+        Self::at(path, manifest_dir)
+    }
+
+    fn at(path: PathBuf, base_dir: &Path) -> Self {
+        let display_path = path.strip_prefix(base_dir).unwrap_or(&path).display().to_string();
+        Self { path, display_path, redactions: vec![], rendering: PhantomData }
+    }
+
+    /// Renders the actual value with [`Display`] rather than [`Debug`].
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// let greeting = "Hello,\nworld!";
+    /// verify_that!(greeting, matches_snapshot!("greeting").using_display())?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    pub fn using_display(self) -> SnapshotMatcher<DisplayRendering> {
+        SnapshotMatcher {
+            path: self.path,
+            display_path: self.display_path,
+            redactions: self.redactions,
+            rendering: PhantomData,
+        }
+    }
+}
+
+impl<RenderingT> SnapshotMatcher<RenderingT> {
+    /// Replaces every match of the regular expression `pattern` in the
+    /// rendered value by `replacement` before comparing it with the snapshot.
+    ///
+    /// The replacement may refer to capture groups as in
+    /// [`Regex::replace_all`][regex::Regex::replace_all].
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// let log = "2024-05-01 server started";
+    /// verify_that!(
+    ///     log,
+    ///     matches_snapshot!("log").using_display().redacting(r"\d{4}-\d{2}-\d{2}", "[date]")
+    /// )?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    ///
+    /// Panics if the given `pattern` is not a syntactically valid regular
+    /// expression.
+    pub fn redacting(self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).unwrap();
+        let replacement = replacement.to_string();
+        self.redacting_with(move |rendered| regex.replace_all(rendered, &replacement).into_owned())
+    }
+
+    /// Applies `redaction` to the rendered value before comparing it with the
+    /// snapshot.
+    ///
+    /// Redactions are applied in the order in which they are added.
+    pub fn redacting_with(mut self, redaction: impl Fn(&str) -> String + 'static) -> Self {
+        self.redactions.push(Box::new(redaction));
+        self
+    }
+
+    fn render<T>(&self, actual: &T) -> String
+    where
+        RenderingT: SnapshotRendering<T>,
+    {
+        self.redactions
+            .iter()
+            .fold(RenderingT::render(actual), |rendered, redaction| redaction(&rendered))
+    }
+
+    fn read_snapshot(&self) -> std::io::Result<String> {
+        let snapshot = std::fs::read_to_string(&self.path)?.replace("\r\n", "\n");
+        Ok(snapshot.strip_suffix('\n').map(str::to_string).unwrap_or(snapshot))
+    }
+
+    fn write_snapshot(&self, rendered: &str) {
+        if self.read_snapshot().ok().as_deref() == Some(rendered) {
+            return;
+        }
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).unwrap_or_else(|e| {
+                panic!("Could not create the snapshot directory {}: {e}", dir.display())
+            });
+        }
+        std::fs::write(&self.path, format!("{rendered}\n")).unwrap_or_else(|e| {
+            panic!("Could not write the snapshot {}: {e}", self.path.display())
+        });
+    }
+}
+
+impl<T: Debug + Clone, RenderingT: SnapshotRendering<T>> Matcher<T>
+    for SnapshotMatcher<RenderingT>
+{
+    fn matches(&self, actual: T) -> MatcherResult {
+        let rendered = self.render(&actual);
+        if UPDATE_SNAPSHOTS.with(Cell::get) && updates_allowed() {
+            self.write_snapshot(&rendered);
+            return MatcherResult::Match;
+        }
+        (self.read_snapshot().ok() == Some(rendered)).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => format!("matches the snapshot {}", self.display_path).into(),
+            MatcherResult::NoMatch => {
+                format!("doesn't match the snapshot {}", self.display_path).into()
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: T) -> Description {
+        let rendered = self.render(&actual);
+        let snapshot = match self.read_snapshot() {
+            Ok(snapshot) => snapshot,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return format!(
+                    "which has no snapshot {} yet; run with GOOGLETEST_UPDATE_SNAPSHOTS=1 to \
+                    create it{}",
+                    self.display_path,
+                    create_diff(&rendered, "", edit_distance::Mode::Exact)
+                )
+                .into();
+            }
+            Err(e) => {
+                return format!(
+                    "which could not be compared: reading {} failed: {e}",
+                    self.path.display()
+                )
+                .into();
+            }
+        };
+        if snapshot == rendered {
+            return format!("which matches the snapshot {}", self.display_path).into();
+        }
+        let diff = create_diff(&rendered, &snapshot, edit_distance::Mode::Exact);
+        let diff = if diff.is_empty() {
+            format!("\nRendered as {rendered:?}, but the snapshot is {snapshot:?}").into()
+        } else {
+            diff
+        };
+        format!(
+            "which doesn't match the snapshot {}; run with GOOGLETEST_UPDATE_SNAPSHOTS=1 to \
+            update it{diff}",
+            self.display_path,
+        )
+        .into()
+    }
+}
+
+thread_local! {
+    pub(crate) static UPDATE_SNAPSHOTS: Cell<bool> = Cell::new(update_requested());
+    // The number of snapshot assertions so far, for each source file and test.
+    static SNAPSHOT_COUNTS: RefCell<HashMap<String, usize>> = RefCell::new(HashMap::new());
+}
+
+fn update_requested() -> bool {
+    std::env::var("GOOGLETEST_UPDATE_SNAPSHOTS").map(|s| !s.is_empty() && s != "0").unwrap_or(false)
+}
+
+fn current_test_name(module_path: &str) -> String {
+    match std::thread::current().name() {
+        Some(name) if name != "main" => name.to_string(),
+        _ => module_path.to_string(),
+    }
+}
+
+// Makes a test name usable as part of a file name.
+fn sanitize(name: &str) -> String {
+    name.replace("::", "__")
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::temp_dir::TempDir;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    // Returns a fresh temporary directory and a matcher for a snapshot in it,
+    // which contains `snapshot` if given.
+    fn snapshot_matcher(test_name: &str, snapshot: Option<&str>) -> (TempDir, SnapshotMatcher) {
+        let dir = TempDir::new(&format!("snapshot_{test_name}"));
+        let path = dir.path().join("snapshots").join("value.snap");
+        if let Some(snapshot) = snapshot {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, snapshot).unwrap();
+        }
+        let matcher = SnapshotMatcher::at(path, dir.path());
+        (dir, matcher)
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone)]
+    struct Report {
+        title: &'static str,
+        lines: Vec<&'static str>,
+    }
+
+    fn report() -> Report {
+        Report { title: "Summary", lines: vec!["first", "second"] }
+    }
+
+    const REPORT_SNAPSHOT: &str = indoc! {r#"
+        Report {
+            title: "Summary",
+            lines: [
+                "first",
+                "second",
+            ],
+        }
+    "#};
+
+    #[test]
+    fn matches_stored_debug_rendering() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("debug", Some(REPORT_SNAPSHOT));
+
+        verify_that!(report(), matcher)
+    }
+
+    #[test]
+    fn matches_stored_display_rendering() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("display", Some("first line\r\nsecond line\r\n"));
+
+        verify_that!("first line\nsecond line", matcher.using_display())
+    }
+
+    #[test]
+    fn does_not_match_missing_snapshot() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("missing", None);
+
+        let result = verify_that!(report(), matcher);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "which has no snapshot snapshots/value.snap yet; run with \
+                GOOGLETEST_UPDATE_SNAPSHOTS=1 to create it"
+            )))
+        )
+    }
+
+    #[test]
+    fn explains_difference_from_snapshot_with_diff() -> Result<()> {
+        let snapshot = REPORT_SNAPSHOT.replace("second", "third");
+
+        let (_dir, matcher) = snapshot_matcher("differs", Some(&snapshot));
+
+        let result = verify_that!(report(), matcher);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {r#"
+                Actual: Report { title: "Summary", lines: ["first", "second"] },
+                  which doesn't match the snapshot snapshots/value.snap; run with GOOGLETEST_UPDATE_SNAPSHOTS=1 to update it
+                  Difference(-actual / +expected):
+                   Report {
+                       title: "Summary",
+                       lines: [
+                           "first",
+                  -        "second",
+                  ?         ^^^^^
+                  +        "third",
+                  ?         ^^^^
+                       ],
+                   }
+                "#})))
+        )
+    }
+
+    #[test]
+    fn explains_difference_from_single_line_snapshot() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("single_line", Some("124\n"));
+
+        let result = verify_that!(123, matcher);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Rendered as \"123\", but the snapshot is \"124\""
+            )))
+        )
+    }
+
+    #[test]
+    fn applies_redactions_in_order() -> Result<()> {
+        let (_dir, matcher) =
+            snapshot_matcher("redactions", Some("Run at [date] [time] by [user]\n"));
+        let matcher = matcher
+            .redacting(r"\d{4}-\d{2}-\d{2}", "[date]")
+            .redacting(r"(\d{2}):\d{2}", "[time]")
+            .redacting_with(|rendered| rendered.replace("alice", "[user]"))
+            .using_display();
+
+        verify_that!("Run at 2024-03-01 12:30 by alice", matcher)
+    }
+
+    #[test]
+    fn update_mode_writes_snapshot() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("update", Some("outdated\n"));
+        UPDATE_SNAPSHOTS.with(|update| update.set(true));
+
+        let result = verify_that!(report(), &matcher);
+
+        UPDATE_SNAPSHOTS.with(|update| update.set(false));
+        verify_that!(result, ok(anything()))?;
+        verify_that!(std::fs::read_to_string(&matcher.path)?, eq(REPORT_SNAPSHOT))?;
+        verify_that!(report(), matcher)
+    }
+
+    #[test]
+    fn update_mode_creates_missing_snapshot() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("create", None);
+        UPDATE_SNAPSHOTS.with(|update| update.set(true));
+
+        let result = verify_that!(report(), &matcher);
+
+        UPDATE_SNAPSHOTS.with(|update| update.set(false));
+        verify_that!(result, ok(anything()))?;
+        verify_that!(report(), matcher)
+    }
+
+    #[test]
+    fn update_mode_does_not_write_negated_snapshot() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("negated", Some("outdated\n"));
+        UPDATE_SNAPSHOTS.with(|update| update.set(true));
+
+        let result = verify_that!(report(), not(&matcher));
+
+        UPDATE_SNAPSHOTS.with(|update| update.set(false));
+        verify_that!(result, ok(anything()))?;
+        verify_that!(std::fs::read_to_string(&matcher.path)?, eq("outdated\n"))
+    }
+
+    #[test]
+    fn update_mode_does_not_write_snapshot_outside_assertion() -> Result<()> {
+        let (_dir, matcher) = snapshot_matcher("outside_assertion", None);
+        UPDATE_SNAPSHOTS.with(|update| update.set(true));
+
+        let result = matcher.matches(report());
+
+        UPDATE_SNAPSHOTS.with(|update| update.set(false));
+        verify_that!(result, eq(MatcherResult::NoMatch))?;
+        verify_that!(matcher.path.exists(), eq(false))
+    }
+
+    #[test]
+    fn names_snapshot_after_source_file_test_and_index() -> Result<()> {
+        let first = SnapshotMatcher::new("/crate", "tests/report_test.rs", "report_test", None);
+        let second = SnapshotMatcher::new("/crate", "tests/report_test.rs", "report_test", None);
+        let named =
+            SnapshotMatcher::new("/crate", "tests/report_test.rs", "report_test", Some("a b"));
+
+        verify_that!(
+            (first.display_path, second.display_path, named.display_path),
+            (
+                eq("tests/snapshots/report_test__matchers__snapshot_matcher__tests__names_snapshot_after_source_file_test_and_index__1.snap"),
+                ends_with("_index__2.snap"),
+                eq("tests/snapshots/report_test__a_b.snap")
+            )
+        )
+    }
+}
@@ -47,13 +47,15 @@ pub struct SomeMatcher<InnerMatcherT> {
     inner: InnerMatcherT,
 }
 
-impl<T: Debug + Copy, InnerMatcherT: Matcher<T>> Matcher<Option<T>> for SomeMatcher<InnerMatcherT> {
+impl<T: Debug + Clone, InnerMatcherT: Matcher<T>> Matcher<Option<T>>
+    for SomeMatcher<InnerMatcherT>
+{
     fn matches(&self, actual: Option<T>) -> MatcherResult {
         actual.map(|v| self.inner.matches(v)).unwrap_or(MatcherResult::NoMatch)
     }
 
     fn explain_match(&self, actual: Option<T>) -> Description {
-        match (self.matches(actual), actual) {
+        match (self.matches(actual.clone()), actual) {
             (_, Some(t)) => {
                 Description::new().text("which has a value").nested(self.inner.explain_match(t))
             }
@@ -61,6 +63,19 @@ impl<T: Debug + Copy, InnerMatcherT: Matcher<T>> Matcher<Option<T>> for SomeMatc
         }
     }
 
+    fn match_and_explain(
+        &self,
+        actual: Option<T>,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        match actual {
+            Some(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+                then(result, &|| Description::new().text("which has a value").nested(explanation()))
+            }),
+            None => then(MatcherResult::NoMatch, &|| "which is None".into()),
+        }
+    }
+
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         match matcher_result {
             MatcherResult::Match => {
@@ -91,6 +106,19 @@ impl<'a, T: Debug, InnerMatcherT: Matcher<&'a T>> Matcher<&'a Option<T>>
         }
     }
 
+    fn match_and_explain(
+        &self,
+        actual: &'a Option<T>,
+        then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+    ) {
+        match actual {
+            Some(value) => self.inner.match_and_explain(value, &mut |result, explanation| {
+                then(result, &|| Description::new().text("which has a value").nested(explanation()))
+            }),
+            None => then(MatcherResult::NoMatch, &|| "which is None".into()),
+        }
+    }
+
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         match matcher_result {
             MatcherResult::Match => {
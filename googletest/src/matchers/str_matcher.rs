@@ -17,9 +17,9 @@ use crate::{
     matcher::{Matcher, MatcherBase, MatcherResult},
     matcher_support::{
         edit_distance,
-        summarize_diff::{create_diff, create_diff_reversed},
+        summarize_diff::{create_diff, create_diff_reversed, create_inline_diff},
     },
-    matchers::eq_matcher::EqMatcher,
+    matchers::{eq_matcher::EqMatcher, golden_file_matcher::GoldenFile},
 };
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -57,7 +57,8 @@ use std::ops::Deref;
 /// > when matching strings. However, it must allocate copies of both the actual
 /// > and expected values when matching strings while
 /// > [`ignoring_ascii_case`][StrMatcherConfigurator::ignoring_ascii_case] is
-/// > set.
+/// > set. The options which normalize case, whitespace or line endings may
+/// > also allocate normalized copies of both values.
 pub fn contains_substring<T>(expected: T) -> StrMatcher<T> {
     StrMatcher {
         configuration: Configuration { mode: MatchMode::Contains, ..Default::default() },
@@ -235,6 +236,82 @@ pub trait StrMatcherConfigurator<ExpectedT> {
     /// case characters outside of the codepoints 0-127 covered by ASCII.
     fn ignoring_ascii_case(self) -> StrMatcher<ExpectedT>;
 
+    /// Configures the matcher to ignore case when comparing values, including
+    /// characters outside of ASCII.
+    ///
+    /// Both values are case folded by converting them to upper case and then
+    /// to lower case with [`str::to_uppercase`] and [`str::to_lowercase`].
+    /// This folds characters whose upper case has several characters, so that
+    /// for example "ß" matches "SS".
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!("Grüße", eq("GRÜSSE").ignoring_unicode_case())?;  // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!("Grüße", eq("GRÜSSE").ignoring_ascii_case())?;   // Fails
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    fn ignoring_unicode_case(self) -> StrMatcher<ExpectedT>;
+
+    /// Configures the matcher to consider any run of whitespace within a line
+    /// equal to a single space.
+    ///
+    /// Line breaks are preserved, so that the values must have the same number
+    /// of lines. Whitespace is defined as in [`char::is_whitespace`].
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!("A  \tstring", eq("A string").ignoring_whitespace_differences())?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!("Astring", eq("A string").ignoring_whitespace_differences())?; // Fails
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    fn ignoring_whitespace_differences(self) -> StrMatcher<ExpectedT>;
+
+    /// Configures the matcher to consider Windows line endings (`"\r\n"`)
+    /// equal to Unix line endings (`"\n"`).
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!("Line 1\r\nLine 2", eq("Line 1\nLine 2").ignoring_line_endings())?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    fn ignoring_line_endings(self) -> StrMatcher<ExpectedT>;
+
+    /// Configures the matcher to ignore all whitespace, including line breaks,
+    /// in either the actual or the expected value.
+    ///
+    /// Whitespace is defined as in [`char::is_whitespace`].
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!("{ \"a\": 1 }", eq("{\"a\":1}").ignoring_all_whitespace())?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    ///
+    /// This replaces
+    /// [`ignoring_whitespace_differences`][StrMatcherConfigurator::ignoring_whitespace_differences]
+    /// if both are used.
+    fn ignoring_all_whitespace(self) -> StrMatcher<ExpectedT>;
+
     /// Configures the matcher to match only strings which otherwise satisfy the
     /// conditions a number times matched by the matcher `times`.
     ///
@@ -289,10 +366,22 @@ pub struct StrMatcher<ExpectedT> {
 impl<ExpectedT, ActualT> Matcher<ActualT> for StrMatcher<ExpectedT>
 where
     ExpectedT: Deref<Target = str> + Debug,
-    ActualT: AsRef<str> + Debug + Copy,
+    ActualT: AsRef<str> + Debug + Clone,
 {
     fn matches(&self, actual: ActualT) -> MatcherResult {
-        self.configuration.do_strings_match(self.expected.deref(), actual.as_ref()).into()
+        let Some(golden_file) = self.configuration.golden_file.as_ref() else {
+            return self
+                .configuration
+                .do_strings_match(self.expected.deref(), actual.as_ref())
+                .into();
+        };
+        // A file which matches with the configured options is left as is.
+        if golden_file.read_error.is_none()
+            && self.configuration.do_strings_match(self.expected.deref(), actual.as_ref())
+        {
+            return MatcherResult::Match;
+        }
+        golden_file.update(actual.as_ref().as_bytes()).into()
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> Description {
@@ -333,6 +422,29 @@ impl<ExpectedT, MatcherT: Into<StrMatcher<ExpectedT>>> StrMatcherConfigurator<Ex
         StrMatcher { configuration: existing.configuration.ignoring_ascii_case(), ..existing }
     }
 
+    fn ignoring_unicode_case(self) -> StrMatcher<ExpectedT> {
+        let existing = self.into();
+        StrMatcher { configuration: existing.configuration.ignoring_unicode_case(), ..existing }
+    }
+
+    fn ignoring_whitespace_differences(self) -> StrMatcher<ExpectedT> {
+        let existing = self.into();
+        StrMatcher {
+            configuration: existing.configuration.ignoring_whitespace_differences(),
+            ..existing
+        }
+    }
+
+    fn ignoring_line_endings(self) -> StrMatcher<ExpectedT> {
+        let existing = self.into();
+        StrMatcher { configuration: existing.configuration.ignoring_line_endings(), ..existing }
+    }
+
+    fn ignoring_all_whitespace(self) -> StrMatcher<ExpectedT> {
+        let existing = self.into();
+        StrMatcher { configuration: existing.configuration.ignoring_all_whitespace(), ..existing }
+    }
+
     fn times(self, times: impl Matcher<usize> + 'static) -> StrMatcher<ExpectedT> {
         let existing = self.into();
         if !matches!(existing.configuration.mode, MatchMode::Contains) {
@@ -356,6 +468,15 @@ impl<T> StrMatcher<T> {
     fn with_default_config(expected: T) -> Self {
         Self { expected, configuration: Default::default() }
     }
+
+    /// Returns a [`StrMatcher`] matching strings equal to `contents`, which
+    /// were read from `golden_file`.
+    pub(crate) fn with_golden_file(expected: T, golden_file: GoldenFile) -> Self {
+        Self {
+            expected,
+            configuration: Configuration { golden_file: Some(golden_file), ..Default::default() },
+        }
+    }
 }
 
 // Holds all the information on how the expected and actual strings are to be
@@ -368,8 +489,12 @@ struct Configuration {
     mode: MatchMode,
     ignore_leading_whitespace: bool,
     ignore_trailing_whitespace: bool,
+    ignore_line_endings: bool,
+    whitespace_policy: WhitespacePolicy,
     case_policy: CasePolicy,
     times: Option<Box<dyn Matcher<usize>>>,
+    // The file from which the expected value was read, if it was.
+    golden_file: Option<GoldenFile>,
 }
 
 #[derive(Clone)]
@@ -394,40 +519,46 @@ impl MatchMode {
 enum CasePolicy {
     Respect,
     IgnoreAscii,
+    IgnoreUnicode,
+}
+
+#[derive(Clone)]
+enum WhitespacePolicy {
+    Respect,
+    // Replaces every run of whitespace within a line with a single space.
+    Collapse,
+    Ignore,
 }
 
 impl Configuration {
     // The entry point for all string matching. StrMatcher::matches redirects
     // immediately to this function.
     fn do_strings_match(&self, expected: &str, actual: &str) -> bool {
-        let (expected, actual) =
-            match (self.ignore_leading_whitespace, self.ignore_trailing_whitespace) {
-                (true, true) => (expected.trim(), actual.trim()),
-                (true, false) => (expected.trim_start(), actual.trim_start()),
-                (false, true) => (expected.trim_end(), actual.trim_end()),
-                (false, false) => (expected, actual),
-            };
+        let (expected, actual) = (self.normalize(expected), self.normalize(actual));
+        let (expected, actual) = (expected.as_ref(), actual.as_ref());
         match self.mode {
             MatchMode::Equals => match self.case_policy {
-                CasePolicy::Respect => expected == actual,
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => expected == actual,
                 CasePolicy::IgnoreAscii => expected.eq_ignore_ascii_case(actual),
             },
             MatchMode::Contains => match self.case_policy {
-                CasePolicy::Respect => self.does_containment_match(actual, expected),
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => {
+                    self.does_containment_match(actual, expected)
+                }
                 CasePolicy::IgnoreAscii => self.does_containment_match(
                     actual.to_ascii_lowercase().as_str(),
                     expected.to_ascii_lowercase().as_str(),
                 ),
             },
             MatchMode::StartsWith => match self.case_policy {
-                CasePolicy::Respect => actual.starts_with(expected),
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => actual.starts_with(expected),
                 CasePolicy::IgnoreAscii => {
                     actual.len() >= expected.len()
                         && actual[..expected.len()].eq_ignore_ascii_case(expected)
                 }
             },
             MatchMode::EndsWith => match self.case_policy {
-                CasePolicy::Respect => actual.ends_with(expected),
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => actual.ends_with(expected),
                 CasePolicy::IgnoreAscii => {
                     actual.len() >= expected.len()
                         && actual[actual.len() - expected.len()..].eq_ignore_ascii_case(expected)
@@ -436,6 +567,50 @@ impl Configuration {
         }
     }
 
+    // Applies the options which transform each value independently before the
+    // comparison: trimming, line endings, whitespace, and Unicode case folding.
+    // ASCII case is ignored by the comparison itself. Explanations render the
+    // diff between the normalized values.
+    fn normalize<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        let mut value = Cow::Borrowed(
+            match (self.ignore_leading_whitespace, self.ignore_trailing_whitespace) {
+                (true, true) => value.trim(),
+                (true, false) => value.trim_start(),
+                (false, true) => value.trim_end(),
+                (false, false) => value,
+            },
+        );
+        if self.ignore_line_endings && value.contains("\r\n") {
+            value = value.replace("\r\n", "\n").into();
+        }
+        match self.whitespace_policy {
+            WhitespacePolicy::Respect => {}
+            WhitespacePolicy::Collapse => {
+                let mut collapsed = String::with_capacity(value.len());
+                let mut in_run = false;
+                for c in value.chars() {
+                    if c.is_whitespace() && c != '\n' {
+                        if !in_run {
+                            collapsed.push(' ');
+                        }
+                        in_run = true;
+                    } else {
+                        collapsed.push(c);
+                        in_run = false;
+                    }
+                }
+                value = collapsed.into();
+            }
+            WhitespacePolicy::Ignore => {
+                value = value.chars().filter(|c| !c.is_whitespace()).collect::<String>().into();
+            }
+        }
+        if matches!(self.case_policy, CasePolicy::IgnoreUnicode) {
+            value = value.to_uppercase().to_lowercase().into();
+        }
+        value
+    }
+
     // Returns whether actual contains expected a number of times matched by the
     // matcher self.times. Does not take other configuration into account.
     fn does_containment_match(&self, actual: &str, expected: &str) -> bool {
@@ -451,16 +626,25 @@ impl Configuration {
 
     // StrMatcher::<str>::describe redirects immediately to this function.
     fn describe(&self, matcher_result: MatcherResult, expected: &str) -> Description {
-        let mut addenda: Vec<Cow<'static, str>> = Vec::with_capacity(3);
+        let mut addenda: Vec<Cow<'static, str>> = Vec::with_capacity(5);
         match (self.ignore_leading_whitespace, self.ignore_trailing_whitespace) {
             (true, true) => addenda.push("ignoring leading and trailing whitespace".into()),
             (true, false) => addenda.push("ignoring leading whitespace".into()),
             (false, true) => addenda.push("ignoring trailing whitespace".into()),
             (false, false) => {}
         }
+        if self.ignore_line_endings {
+            addenda.push("ignoring line endings".into());
+        }
+        match self.whitespace_policy {
+            WhitespacePolicy::Respect => {}
+            WhitespacePolicy::Collapse => addenda.push("ignoring whitespace differences".into()),
+            WhitespacePolicy::Ignore => addenda.push("ignoring all whitespace".into()),
+        }
         match self.case_policy {
             CasePolicy::Respect => {}
             CasePolicy::IgnoreAscii => addenda.push("ignoring ASCII case".into()),
+            CasePolicy::IgnoreUnicode => addenda.push("ignoring Unicode case".into()),
         }
         if let Some(times) = self.times.as_ref() {
             addenda.push(format!("count {}", times.describe(matcher_result)).into());
@@ -485,26 +669,40 @@ impl Configuration {
                 MatcherResult::NoMatch => "does not end with",
             },
         };
-        format!("{match_mode_description} {expected:?}{extra}").into()
+        match self.golden_file.as_ref() {
+            Some(golden_file) => format!(
+                "{match_mode_description} the contents of {}{extra}",
+                golden_file.display_path
+            )
+            .into(),
+            None => format!("{match_mode_description} {expected:?}{extra}").into(),
+        }
     }
 
     fn explain_match(&self, expected: &str, actual: &str) -> Description {
-        let default_explanation = format!(
-            "which {}",
-            self.describe(self.do_strings_match(expected, actual).into(), expected)
-        )
-        .into();
-        if !expected.contains('\n') || !actual.contains('\n') {
-            return default_explanation;
+        if let Some(explanation) =
+            self.golden_file.as_ref().and_then(GoldenFile::explain_read_error)
+        {
+            return explanation.into();
         }
-
-        if self.ignore_leading_whitespace {
-            // TODO - b/283448414 : Support StrMatcher with ignore_leading_whitespace.
-            return default_explanation;
+        let strings_match = self.do_strings_match(expected, actual);
+        let default_explanation =
+            format!("which {}", self.describe(strings_match.into(), expected)).into();
+        // The diff is rendered on the values as they are compared.
+        let (expected, actual) = (self.normalize(expected), self.normalize(actual));
+        let (expected, actual) = (expected.as_ref(), actual.as_ref());
+        if !expected.contains('\n') && !actual.contains('\n') {
+            return match (&self.mode, &self.case_policy) {
+                (MatchMode::Equals, CasePolicy::Respect | CasePolicy::IgnoreUnicode)
+                    if !strings_match =>
+                {
+                    let diff = create_inline_diff(&format!("{actual:?}"), &format!("{expected:?}"));
+                    format!("{default_explanation}{diff}").into()
+                }
+                _ => default_explanation,
+            };
         }
-
-        if self.ignore_trailing_whitespace {
-            // TODO - b/283448414 : Support StrMatcher with ignore_trailing_whitespace.
+        if !expected.contains('\n') || !actual.contains('\n') {
             return default_explanation;
         }
 
@@ -516,7 +714,7 @@ impl Configuration {
             // TODO - b/283448414 : Support StrMatcher with ignore ascii case policy.
             return default_explanation;
         }
-        if self.do_strings_match(expected, actual) {
+        if strings_match {
             // TODO - b/283448414 : Consider supporting debug difference if the
             // strings match. This can be useful when a small contains is found
             // in a long string.
@@ -552,6 +750,22 @@ impl Configuration {
         Self { case_policy: CasePolicy::IgnoreAscii, ..self }
     }
 
+    fn ignoring_unicode_case(self) -> Self {
+        Self { case_policy: CasePolicy::IgnoreUnicode, ..self }
+    }
+
+    fn ignoring_whitespace_differences(self) -> Self {
+        Self { whitespace_policy: WhitespacePolicy::Collapse, ..self }
+    }
+
+    fn ignoring_line_endings(self) -> Self {
+        Self { ignore_line_endings: true, ..self }
+    }
+
+    fn ignoring_all_whitespace(self) -> Self {
+        Self { whitespace_policy: WhitespacePolicy::Ignore, ..self }
+    }
+
     fn times(self, times: impl Matcher<usize> + 'static) -> Self {
         Self { times: Some(Box::new(times)), ..self }
     }
@@ -563,8 +777,11 @@ impl Default for Configuration {
             mode: MatchMode::Equals,
             ignore_leading_whitespace: false,
             ignore_trailing_whitespace: false,
+            ignore_line_endings: false,
+            whitespace_policy: WhitespacePolicy::Respect,
             case_policy: CasePolicy::Respect,
             times: None,
+            golden_file: None,
         }
     }
 }
@@ -946,6 +1163,7 @@ mod tests {
    First line
   -Second line
   +Second lines
+  ?           ^
    Third line"
             )))
         )
@@ -978,6 +1196,7 @@ mod tests {
    First line
   -Second line
   +Second lines
+  ?           ^
    Third line
    <---- remaining lines omitted ---->"
             )))
@@ -1010,6 +1229,7 @@ mod tests {
    First line
   -Second line
   +Second lines
+  ?           ^
    <---- remaining lines omitted ---->"
             )))
         )
@@ -1044,6 +1264,7 @@ mod tests {
    Second line
   -Third line
   +Third lines
+  ?          ^
    Fourth line"
             )))
         )
@@ -1080,6 +1301,7 @@ mod tests {
    Second line
   -Third line
   +Third lines
+  ?          ^
    Fourth line
    <---- remaining lines omitted ---->"
             )))
@@ -1118,8 +1340,11 @@ mod tests {
   +line
    Third line
   -Fourth line
+  ?  ^
   +Foorth line
+  ?  ^
   -Fifth line
+  ?     ^^^^^
   +Fifth
    <---- remaining lines omitted ---->"
             )))
@@ -1153,6 +1378,7 @@ mod tests {
    First line
   -Second line
   +Second lines
+  ?           ^
    Third line
   -Fourth line"
             )))
@@ -1195,4 +1421,206 @@ mod tests {
             err(displays_as(not(contains_substring("Difference(-actual / +expected):"))))
         )
     }
+
+    #[test]
+    fn matches_string_ignoring_unicode_case() -> Result<()> {
+        verify_that!("Grüße", eq("GRÜSSE").ignoring_unicode_case())
+    }
+
+    #[test]
+    fn does_not_match_different_string_ignoring_unicode_case() -> Result<()> {
+        verify_that!("Grüße", not(eq("GRÜNE").ignoring_unicode_case()))
+    }
+
+    #[test]
+    fn ascii_case_policy_does_not_fold_non_ascii_characters() -> Result<()> {
+        verify_that!("ÉCOLE", not(eq("école").ignoring_ascii_case()))
+    }
+
+    #[test]
+    fn unicode_case_applies_to_starts_with_ends_with_and_contains() -> Result<()> {
+        verify_that!("Ärger über Öl", starts_with("ärger").ignoring_unicode_case())?;
+        verify_that!("Ärger über Öl", ends_with("ÖL").ignoring_unicode_case())?;
+        verify_that!("Ärger über Öl", contains_substring("ÜBER").ignoring_unicode_case())
+    }
+
+    #[test]
+    fn matches_string_ignoring_whitespace_differences() -> Result<()> {
+        verify_that!("A  \t string", eq("A string").ignoring_whitespace_differences())
+    }
+
+    #[test]
+    fn ignoring_whitespace_differences_does_not_ignore_missing_whitespace() -> Result<()> {
+        verify_that!("Astring", not(eq("A string").ignoring_whitespace_differences()))
+    }
+
+    #[test]
+    fn ignoring_whitespace_differences_preserves_line_breaks() -> Result<()> {
+        verify_that!("A\nstring", not(eq("A string").ignoring_whitespace_differences()))
+    }
+
+    #[test]
+    fn whitespace_differences_apply_to_starts_with_ends_with_and_contains() -> Result<()> {
+        verify_that!(
+            "Some   long\tvalue",
+            starts_with("Some long").ignoring_whitespace_differences()
+        )?;
+        verify_that!(
+            "Some   long\tvalue",
+            ends_with("long value").ignoring_whitespace_differences()
+        )?;
+        verify_that!(
+            "Some   long\tvalue",
+            contains_substring("e  long  v").ignoring_whitespace_differences()
+        )
+    }
+
+    #[test]
+    fn matches_string_ignoring_line_endings() -> Result<()> {
+        verify_that!("Line 1\r\nLine 2\r\n", eq("Line 1\nLine 2\n").ignoring_line_endings())
+    }
+
+    #[test]
+    fn does_not_match_different_line_endings_by_default() -> Result<()> {
+        verify_that!("Line 1\r\nLine 2", not(eq("Line 1\nLine 2")))
+    }
+
+    #[test]
+    fn line_endings_apply_to_starts_with_ends_with_and_contains() -> Result<()> {
+        verify_that!("A\r\nB\r\nC", starts_with("A\nB").ignoring_line_endings())?;
+        verify_that!("A\r\nB\r\nC", ends_with("B\nC").ignoring_line_endings())?;
+        verify_that!("A\r\nB\r\nC", contains_substring("\nB\n").ignoring_line_endings())
+    }
+
+    #[test]
+    fn matches_string_ignoring_all_whitespace() -> Result<()> {
+        verify_that!("{ \"a\": 1,\n  \"b\": 2 }", eq("{\"a\":1,\"b\":2}").ignoring_all_whitespace())
+    }
+
+    #[test]
+    fn all_whitespace_applies_to_starts_with_ends_with_and_contains() -> Result<()> {
+        verify_that!("a b\tc\nd", starts_with("abc").ignoring_all_whitespace())?;
+        verify_that!("a b\tc\nd", ends_with("c d").ignoring_all_whitespace())?;
+        verify_that!("a b\tc\nd", contains_substring("bcd").ignoring_all_whitespace())
+    }
+
+    #[test]
+    fn normalization_options_combine() -> Result<()> {
+        verify_that!(
+            "  STRASSE\r\n  Nr.   5 ",
+            eq("straße\r\n nr. 5")
+                .ignoring_unicode_case()
+                .ignoring_line_endings()
+                .ignoring_whitespace_differences()
+                .ignoring_outer_whitespace()
+        )
+    }
+
+    #[test]
+    fn describes_itself_ignoring_unicode_case() -> Result<()> {
+        let matcher = StrMatcher::with_default_config("A string").ignoring_unicode_case();
+        verify_that!(
+            Matcher::<&str>::describe(&matcher, MatcherResult::Match),
+            displays_as(eq("is equal to \"A string\" (ignoring Unicode case)"))
+        )
+    }
+
+    #[test]
+    fn describes_itself_ignoring_whitespace_differences_and_line_endings() -> Result<()> {
+        let matcher = StrMatcher::with_default_config("A string")
+            .ignoring_whitespace_differences()
+            .ignoring_line_endings();
+        verify_that!(
+            Matcher::<&str>::describe(&matcher, MatcherResult::Match),
+            displays_as(eq(
+                "is equal to \"A string\" (ignoring line endings, ignoring whitespace differences)"
+            ))
+        )
+    }
+
+    #[test]
+    fn describes_itself_ignoring_all_whitespace() -> Result<()> {
+        let matcher = StrMatcher::with_default_config("A string").ignoring_all_whitespace();
+        verify_that!(
+            Matcher::<&str>::describe(&matcher, MatcherResult::NoMatch),
+            displays_as(eq("isn't equal to \"A string\" (ignoring all whitespace)"))
+        )
+    }
+
+    #[test]
+    fn match_explanation_shows_diff_of_normalized_values() -> Result<()> {
+        let result = verify_that!(
+            "First  line\r\nSECOND line\r\nThird\tline\r\n",
+            eq("first line\nsecond line\nfourth line\n")
+                .ignoring_unicode_case()
+                .ignoring_line_endings()
+                .ignoring_whitespace_differences()
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "\
+   first line
+   second line
+  -third line
+  ?  ^^^
+  +fourth line
+  ?^^^^"
+            )))
+        )
+    }
+
+    #[test]
+    fn match_explanation_shows_diff_ignoring_outer_whitespace() -> Result<()> {
+        let result = verify_that!(
+            "\n  First line\nSecond line\n\n",
+            eq("First line\nThird line").ignoring_outer_whitespace()
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "\
+   First line
+  -Second line
+  ?^^^^^
+  +Third line
+  ?^^^^"
+            )))
+        )
+    }
+
+    #[test]
+    fn match_explanation_contains_inline_diff_of_single_line_strings() -> Result<()> {
+        let result = verify_that!(
+            "Some   long value with a typo",
+            eq("Some long value with a tpyo").ignoring_whitespace_differences()
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "
+  Difference(-actual / +expected):
+  -... long value with a typo\"
+  +... long value with a tpyo\"
+                          ^^
+"
+            )))
+        )
+    }
+
+    #[test]
+    fn match_explanation_does_not_show_inline_diff_ignoring_ascii_case() -> Result<()> {
+        let result = verify_that!(
+            "Some long value with a typo",
+            eq("some long value with a tpyo").ignoring_ascii_case()
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(not(contains_substring("Difference(-actual / +expected):"))))
+        )
+    }
 }
@@ -0,0 +1,243 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the matchers
+// are generated by `#[derive(Matchable)]`, which is documented in the
+// `matcher` module.
+#![doc(hidden)]
+
+/// Functions for use only by the code generated by `#[derive(Matchable)]`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::{
+        description::Description,
+        matcher::{explain_with_match_and_explain, Matcher, MatcherBase, MatcherResult},
+        matchers::BoxedMatcher,
+    };
+    use std::fmt::Debug;
+
+    /// Matches a struct, or a single variant of an enum, whose fields match
+    /// the matchers added with [`StructMatcher::field`].
+    ///
+    /// This is the implementation shared by all matchers generated by
+    /// `#[derive(Matchable)]`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    #[derive(MatcherBase)]
+    pub struct StructMatcher<'a, T: Debug> {
+        type_name: &'static str,
+        is_variant: fn(&T) -> bool,
+        variant_name: fn(&T) -> &'static str,
+        fields: Vec<BoxedMatcher<'a, &'a T>>,
+    }
+
+    impl<'a, T: Debug> StructMatcher<'a, T> {
+        /// Creates a matcher for the struct or enum variant `type_name`.
+        ///
+        /// `is_variant` returns whether the actual value has the expected enum
+        /// variant. It always returns `true` for structs. `variant_name`
+        /// returns the name of the variant of the actual value, which is shown
+        /// when it is not the expected one.
+        pub fn new(
+            type_name: &'static str,
+            is_variant: fn(&T) -> bool,
+            variant_name: fn(&T) -> &'static str,
+        ) -> Self {
+            Self { type_name, is_variant, variant_name, fields: vec![] }
+        }
+
+        /// Adds the matcher `field` for one of the fields of the struct.
+        pub fn field(mut self, field: impl Matcher<&'a T> + 'a) -> Self {
+            self.fields.push(field.boxed());
+            self
+        }
+    }
+
+    impl<'a, T: Debug> Matcher<&'a T> for StructMatcher<'a, T> {
+        fn matches(&self, actual: &'a T) -> MatcherResult {
+            ((self.is_variant)(actual) && self.fields.iter().all(|f| f.matches(actual).is_match()))
+                .into()
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> Description {
+            let header = match matcher_result {
+                MatcherResult::Match => format!("is {}", self.type_name),
+                MatcherResult::NoMatch => format!("is not {}", self.type_name),
+            };
+            match self.fields.as_slice() {
+                [] => header.into(),
+                [field] => {
+                    format!("{header} which {}", field.describe(MatcherResult::Match)).into()
+                }
+                fields => Description::new()
+                    .text(format!("{header} which has all the following properties:"))
+                    .nested(
+                        fields
+                            .iter()
+                            .map(|field| field.describe(MatcherResult::Match))
+                            .collect::<Description>()
+                            .bullet_list(),
+                    ),
+            }
+        }
+
+        fn explain_match(&self, actual: &'a T) -> Description {
+            explain_with_match_and_explain(self, actual)
+        }
+
+        fn match_and_explain(
+            &self,
+            actual: &'a T,
+            then: &mut dyn FnMut(MatcherResult, &dyn Fn() -> Description),
+        ) {
+            if !(self.is_variant)(actual) {
+                return then(MatcherResult::NoMatch, &|| {
+                    format!("which has the wrong enum variant `{}`", (self.variant_name)(actual))
+                        .into()
+                });
+            }
+            // Only the explanations of the mismatching fields are shown, so they
+            // are rendered as soon as the field is evaluated.
+            let mut mismatches = vec![];
+            for field in &self.fields {
+                field.match_and_explain(actual, &mut |result, explanation| {
+                    if result.is_no_match() {
+                        mismatches.push(explanation());
+                    }
+                });
+            }
+            then(mismatches.is_empty().into(), &|| match mismatches.len() {
+                0 => format!("which {}", self.describe(MatcherResult::Match)).into(),
+                1 => mismatches[0].clone(),
+                _ => mismatches.iter().cloned().collect::<Description>().bullet_list(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::internal::StructMatcher;
+    use crate::matcher::MatcherResult;
+    use crate::matchers::__internal_unstable_do_not_depend_on_these::field_matcher;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[derive(Debug)]
+    struct AStruct {
+        a_field: u32,
+        another_field: &'static str,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    enum AnEnum {
+        A(u32),
+        B,
+    }
+
+    fn variant_name(value: &AnEnum) -> &'static str {
+        match value {
+            AnEnum::A(_) => "A",
+            AnEnum::B => "B",
+        }
+    }
+
+    fn a_struct_matcher<'a>() -> StructMatcher<'a, AStruct> {
+        StructMatcher::new("AStruct", |_| true, |_| "AStruct")
+    }
+
+    fn a_field<'a>(inner: impl Matcher<&'a u32> + 'a) -> impl Matcher<&'a AStruct> + 'a {
+        field_matcher(|o: &AStruct| Some(&o.a_field), "a_field", inner)
+    }
+
+    fn another_field<'a>(
+        inner: impl Matcher<&'a &'static str> + 'a,
+    ) -> impl Matcher<&'a AStruct> + 'a {
+        field_matcher(|o: &AStruct| Some(&o.another_field), "another_field", inner)
+    }
+
+    #[test]
+    fn matches_struct_without_field_matchers() -> Result<()> {
+        verify_that!(AStruct { a_field: 1, another_field: "A" }, a_struct_matcher())
+    }
+
+    #[test]
+    fn matches_struct_when_all_fields_match() -> Result<()> {
+        verify_that!(
+            AStruct { a_field: 1, another_field: "A" },
+            a_struct_matcher().field(a_field(eq(&1))).field(another_field(eq(&"A")))
+        )
+    }
+
+    #[test]
+    fn does_not_match_struct_when_one_field_does_not_match() -> Result<()> {
+        verify_that!(
+            AStruct { a_field: 1, another_field: "A" },
+            not(a_struct_matcher().field(a_field(eq(&1))).field(another_field(eq(&"B"))))
+        )
+    }
+
+    #[test]
+    fn does_not_match_wrong_enum_variant() -> Result<()> {
+        let matcher = StructMatcher::new("AnEnum::B", |v| matches!(v, AnEnum::B), variant_name);
+
+        verify_that!(matcher.matches(&AnEnum::A(1)), eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn describes_all_field_matchers() -> Result<()> {
+        let matcher = a_struct_matcher().field(a_field(eq(&1))).field(another_field(eq(&"A")));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Match),
+            displays_as(eq(indoc!(
+                r#"
+                is AStruct which has all the following properties:
+                  * has field `a_field`, which is equal to 1
+                  * has field `another_field`, which is equal to "A""#
+            )))
+        )
+    }
+
+    #[test]
+    fn explains_only_mismatching_fields() -> Result<()> {
+        let matcher = a_struct_matcher()
+            .field(a_field(eq(&2)))
+            .field(another_field(eq(&"A")))
+            .field(a_field(gt(&5)));
+
+        verify_that!(
+            matcher.explain_match(&AStruct { a_field: 1, another_field: "A" }),
+            displays_as(eq(indoc!(
+                "
+                * which has field `a_field`, which isn't equal to 2
+                * which has field `a_field`, which is less than or equal to 5"
+            )))
+        )
+    }
+
+    #[test]
+    fn explains_wrong_enum_variant() -> Result<()> {
+        let matcher = StructMatcher::new("AnEnum::B", |v| matches!(v, AnEnum::B), variant_name);
+
+        verify_that!(
+            matcher.explain_match(&AnEnum::A(1)),
+            displays_as(eq("which has the wrong enum variant `A`"))
+        )
+    }
+}
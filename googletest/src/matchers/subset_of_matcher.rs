@@ -80,7 +80,7 @@ pub struct SubsetOfMatcher<ExpectedT> {
     superset: ExpectedT,
 }
 
-impl<ElementT: Debug + PartialEq + Copy, ActualT: Debug + Copy, ExpectedT: Debug> Matcher<ActualT>
+impl<ElementT: Debug + PartialEq + Clone, ActualT: Debug + Clone, ExpectedT: Debug> Matcher<ActualT>
     for SubsetOfMatcher<ExpectedT>
 where
     ActualT: IntoIterator<Item = ElementT>,
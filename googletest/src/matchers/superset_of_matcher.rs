@@ -81,7 +81,7 @@ pub struct SupersetOfMatcher<ExpectedT> {
     subset: ExpectedT,
 }
 
-impl<ElementT: Debug + Copy + PartialEq, ActualT: Debug + Copy, ExpectedT: Debug> Matcher<ActualT>
+impl<ElementT: Debug + Clone + PartialEq, ActualT: Debug + Clone, ExpectedT: Debug> Matcher<ActualT>
     for SupersetOfMatcher<ExpectedT>
 where
     ActualT: IntoIterator<Item = ElementT>,
@@ -89,7 +89,7 @@ where
 {
     fn matches(&self, actual: ActualT) -> MatcherResult {
         for expected_item in &self.subset {
-            if actual_is_missing(actual, expected_item) {
+            if actual_is_missing(actual.clone(), expected_item) {
                 return MatcherResult::NoMatch;
             }
         }
@@ -100,7 +100,7 @@ where
         let missing_items: Vec<_> = self
             .subset
             .into_iter()
-            .filter(|expected_item| actual_is_missing(actual, *expected_item))
+            .filter(|expected_item| actual_is_missing(actual.clone(), *expected_item))
             .map(|expected_item| format!("{expected_item:#?}"))
             .collect();
         match missing_items.len() {
@@ -62,7 +62,7 @@ pub mod internal {
         ($([$field_number:tt, $matcher_type:ident, $field_type:ident]),*) => {
             impl<$($matcher_type: MatcherBase),*> MatcherBase for ($($matcher_type,)*){}
 
-            impl<$($field_type: Debug + Copy, $matcher_type: Matcher<$field_type>),*>
+            impl<$($field_type: Debug + Clone, $matcher_type: Matcher<$field_type>),*>
                 Matcher<($($field_type,)*)> for ($($matcher_type,)*)
             {
                 fn matches(&self, actual:  ($($field_type,)*)) -> MatcherResult {
@@ -77,14 +77,14 @@ pub mod internal {
 
                 fn explain_match(&self, actual:  ($($field_type,)*)) -> Description  {
                     let mut explanation = Description::new().text("which").nested(
-                        self.describe(self.matches(actual)));
-                    $(match self.$field_number.matches(actual.$field_number) {
+                        self.describe(self.matches(actual.clone())));
+                    $(match self.$field_number.matches(actual.$field_number.clone()) {
                         MatcherResult::Match => {},
                         MatcherResult::NoMatch => {
                             explanation = explanation
                                 .text(format!(concat!("Element #", $field_number, " is {:?},"),
                                     actual.$field_number))
-                                .nested(self.$field_number.explain_match(actual.$field_number));
+                                .nested(self.$field_number.explain_match(actual.$field_number.clone()));
                         }
                     })*
                     explanation
@@ -82,6 +82,17 @@
 /// #     .unwrap();
 /// ```
 ///
+/// When the number of matchers is only known at runtime, collect them into a
+/// `Vec` of [`BoxedMatcher`][crate::matchers::BoxedMatcher] and pass it
+/// prefixed with `..`:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// let matchers: Vec<BoxedMatcher<&i32>> = vec![lt(&2).boxed(), gt(&1).boxed(), eq(&3).boxed()];
+/// verify_that!(vec![1,2,3], unordered_elements_are![..matchers])
+/// #     .unwrap();
+/// ```
+///
 /// The matcher proceeds in three stages:
 ///
 /// 1. It first checks whether the actual value is of the right size to possibly
@@ -107,10 +118,18 @@
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __unordered_elements_are {
+    (.. $matchers:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::
+        UnorderedElementsAreMatcher::new(
+            ::std::iter::IntoIterator::into_iter($matchers).collect(),
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::
+            Requirements::PerfectMatch)
+    }};
+
     ($(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         UnorderedElementsAreMatcher::new(
-            [],
+            vec![],
             $crate::matchers::__internal_unstable_do_not_depend_on_these::
             Requirements::PerfectMatch)
     }};
@@ -118,7 +137,7 @@ macro_rules! __unordered_elements_are {
     ($($matcher:expr),* $(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         UnorderedElementsAreMatcher::new(
-            [$(Box::new(
+            vec![$($crate::matcher::MatcherBase::boxed(
                 $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
                     $matcher
                 )
@@ -177,6 +196,10 @@ macro_rules! __unordered_elements_are {
 /// #     .unwrap();
 /// ```
 ///
+/// As with [`unordered_elements_are!`][crate::matchers::unordered_elements_are],
+/// a `Vec` of [`BoxedMatcher`][crate::matchers::BoxedMatcher] prefixed with
+/// `..` can be passed instead of a list of matchers.
+///
 /// The matcher proceeds in three stages:
 ///
 /// 1. It first checks whether the actual value is large enough to possibly be
@@ -199,17 +222,24 @@ macro_rules! __unordered_elements_are {
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __contains_each {
+    (.. $matchers:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::
+        UnorderedElementsAreMatcher::new(
+            ::std::iter::IntoIterator::into_iter($matchers).collect(),
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::Superset)
+    }};
+
     ($(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         UnorderedElementsAreMatcher::new(
-            [],
+            vec![],
             $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::Superset)
     }};
 
     ($($matcher:expr),* $(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         UnorderedElementsAreMatcher::new(
-            [$(Box::new(
+            vec![$($crate::matcher::MatcherBase::boxed(
                 $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
                     $matcher
                 )
@@ -268,6 +298,10 @@ macro_rules! __contains_each {
 /// #     .unwrap();
 /// ```
 ///
+/// As with [`unordered_elements_are!`][crate::matchers::unordered_elements_are],
+/// a `Vec` of [`BoxedMatcher`][crate::matchers::BoxedMatcher] prefixed with
+/// `..` can be passed instead of a list of matchers.
+///
 /// The matcher proceeds in three stages:
 ///
 /// 1. It first checks whether the actual value is too large to possibly be
@@ -290,16 +324,23 @@ macro_rules! __contains_each {
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __is_contained_in {
+    (.. $matchers:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::
+        UnorderedElementsAreMatcher::new(
+            ::std::iter::IntoIterator::into_iter($matchers).collect(),
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::Subset)
+    }};
+
     ($(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         UnorderedElementsAreMatcher::new(
-            [], $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::Subset)
+            vec![], $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::Subset)
     }};
 
     ($($matcher:expr),* $(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         UnorderedElementsAreMatcher::new(
-            [$(Box::new(
+            vec![$($crate::matcher::MatcherBase::boxed(
                 $crate::matcher_support::__internal_unstable_do_not_depend_on_these::auto_eq!(
                     $matcher
                 )
@@ -316,7 +357,8 @@ pub mod internal {
     use crate::description::Description;
     use crate::matcher::{Matcher, MatcherBase, MatcherResult};
     use crate::matcher_support::count_elements::count_elements;
-    use std::collections::HashSet;
+    use crate::matchers::BoxedMatcher;
+    use std::collections::{HashMap, HashSet, VecDeque};
     use std::fmt::{Debug, Display};
 
     /// This struct is meant to be used only through the
@@ -325,13 +367,13 @@ pub mod internal {
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
     #[derive(MatcherBase)]
-    pub struct UnorderedElementsAreMatcher<'a, T: Debug + Copy, const N: usize> {
-        elements: [Box<dyn Matcher<T> + 'a>; N],
+    pub struct UnorderedElementsAreMatcher<'a, T: Debug + Clone> {
+        elements: Vec<BoxedMatcher<'a, T>>,
         requirements: Requirements,
     }
 
-    impl<'a, T: Debug + Copy, const N: usize> UnorderedElementsAreMatcher<'a, T, N> {
-        pub fn new(elements: [Box<dyn Matcher<T> + 'a>; N], requirements: Requirements) -> Self {
+    impl<'a, T: Debug + Clone> UnorderedElementsAreMatcher<'a, T> {
+        pub fn new(elements: Vec<BoxedMatcher<'a, T>>, requirements: Requirements) -> Self {
             Self { elements, requirements }
         }
     }
@@ -344,32 +386,48 @@ pub mod internal {
     // 2. `UnorderedElementsAreMatcher` verifies that each actual element matches at
     // least one expected element and vice versa.
     // 3. `UnorderedElementsAreMatcher` verifies that a perfect matching exists
-    // using Ford-Fulkerson.
-    impl<'a, T: Debug + Copy, ContainerT: Debug + Copy, const N: usize> Matcher<ContainerT>
-        for UnorderedElementsAreMatcher<'a, T, N>
+    // using Hopcroft-Karp.
+    //
+    // When all matchers are `eq` matchers, `matches` first looks for a match in
+    // the much smaller graph connecting each actual element only to the matchers
+    // expecting a value with the same `Debug` representation.
+    impl<'a, T: Debug + Clone, ContainerT: Debug + Clone> Matcher<ContainerT>
+        for UnorderedElementsAreMatcher<'a, T>
     where
         ContainerT: IntoIterator<Item = T>,
     {
         fn matches(&self, actual: ContainerT) -> MatcherResult {
-            let match_matrix = MatchMatrix::generate(actual, &self.elements);
-            match_matrix.is_match_for(self.requirements).into()
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            if !self.requirements.accepts_sizes(actual.len(), self.elements.len()) {
+                return MatcherResult::NoMatch;
+            }
+            if MatchGraph::generate_by_hashing(&actual, &self.elements)
+                .is_some_and(|graph| graph.is_match_for(self.requirements))
+            {
+                return MatcherResult::Match;
+            }
+            let match_graph = MatchGraph::generate(&actual, &self.elements);
+            match_graph.is_match_for(self.requirements).into()
         }
 
         fn explain_match(&self, actual: ContainerT) -> Description {
             if let Some(size_mismatch_explanation) =
-                self.requirements.explain_size_mismatch(actual, N)
+                self.requirements.explain_size_mismatch(actual.clone(), self.elements.len())
             {
                 return size_mismatch_explanation;
             }
 
-            let match_matrix = MatchMatrix::generate(actual, &self.elements);
+            let match_graph = MatchGraph::generate(
+                &actual.clone().into_iter().collect::<Vec<_>>(),
+                &self.elements,
+            );
             if let Some(unmatchable_explanation) =
-                match_matrix.explain_unmatchable(self.requirements)
+                match_graph.explain_unmatchable(self.requirements)
             {
                 return unmatchable_explanation;
             }
 
-            let best_match = match_matrix.find_best_match();
+            let best_match = match_graph.find_best_match();
             best_match
                 .get_explanation(actual, &self.elements, self.requirements)
                 .unwrap_or("whose elements all match".into())
@@ -411,28 +469,39 @@ pub mod internal {
     }
 
     impl Requirements {
-        fn explain_size_mismatch<ContainerT: IntoIterator + Copy>(
+        // Returns whether a mapping meeting the requirements can exist between
+        // `actual_size` actual values and `expected_size` matchers.
+        fn accepts_sizes(&self, actual_size: usize, expected_size: usize) -> bool {
+            match self {
+                Requirements::PerfectMatch => actual_size == expected_size,
+                Requirements::Superset => actual_size >= expected_size,
+                Requirements::Subset => actual_size <= expected_size,
+            }
+        }
+
+        fn explain_size_mismatch<ContainerT: IntoIterator + Clone>(
             &self,
             actual: ContainerT,
             expected_size: usize,
         ) -> Option<Description> {
             let actual_size = count_elements(actual);
+            if self.accepts_sizes(actual_size, expected_size) {
+                return None;
+            }
             match self {
-                Requirements::PerfectMatch if actual_size != expected_size => Some(
+                Requirements::PerfectMatch => Some(
                     format!("which has size {} (expected {})", actual_size, expected_size).into(),
                 ),
 
-                Requirements::Superset if actual_size < expected_size => Some(
+                Requirements::Superset => Some(
                     format!("which has size {} (expected at least {})", actual_size, expected_size)
                         .into(),
                 ),
 
-                Requirements::Subset if actual_size > expected_size => Some(
+                Requirements::Subset => Some(
                     format!("which has size {} (expected at most {})", actual_size, expected_size)
                         .into(),
                 ),
-
-                _ => None,
             }
         }
     }
@@ -454,20 +523,68 @@ pub mod internal {
     }
 
     /// The bipartite matching graph between actual and expected elements.
-    struct MatchMatrix<const N: usize>(Vec<[MatcherResult; N]>);
+    ///
+    /// Each entry of `adjacency` corresponds to an actual element and lists
+    /// the indexes of the expected elements matching it, in increasing order.
+    struct MatchGraph {
+        adjacency: Vec<Vec<usize>>,
+        expected_len: usize,
+    }
 
-    impl<const N: usize> MatchMatrix<N> {
-        fn generate<'a, T: Debug + Copy + 'a, ContainerT: Debug + Copy + IntoIterator<Item = T>>(
-            actual: ContainerT,
-            expected: &[Box<dyn Matcher<T> + 'a>; N],
+    impl MatchGraph {
+        /// Builds the complete graph by evaluating every matcher against every
+        /// actual element.
+        fn generate<'a, T: Debug + Clone + 'a>(
+            actual: &[T],
+            expected: &[BoxedMatcher<'a, T>],
         ) -> Self {
-            let mut matrix = MatchMatrix(vec![[MatcherResult::NoMatch; N]; count_elements(actual)]);
-            for (actual_idx, actual) in actual.into_iter().enumerate() {
-                for (expected_idx, expected) in expected.iter().enumerate() {
-                    matrix.0[actual_idx][expected_idx] = expected.matches(actual);
-                }
+            let adjacency = actual
+                .iter()
+                .map(|actual| {
+                    expected
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, expected)| expected.matches(actual.clone()).is_match())
+                        .map(|(expected_idx, _)| expected_idx)
+                        .collect()
+                })
+                .collect();
+            MatchGraph { adjacency, expected_len: expected.len() }
+        }
+
+        /// Builds a subgraph of the complete graph in which an actual element
+        /// is only connected to the `eq` matchers expecting a value with the
+        /// same `Debug` representation, found by hashing.
+        ///
+        /// Returns `None` unless all matchers are `eq` matchers. Equal values
+        /// usually have the same `Debug` representation, so this subgraph
+        /// usually contains a full match if the complete graph does, while
+        /// requiring only about one matcher evaluation per element instead of
+        /// one per pair of actual and expected elements. Since it may miss some
+        /// edges, it can only prove that a match exists, not that none does.
+        fn generate_by_hashing<'a, T: Debug + Clone + 'a>(
+            actual: &[T],
+            expected: &[BoxedMatcher<'a, T>],
+        ) -> Option<Self> {
+            let mut expected_by_debug = HashMap::<&str, Vec<usize>>::new();
+            for (expected_idx, expected) in expected.iter().enumerate() {
+                expected_by_debug.entry(expected.equality_hint()?).or_default().push(expected_idx);
             }
-            matrix
+            let adjacency = actual
+                .iter()
+                .map(|actual| {
+                    expected_by_debug
+                        .get(format!("{actual:?}").as_str())
+                        .into_iter()
+                        .flatten()
+                        .copied()
+                        .filter(|&expected_idx| {
+                            expected[expected_idx].matches(actual.clone()).is_match()
+                        })
+                        .collect()
+                })
+                .collect();
+            Some(MatchGraph { adjacency, expected_len: expected.len() })
         }
 
         fn is_match_for(&self, requirements: Requirements) -> bool {
@@ -500,178 +617,172 @@ pub mod internal {
         // each expected matches at least one actual.
         // This is a necessary condition but not sufficient. But it is faster
         // than `find_best_match()`.
-        fn find_unmatchable_elements(&self) -> UnmatchableElements<N> {
-            let unmatchable_actual =
-                self.0.iter().map(|row| row.iter().all(|&e| e.is_no_match())).collect();
-            let mut unmatchable_expected = [false; N];
-            for (col_idx, expected) in unmatchable_expected.iter_mut().enumerate() {
-                *expected = self.0.iter().map(|row| row[col_idx]).all(|e| e.is_no_match());
+        fn find_unmatchable_elements(&self) -> UnmatchableElements {
+            UnmatchableElements {
+                unmatchable_actual: self.unmatchable_actual(),
+                unmatchable_expected: self.unmatchable_expected(),
             }
-            UnmatchableElements { unmatchable_actual, unmatchable_expected }
         }
 
-        fn find_unmatched_expected(&self) -> UnmatchableElements<N> {
-            let mut unmatchable_expected = [false; N];
-            for (col_idx, expected) in unmatchable_expected.iter_mut().enumerate() {
-                *expected = self.0.iter().map(|row| row[col_idx]).all(|e| e.is_no_match());
+        fn find_unmatched_expected(&self) -> UnmatchableElements {
+            UnmatchableElements {
+                unmatchable_actual: vec![false; self.adjacency.len()],
+                unmatchable_expected: self.unmatchable_expected(),
             }
-            UnmatchableElements { unmatchable_actual: vec![false; N], unmatchable_expected }
         }
 
-        fn find_unmatched_actual(&self) -> UnmatchableElements<N> {
-            let unmatchable_actual =
-                self.0.iter().map(|row| row.iter().all(|e| e.is_no_match())).collect();
-            UnmatchableElements { unmatchable_actual, unmatchable_expected: [false; N] }
+        fn find_unmatched_actual(&self) -> UnmatchableElements {
+            UnmatchableElements {
+                unmatchable_actual: self.unmatchable_actual(),
+                unmatchable_expected: vec![false; self.expected_len],
+            }
         }
 
-        // Verifies that a full match exists.
-        //
-        // Uses the well-known Ford-Fulkerson max flow method to find a maximum
-        // bipartite matching. Flow is considered to be from actual to expected.
-        // There is an implicit source node that is connected to all of the actual
-        // nodes, and an implicit sink node that is connected to all of the
-        // expected nodes. All edges have unit capacity.
-        //
-        // Neither the flow graph nor the residual flow graph are represented
-        // explicitly. Instead, they are implied by the information in `self.0` and
-        // the local `actual_match : [Option<usize>; N]` whose elements are initialized
-        // to `None`. This represents the initial state of the algorithm,
-        // where the flow graph is empty, and the residual flow graph has the
-        // following edges:
-        //   - An edge from source to each actual element node
-        //   - An edge from each expected element node to sink
-        //   - An edge from each actual element node to each expected element node, if
-        //     the actual element matches the expected element, i.e.
-        //     `matches!(self.0[actual_id][expected_id], Matches)`
-        //
-        // When the `try_augment(...)` method adds a flow, it sets `actual_match[l] =
-        // Some(r)` for some nodes l and r. This induces the following changes:
-        //   - The edges (source, l), (l, r), and (r, sink) are added to the flow graph.
-        //   - The same three edges are removed from the residual flow graph.
-        //   - The reverse edges (l, source), (r, l), and (sink, r) are added to the
-        //     residual flow graph, which is a directional graph representing unused
-        //     flow capacity.
-        //
-        // When the method augments a flow (changing `actual_match[l]` from `Some(r1)`
-        // to `Some(r2)`), this can be thought of as "undoing" the above steps
-        // with respect to r1 and "redoing" them with respect to r2.
-        //
-        // It bears repeating that the flow graph and residual flow graph are
-        // never represented explicitly, but can be derived by looking at the
-        // information in 'self.0' and in `actual_match`.
+        fn unmatchable_actual(&self) -> Vec<bool> {
+            self.adjacency.iter().map(|edges| edges.is_empty()).collect()
+        }
+
+        fn unmatchable_expected(&self) -> Vec<bool> {
+            let mut unmatchable = vec![true; self.expected_len];
+            for &expected_idx in self.adjacency.iter().flatten() {
+                unmatchable[expected_idx] = false;
+            }
+            unmatchable
+        }
+
+        // Finds a maximum matching between actual and expected elements with
+        // the Hopcroft-Karp algorithm, in O(E * sqrt(V)) time.
         //
-        // As an optimization, there is a second local `expected_match: [Option<usize>;
-        // N]` which does not provide any new information. Instead, it enables
-        // more efficient queries about edges entering or leaving the expected elements
-        // nodes of the flow or residual flow graphs. The following invariants
-        // are maintained:
+        // The matching is represented by `actual_match`, holding the index of
+        // the expected element matched with each actual element, and by the
+        // inverse mapping `expected_match`.
         //
-        // actual_match[a] == None or expected_match[actual_match[a].unwrap()] ==
-        // Some(a)
-        // expected_match[r] == None or actual_match[expected_match[e].unwrap()] ==
-        // Some(e)
+        // Each phase first runs a breadth-first search from all unmatched
+        // actual elements, alternating between edges outside of the matching
+        // (from actual to expected elements) and edges in the matching (from
+        // expected back to actual elements). This assigns to each reachable
+        // actual element its distance in `layers`, and stops at the first layer
+        // from which an unmatched expected element is reachable. Then a
+        // depth-first search from each unmatched actual element finds a maximal
+        // set of vertex-disjoint shortest augmenting paths, only following
+        // edges to the next layer. Augmenting the matching along these paths
+        // increases its size by their number. The algorithm stops when no
+        // augmenting path remains, at which point the matching is maximum.
         //
-        // | [ source ]                                                              |
-        // |   |||                                                                   |
-        // |   |||                                                                   |
-        // |   ||\-> actual_match[0]=Some(1) -\   expected_match[0]=None    ---\     |
-        // |   ||                             |                                |     |
-        // |   |\--> actual_match[1]=None     \-> expected_match[1]=Some(0) --\|     |
-        // |   |                                                              ||     |
-        // |   \---> actual_match[2]=Some(2)  --> expected_match[2]=Some(2) -\||     |
-        // |                                                                 |||     |
-        // |         elements                     matchers                   vvv     |
-        // |                                                               [ sink ]  |
+        // Both searches visit the actual elements and the expected elements in
+        // increasing index order, so that the first phase matches each actual
+        // element with the first available expected element.
         //
         // See Also:
-        //   [1] Cormen, et al (2001). "Section 26.2: The Ford-Fulkerson method".
-        //       "Introduction to Algorithms (Second ed.)", pp. 651-664.
-        //   [2] "Ford-Fulkerson algorithm", Wikipedia,
-        //       'http://en.wikipedia.org/wiki/Ford%E2%80%93Fulkerson_algorithm'
-        fn find_best_match(&self) -> BestMatch<N> {
-            let mut actual_match = vec![None; self.0.len()];
-            let mut expected_match: [Option<usize>; N] = [None; N];
-            // Searches the residual flow graph for a path from each actual node to
-            // the sink in the residual flow graph, and if one is found, add this path
-            // to the graph.
-            // It's okay to search through the actual nodes once. The
-            // edge from the implicit source node to each previously-visited actual
-            // node will have flow if that actual node has any path to the sink
-            // whatsoever. Subsequent augmentations can only add flow to the
-            // network, and cannot take away that previous flow unit from the source.
-            // Since the source-to-actual edge can only carry one flow unit (or,
-            // each actual element can be matched to only one expected element), there is no
-            // need to visit the actual nodes more than once looking for
-            // augmented paths. The flow is known to be possible or impossible
-            // by looking at the node once.
-            for actual_idx in 0..self.0.len() {
-                assert!(actual_match[actual_idx].is_none());
-                let mut seen = [false; N];
-                self.try_augment(actual_idx, &mut seen, &mut actual_match, &mut expected_match);
+        //   [1] Hopcroft, Karp (1973). "An n^5/2 algorithm for maximum matchings
+        //       in bipartite graphs". SIAM Journal on Computing 2 (4), pp. 225-231.
+        //   [2] "Hopcroft-Karp algorithm", Wikipedia,
+        //       'https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm'
+        fn find_best_match(&self) -> BestMatch {
+            let mut actual_match = vec![None; self.adjacency.len()];
+            let mut expected_match = vec![None; self.expected_len];
+            while let Some((mut layers, last_layer)) =
+                self.find_layers(&actual_match, &expected_match)
+            {
+                let mut next_edge = vec![0; self.adjacency.len()];
+                for actual_idx in 0..self.adjacency.len() {
+                    if actual_match[actual_idx].is_none() {
+                        self.try_augment(
+                            actual_idx,
+                            &mut layers,
+                            last_layer,
+                            &mut next_edge,
+                            &mut actual_match,
+                            &mut expected_match,
+                        );
+                    }
+                }
             }
-            BestMatch(actual_match)
+            BestMatch { actual_match, expected_len: self.expected_len }
         }
 
-        // Perform a depth-first search from actual node `actual_idx` to the sink by
-        // searching for an unassigned expected node. If a path is found, flow
-        // is added to the network by linking the actual and expected vector elements
-        // corresponding each segment of the path. Returns true if a path to
-        // sink was found, which means that a unit of flow was added to the
-        // network. The 'seen' array elements correspond to expected nodes and are
-        // marked to eliminate cycles from the search.
-        //
-        // Actual nodes will only be explored at most once because they
-        // are accessible from at most one expected node in the residual flow
-        // graph.
-        //
-        // Note that `actual_match[actual_idx]` is the only element of `actual_match`
-        // that `try_augment(...)` will potentially transition from `None` to
-        // `Some(...)`. Any other `actual_match` element holding `None` before
-        // `try_augment(...)` will be holding it when `try_augment(...)`
-        // returns.
+        // The breadth-first search of a phase of `find_best_match`. Returns the
+        // layer of each actual element, with `usize::MAX` for those not
+        // reached, and the last layer, or `None` if no augmenting path exists.
+        fn find_layers(
+            &self,
+            actual_match: &[Option<usize>],
+            expected_match: &[Option<usize>],
+        ) -> Option<(Vec<usize>, usize)> {
+            let mut layers = vec![usize::MAX; self.adjacency.len()];
+            let mut queue = VecDeque::new();
+            for (actual_idx, matched) in actual_match.iter().enumerate() {
+                if matched.is_none() {
+                    layers[actual_idx] = 0;
+                    queue.push_back(actual_idx);
+                }
+            }
+            let mut last_layer = None;
+            while let Some(actual_idx) = queue.pop_front() {
+                if last_layer.is_some_and(|last_layer| layers[actual_idx] >= last_layer) {
+                    break;
+                }
+                for &expected_idx in &self.adjacency[actual_idx] {
+                    match expected_match[expected_idx] {
+                        None => last_layer = Some(layers[actual_idx]),
+                        Some(next_idx) if layers[next_idx] == usize::MAX => {
+                            layers[next_idx] = layers[actual_idx] + 1;
+                            queue.push_back(next_idx);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            last_layer.map(|last_layer| (layers, last_layer))
+        }
+
+        // The depth-first search of a phase of `find_best_match`, looking for
+        // an augmenting path from the unmatched actual element `root` through
+        // successive layers. If one is found, the matching is augmented along
+        // it and this returns true. Only paths whose length matches the last
+        // layer are considered, so that all paths of a phase are shortest.
         //
+        // The search is iterative, so that long augmenting paths in large
+        // containers do not overflow the stack. `path` holds the actual
+        // elements of the current path, each of which was left through the
+        // edge preceding `next_edge` in its adjacency list. Actual elements
+        // from which no augmenting path exists are removed from the layers, so
+        // that no later search of the same phase visits them again.
         fn try_augment(
             &self,
-            actual_idx: usize,
-            seen: &mut [bool; N],
+            root: usize,
+            layers: &mut [usize],
+            last_layer: usize,
+            next_edge: &mut [usize],
             actual_match: &mut [Option<usize>],
-            expected_match: &mut [Option<usize>; N],
+            expected_match: &mut [Option<usize>],
         ) -> bool {
-            for expected_idx in 0..N {
-                if seen[expected_idx] {
-                    continue;
-                }
-                if self.0[actual_idx][expected_idx].is_no_match() {
+            let mut path = vec![root];
+            while let Some(&actual_idx) = path.last() {
+                let Some(&expected_idx) = self.adjacency[actual_idx].get(next_edge[actual_idx])
+                else {
+                    layers[actual_idx] = usize::MAX;
+                    path.pop();
                     continue;
-                }
-                // There is an edge between `actual_idx` and `expected_idx`.
-                seen[expected_idx] = true;
-                // Next a search is performed to determine whether
-                // this edge is a dead end or leads to the sink.
-                //
-                // `expected_match[expected_idx].is_none()` means that there is residual flow
-                // from expected node at index expected_idx to the sink, so we
-                // can use that to finish this flow path and return success.
-                //
-                // Otherwise, we look for a residual flow starting from
-                // `expected_match[expected_idx].unwrap()` by calling
-                // ourselves recursively to see if this ultimately leads to
-                // sink.
-                if expected_match[expected_idx].is_none()
-                    || self.try_augment(
-                        expected_match[expected_idx].unwrap(),
-                        seen,
-                        actual_match,
-                        expected_match,
-                    )
-                {
-                    // We found a residual flow from source to sink. We thus need to add the new
-                    // edge to the current flow.
-                    // Note: this also remove the potential flow that existed by overwriting the
-                    // value in the `expected_match` and `actual_match`.
-                    expected_match[expected_idx] = Some(actual_idx);
-                    actual_match[actual_idx] = Some(expected_idx);
-                    return true;
+                };
+                next_edge[actual_idx] += 1;
+                match expected_match[expected_idx] {
+                    None if layers[actual_idx] == last_layer => {
+                        for &actual_idx in &path {
+                            let expected_idx =
+                                self.adjacency[actual_idx][next_edge[actual_idx] - 1];
+                            actual_match[actual_idx] = Some(expected_idx);
+                            expected_match[expected_idx] = Some(actual_idx);
+                        }
+                        return true;
+                    }
+                    Some(next_idx)
+                        if layers[actual_idx] < last_layer
+                            && layers[next_idx] == layers[actual_idx] + 1 =>
+                    {
+                        path.push(next_idx);
+                    }
+                    _ => {}
                 }
             }
             false
@@ -680,15 +791,12 @@ pub mod internal {
 
     /// The list of elements that do not match any element in the corresponding
     /// set.
-    /// These lists are represented as fixed sized bit set to avoid
-    /// allocation.
-    /// TODO(bjacotg) Use BitArr!(for N) once generic_const_exprs is stable.
-    struct UnmatchableElements<const N: usize> {
+    struct UnmatchableElements {
         unmatchable_actual: Vec<bool>,
-        unmatchable_expected: [bool; N],
+        unmatchable_expected: Vec<bool>,
     }
 
-    impl<const N: usize> UnmatchableElements<N> {
+    impl UnmatchableElements {
         fn has_unmatchable_elements(&self) -> bool {
             self.unmatchable_actual.iter().any(|b| *b)
                 || self.unmatchable_expected.iter().any(|b| *b)
@@ -754,17 +862,20 @@ pub mod internal {
     }
 
     /// The representation of a match between actual and expected.
-    /// The value at idx represents to which expected the actual at idx is
-    /// matched with. For example, `BestMatch([Some(0), None, Some(1)])`
-    /// means:
+    /// The value at idx in `actual_match` represents to which expected the
+    /// actual at idx is matched with. For example, an `actual_match` of
+    /// `[Some(0), None, Some(1)]` means:
     ///  * The 0th element in actual matches the 0th element in expected.
     ///  * The 1st element in actual does not match.
     ///  * The 2nd element in actual matches the 1st element in expected.
-    struct BestMatch<const N: usize>(Vec<Option<usize>>);
+    struct BestMatch {
+        actual_match: Vec<Option<usize>>,
+        expected_len: usize,
+    }
 
-    impl<const N: usize> BestMatch<N> {
+    impl BestMatch {
         fn is_full_match(&self) -> bool {
-            self.0.iter().all(|o| o.is_some())
+            self.actual_match.iter().all(|o| o.is_some())
         }
 
         fn is_subset_match(&self) -> bool {
@@ -776,13 +887,13 @@ pub mod internal {
         }
 
         fn get_matches(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
-            self.0.iter().enumerate().filter_map(|(actual_idx, maybe_expected_idx)| {
+            self.actual_match.iter().enumerate().filter_map(|(actual_idx, maybe_expected_idx)| {
                 maybe_expected_idx.map(|expected_idx| (actual_idx, expected_idx))
             })
         }
 
         fn get_unmatched_actual(&self) -> impl Iterator<Item = usize> + '_ {
-            self.0
+            self.actual_match
                 .iter()
                 .enumerate()
                 .filter(|&(_, o)| o.is_none())
@@ -790,18 +901,20 @@ pub mod internal {
         }
 
         fn get_unmatched_expected(&self) -> Vec<usize> {
-            let matched_expected: HashSet<_> = self.0.iter().flatten().collect();
-            (0..N).filter(|expected_idx| !matched_expected.contains(expected_idx)).collect()
+            let matched_expected: HashSet<_> = self.actual_match.iter().flatten().collect();
+            (0..self.expected_len)
+                .filter(|expected_idx| !matched_expected.contains(expected_idx))
+                .collect()
         }
 
         fn get_explanation<
             'a,
-            T: Debug + Copy,
-            ContainerT: Debug + Copy + IntoIterator<Item = T>,
+            T: Debug + Clone,
+            ContainerT: Debug + Clone + IntoIterator<Item = T>,
         >(
             &self,
             actual: ContainerT,
-            expected: &[Box<dyn Matcher<T> + 'a>; N],
+            expected: &[BoxedMatcher<'a, T>],
             requirements: Requirements,
         ) -> Option<Description> {
             let actual: Vec<_> = actual.into_iter().collect();
@@ -851,6 +964,33 @@ mod tests {
     use indoc::indoc;
     use std::collections::HashMap;
 
+    #[test]
+    fn does_not_evaluate_matchers_when_sizes_cannot_match() -> Result<()> {
+        let calls = std::cell::Cell::new(0);
+        let counting = || {
+            predicate(|_: &i32| {
+                calls.set(calls.get() + 1);
+                true
+            })
+        };
+
+        let (three, one, two) = (vec![1, 2, 3], vec![1], vec![1, 2]);
+
+        let perfect_match = unordered_elements_are![counting(), counting()].matches(&three);
+        let superset = contains_each![counting(), counting()].matches(&one);
+        let subset = is_contained_in![counting()].matches(&two);
+
+        verify_that!(
+            (perfect_match, superset, subset, calls.get()),
+            (
+                eq(MatcherResult::NoMatch),
+                eq(MatcherResult::NoMatch),
+                eq(MatcherResult::NoMatch),
+                eq(0)
+            )
+        )
+    }
+
     #[test]
     fn has_correct_description_for_map() -> Result<()> {
         // UnorderedElementsAreMatcher maintains references to the matchers, so the
@@ -911,4 +1051,51 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn unordered_elements_are_finds_match_requiring_augmenting_paths() -> Result<()> {
+        // Matching each element with the first matcher it matches leaves the
+        // last element unmatched, so the matching must be rearranged.
+        verify_that!(vec![1, 2, 3, 4], unordered_elements_are![ge(&1), ge(&2), ge(&3), le(&1)])
+    }
+
+    #[test]
+    fn unordered_elements_are_matches_large_container_of_eq_matchers() -> Result<()> {
+        let value = (0..10_000).collect::<Vec<i32>>();
+        let matchers = value.iter().rev().map(|e| eq(e).boxed()).collect::<Vec<_>>();
+
+        verify_that!(value, unordered_elements_are![..matchers])
+    }
+
+    #[test]
+    fn unordered_elements_are_matches_large_container_of_other_matchers() -> Result<()> {
+        let value = (0..1_000).collect::<Vec<i32>>();
+        let matchers = value.iter().rev().map(|e| ge(e).and(le(e)).boxed()).collect::<Vec<_>>();
+
+        verify_that!(value, unordered_elements_are![..matchers])
+    }
+
+    #[test]
+    fn unordered_elements_are_does_not_match_large_container_with_one_difference() -> Result<()> {
+        let value = (0..1_000).collect::<Vec<i32>>();
+        let mut expected = value.clone();
+        expected[500] = -1;
+        let matchers = expected.iter().map(|e| eq(e).boxed()).collect::<Vec<_>>();
+        let matcher = unordered_elements_are![..matchers];
+
+        verify_that!(
+            matcher.explain_match(&value),
+            displays_as(eq(
+                "whose element #500 does not match any expected elements and no elements match \
+                 the expected element #500"
+            ))
+        )
+    }
+
+    #[test]
+    fn unordered_elements_are_matches_eq_matchers_with_different_debug_output() -> Result<()> {
+        // 0.0 and -0.0 are equal, but have different `Debug` representations,
+        // so the match can only be found without the hashing fast path.
+        verify_that!(vec![0.0, 1.0], unordered_elements_are![eq(&1.0), eq(&-0.0)])
+    }
 }
@@ -0,0 +1,251 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherBase, MatcherResult};
+use std::fmt::Debug;
+
+/// Matches a container with the same elements as `expected`, each occurring
+/// the same number of times, in any order.
+///
+/// This compares the containers as multisets: unlike
+/// [`container_eq`][crate::matchers::container_eq], the order of the elements
+/// does not matter, but their number of occurrences does. The actual and
+/// expected containers may be of different types, for instance a `Vec` and a
+/// [`HashSet`][std::collections::HashSet], as long as their elements can be
+/// compared with `==`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::HashSet;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![3, 1, 2], unordered_eq(HashSet::from([1, 2, 3])))?; // Passes
+/// verify_that!(vec!["a", "b", "a"], unordered_eq(["a", "a", "b"]))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec!["a", "b"], unordered_eq(["a", "a", "b"]))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// When the match fails, the explanation lists every element whose number of
+/// occurrences differs:
+///
+/// ```text
+/// Expected: is equal to ["a", "a", "b", "c"] as a multiset
+/// Actual: ["a", "b", "d"],
+///   which has different element counts:
+///     * expected 2 copies of "a", found 1
+///     * expected 1 copy of "c", found none
+///     * expected no copies of "d", found 1
+/// ```
+///
+/// *Performance note*: The elements are only required to implement
+/// [`PartialEq`], so counting them requires time proportional to the product
+/// of the sizes of the containers and of their numbers of distinct elements.
+pub fn unordered_eq<ExpectedContainerT>(
+    expected: ExpectedContainerT,
+) -> UnorderedEqMatcher<ExpectedContainerT> {
+    UnorderedEqMatcher { expected }
+}
+
+/// A matcher which matches a container with the same elements as `expected`,
+/// with the same multiplicities.
+///
+/// See [`unordered_eq`].
+#[derive(MatcherBase)]
+pub struct UnorderedEqMatcher<ExpectedContainerT> {
+    expected: ExpectedContainerT,
+}
+
+/// The number of occurrences of the distinct elements of the actual and
+/// expected containers.
+struct ElementCounts<'e, ActualElementT, ExpectedElementT> {
+    /// Each distinct expected element, with its number of occurrences in the
+    /// expected and actual containers.
+    expected: Vec<(&'e ExpectedElementT, usize, usize)>,
+    /// Each distinct actual element which is not expected, with its number of
+    /// occurrences.
+    unexpected: Vec<(ActualElementT, usize)>,
+}
+
+impl<ExpectedContainerT> UnorderedEqMatcher<ExpectedContainerT> {
+    fn count<'e, ActualElementT, ExpectedElementT>(
+        &'e self,
+        actual: impl IntoIterator<Item = ActualElementT>,
+    ) -> ElementCounts<'e, ActualElementT, ExpectedElementT>
+    where
+        ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + PartialEq,
+        ExpectedElementT: PartialEq + 'e,
+        &'e ExpectedContainerT: IntoIterator<Item = &'e ExpectedElementT>,
+    {
+        let mut expected: Vec<(&ExpectedElementT, usize, usize)> = vec![];
+        for element in &self.expected {
+            match expected.iter_mut().find(|(e, _, _)| *e == element) {
+                Some((_, count, _)) => *count += 1,
+                None => expected.push((element, 1, 0)),
+            }
+        }
+        let mut unexpected: Vec<(ActualElementT, usize)> = vec![];
+        for element in actual {
+            if let Some((_, _, count)) = expected.iter_mut().find(|(e, _, _)| element == *e) {
+                *count += 1;
+            } else if let Some((_, count)) = unexpected.iter_mut().find(|(e, _)| *e == element) {
+                *count += 1;
+            } else {
+                unexpected.push((element, 1));
+            }
+        }
+        ElementCounts { expected, unexpected }
+    }
+}
+
+impl<ActualElementT, ActualContainerT, ExpectedElementT, ExpectedContainerT>
+    Matcher<ActualContainerT> for UnorderedEqMatcher<ExpectedContainerT>
+where
+    ActualElementT: for<'a> PartialEq<&'a ExpectedElementT> + PartialEq + Debug + Clone,
+    ActualContainerT: IntoIterator<Item = ActualElementT> + Debug + Clone,
+    ExpectedElementT: PartialEq + Debug,
+    ExpectedContainerT: Debug,
+    for<'a> &'a ExpectedContainerT: IntoIterator<Item = &'a ExpectedElementT>,
+{
+    fn matches(&self, actual: ActualContainerT) -> MatcherResult {
+        let counts = self.count(actual);
+        (counts.unexpected.is_empty()
+            && counts.expected.iter().all(|(_, expected, actual)| expected == actual))
+        .into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => format!("is equal to {:?} as a multiset", self.expected).into(),
+            MatcherResult::NoMatch => {
+                format!("isn't equal to {:?} as a multiset", self.expected).into()
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: ActualContainerT) -> Description {
+        let counts = self.count(actual);
+        let differences = counts
+            .expected
+            .iter()
+            .filter(|(_, expected, actual)| expected != actual)
+            .map(|(element, expected, actual)| describe_counts(element, *expected, *actual))
+            .chain(
+                counts
+                    .unexpected
+                    .iter()
+                    .map(|(element, actual)| describe_counts(element, 0, *actual)),
+            )
+            .collect::<Description>();
+        if differences.is_empty() {
+            return "which has the same elements with the same multiplicities".into();
+        }
+        Description::new()
+            .text("which has different element counts:")
+            .nested(differences.bullet_list())
+    }
+}
+
+fn describe_counts(element: &impl Debug, expected: usize, actual: usize) -> String {
+    let copies = match expected {
+        0 => "no copies".to_string(),
+        1 => "1 copy".to_string(),
+        n => format!("{n} copies"),
+    };
+    let found = match actual {
+        0 => "none".to_string(),
+        n => n.to_string(),
+    };
+    format!("expected {copies} of {element:?}, found {found}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::MatcherResult;
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn unordered_eq_matches_same_elements_in_different_order() -> Result<()> {
+        verify_that!(vec![3, 1, 2], unordered_eq(vec![1, 2, 3]))
+    }
+
+    #[test]
+    fn unordered_eq_matches_vec_against_hash_set() -> Result<()> {
+        verify_that!(vec![3, 1, 2], unordered_eq(HashSet::from([1, 2, 3])))
+    }
+
+    #[test]
+    fn unordered_eq_matches_btree_set_against_array() -> Result<()> {
+        verify_that!(BTreeSet::from([1, 2, 3]), unordered_eq([2, 3, 1]))
+    }
+
+    #[test]
+    fn unordered_eq_matches_duplicates() -> Result<()> {
+        verify_that!(vec!["a", "b", "a"], unordered_eq(["a", "a", "b"]))
+    }
+
+    #[test]
+    fn unordered_eq_matches_empty_containers() -> Result<()> {
+        verify_that!(Vec::<i32>::new(), unordered_eq(HashSet::<i32>::new()))
+    }
+
+    #[test]
+    fn unordered_eq_does_not_match_different_multiplicities() -> Result<()> {
+        verify_that!(vec![1, 1, 2], not(unordered_eq(vec![1, 2, 2])))
+    }
+
+    #[test]
+    fn unordered_eq_does_not_match_unexpected_element() -> Result<()> {
+        verify_that!(vec![1, 2, 3], not(unordered_eq(vec![1, 2])))
+    }
+
+    #[test]
+    fn unordered_eq_does_not_match_missing_element() -> Result<()> {
+        verify_that!(vec![1, 2], not(unordered_eq(HashSet::from([1, 2, 3]))))
+    }
+
+    #[test]
+    fn unordered_eq_describes_itself() -> Result<()> {
+        verify_that!(
+            Matcher::<&Vec<i32>>::describe(&unordered_eq(vec![1, 2]), MatcherResult::Match),
+            displays_as(eq("is equal to [1, 2] as a multiset"))
+        )
+    }
+
+    #[test]
+    fn unordered_eq_explains_count_differences() -> Result<()> {
+        let result =
+            verify_that!(vec!["a", "b", "d", "d"], unordered_eq(["a", "a", "a", "b", "c"]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: ["a", "b", "d", "d"],
+                  which has different element counts:
+                    * expected 3 copies of "a", found 1
+                    * expected 1 copy of "c", found none
+                    * expected no copies of "d", found 2
+                "#
+            ))))
+        )
+    }
+}
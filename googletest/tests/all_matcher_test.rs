@@ -152,3 +152,41 @@ fn formats_error_message_correctly_when_all_is_inside_err() -> Result<()> {
         ))))
     )
 }
+
+#[test]
+fn all_matches_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&str>> =
+        vec![starts_with("A").boxed(), ends_with("string").boxed()];
+    verify_that!("A string", all![..matchers])
+}
+
+#[test]
+fn all_matches_empty_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<i32>> = vec![];
+    verify_that!(1, all![..matchers])
+}
+
+#[test]
+fn all_does_not_match_when_one_boxed_matcher_fails() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<i32>> = vec![gt(0).boxed(), lt(10).boxed(), eq(5).boxed()];
+    verify_that!(4, not(all![..matchers]))
+}
+
+#[test]
+fn all_explains_long_vec_of_boxed_matchers_without_deep_recursion() -> Result<()> {
+    // The matchers must not be nested, lest explaining a mismatch overflow the
+    // small stack of this thread.
+    let result = std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let matchers: Vec<BoxedMatcher<i32>> = (0..10_000)
+                .map(|i| if i == 5_000 { lt(0).boxed() } else { gt(-1).boxed() })
+                .collect();
+            verify_that!(1, all![..matchers]).unwrap_err().to_string()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    verify_that!(result, contains_substring("which is greater than or equal to 0"))
+}
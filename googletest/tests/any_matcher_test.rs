@@ -142,3 +142,50 @@ fn formats_error_message_correctly_when_any_is_inside_err() -> Result<()> {
         ))))
     )
 }
+
+#[test]
+fn any_matches_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&str>> =
+        vec![starts_with("Not").boxed(), ends_with("string").boxed()];
+    verify_that!("A string", any![..matchers])
+}
+
+#[test]
+fn any_does_not_match_empty_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<i32>> = vec![];
+    verify_that!(1, not(any![..matchers]))
+}
+
+#[test]
+fn any_describes_vec_of_boxed_matchers_like_listed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<i32>> = vec![eq(1).boxed(), eq(2).boxed(), eq(3).boxed()];
+    let result = verify_that!(4, any![..matchers]);
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: has at least one of the following properties:
+              * is equal to 1
+              * is equal to 2
+              * is equal to 3"
+        ))))
+    )
+}
+
+#[test]
+fn any_explains_long_vec_of_boxed_matchers_without_deep_recursion() -> Result<()> {
+    // The matchers must not be nested, lest explaining a mismatch overflow the
+    // small stack of this thread.
+    let result = std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let matchers: Vec<BoxedMatcher<i32>> = (0..10_000).map(|i| eq(i + 2).boxed()).collect();
+            verify_that!(1, any![..matchers]).unwrap_err().to_string()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    verify_that!(result, contains_substring("which isn't equal to 10001"))
+}
@@ -0,0 +1,153 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::prelude::*;
+use indoc::indoc;
+
+#[test]
+fn contains_subsequence_matches_elements_with_gaps() -> Result<()> {
+    verify_that!(vec![1, 2, 3, 4, 5], contains_subsequence![eq(&1), eq(&3), eq(&5)])
+}
+
+#[test]
+fn contains_subsequence_matches_adjacent_elements() -> Result<()> {
+    verify_that!(vec![1, 2, 3], contains_subsequence![eq(&1), eq(&2), eq(&3)])
+}
+
+#[test]
+fn contains_subsequence_matches_with_no_matchers() -> Result<()> {
+    verify_that!(vec![1, 2, 3], contains_subsequence![])
+}
+
+#[test]
+fn contains_subsequence_matches_array_with_omitted_eq() -> Result<()> {
+    verify_that!([1, 2, 3, 4], contains_subsequence![2, 4])
+}
+
+#[test]
+fn contains_subsequence_does_not_match_elements_in_wrong_order() -> Result<()> {
+    verify_that!(vec![1, 2, 3], not(contains_subsequence![eq(&3), eq(&1)]))
+}
+
+#[test]
+fn contains_subsequence_does_not_reuse_element() -> Result<()> {
+    verify_that!(vec![1, 2], not(contains_subsequence![eq(&1), eq(&1)]))
+}
+
+#[test]
+fn contains_subsequence_supports_runtime_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&i32>> = vec![eq(&2).boxed(), gt(&3).boxed()];
+    verify_that!(vec![1, 2, 3, 4], contains_subsequence![..matchers])
+}
+
+#[test]
+fn contains_subsequence_explains_where_match_broke_off() -> Result<()> {
+    let result = verify_that!(vec![1, 2, 3, 4, 5], contains_subsequence![eq(&2), eq(&4), eq(&1)]);
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: contains a subsequence of elements:
+              0. is equal to 2
+              1. is equal to 4
+              2. is equal to 1
+            Actual: [1, 2, 3, 4, 5],
+              which contains the first 2 of 3 expected elements in order, at #1, #3,
+              but no later element is equal to 1
+            "
+        ))))
+    )
+}
+
+#[test]
+fn contains_subsequence_explains_when_first_element_is_missing() -> Result<()> {
+    let result = verify_that!(vec![1, 2, 3], contains_subsequence![eq(&4), eq(&1)]);
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("which contains no element that is equal to 4")))
+    )
+}
+
+#[test]
+fn contains_contiguous_matches_adjacent_elements() -> Result<()> {
+    verify_that!(vec![1, 2, 3, 4, 5], contains_contiguous![eq(&2), eq(&3), eq(&4)])
+}
+
+#[test]
+fn contains_contiguous_matches_after_partial_match() -> Result<()> {
+    verify_that!(vec![1, 2, 1, 2, 3], contains_contiguous![eq(&1), eq(&2), eq(&3)])
+}
+
+#[test]
+fn contains_contiguous_matches_with_no_matchers() -> Result<()> {
+    verify_that!(vec![1, 2, 3], contains_contiguous![])
+}
+
+#[test]
+fn contains_contiguous_does_not_match_elements_with_gaps() -> Result<()> {
+    verify_that!(vec![1, 2, 3, 4], not(contains_contiguous![eq(&2), eq(&4)]))
+}
+
+#[test]
+fn contains_contiguous_does_not_match_run_longer_than_container() -> Result<()> {
+    verify_that!(vec![1, 2], not(contains_contiguous![eq(&1), eq(&2), eq(&3)]))
+}
+
+#[test]
+fn contains_contiguous_explains_longest_partial_match() -> Result<()> {
+    let result = verify_that!(
+        vec![1, 2, 9, 1, 2, 3, 8],
+        contains_contiguous![eq(&1), eq(&2), eq(&3), eq(&4)]
+    );
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: contains a contiguous run of elements:
+              0. is equal to 1
+              1. is equal to 2
+              2. is equal to 3
+              3. is equal to 4
+            Actual: [1, 2, 9, 1, 2, 3, 8],
+              whose longest partial match starts at element #3 and matches the first 3 of 4 expected elements, but element #6 is 8, which isn't equal to 4
+            "
+        ))))
+    )
+}
+
+#[test]
+fn contains_contiguous_explains_partial_match_at_end_of_container() -> Result<()> {
+    let result = verify_that!(vec![0, 1, 2], contains_contiguous![eq(&1), eq(&2), eq(&3)]);
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "whose longest partial match starts at element #1 and matches the first 2 of 3 \
+             expected elements, but the container ends before an element that is equal to 3"
+        )))
+    )
+}
+
+#[test]
+fn contains_contiguous_explains_when_no_element_matches() -> Result<()> {
+    let result = verify_that!(vec![1, 2, 3], contains_contiguous![eq(&4)]);
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("which contains no element that is equal to 4")))
+    )
+}
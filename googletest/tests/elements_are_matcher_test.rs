@@ -144,3 +144,295 @@ fn elements_are_implicitly_called() -> Result<()> {
 fn elements_are_with_auto_eq() -> Result<()> {
     verify_that!(vec![1, 2, 3], [&1, &2, lt(&43)])
 }
+
+#[test]
+fn elements_are_matches_more_matchers_than_the_recursion_limit() -> Result<()> {
+    verify_that!(
+        (0..130).collect::<Vec<i32>>(),
+        elements_are![
+            &0, &1, &2, &3, &4, &5, &6, &7, &8, &9, &10, &11, &12, &13, &14, &15, &16, &17, &18,
+            &19, &20, &21, &22, &23, &24, &25, &26, &27, &28, &29, &30, &31, &32, &33, &34, &35,
+            &36, &37, &38, &39, &40, &41, &42, &43, &44, &45, &46, &47, &48, &49, &50, &51, &52,
+            &53, &54, &55, &56, &57, &58, &59, &60, &61, &62, &63, &64, &65, &66, &67, &68, &69,
+            &70, &71, &72, &73, &74, &75, &76, &77, &78, &79, &80, &81, &82, &83, &84, &85, &86,
+            &87, &88, &89, &90, &91, &92, &93, &94, &95, &96, &97, &98, &99, &100, &101, &102,
+            &103, &104, &105, &106, &107, &108, &109, &110, &111, &112, &113, &114, &115, &116,
+            &117, &118, &119, &120, &121, &122, &123, &124, &125, &126, &127, &128, &129,
+        ]
+    )
+}
+
+#[test]
+fn elements_are_matches_rest_among_more_matchers_than_the_recursion_limit() -> Result<()> {
+    verify_that!(
+        (0..200).collect::<Vec<i32>>(),
+        elements_are![
+            &0,
+            &1,
+            &2,
+            &3,
+            &4,
+            &5,
+            &6,
+            &7,
+            &8,
+            &9,
+            &10,
+            &11,
+            &12,
+            &13,
+            &14,
+            &15,
+            &16,
+            &17,
+            &18,
+            &19,
+            &20,
+            &21,
+            &22,
+            &23,
+            &24,
+            &25,
+            &26,
+            &27,
+            &28,
+            &29,
+            &30,
+            &31,
+            &32,
+            &33,
+            &34,
+            &35,
+            &36,
+            &37,
+            &38,
+            &39,
+            &40,
+            &41,
+            &42,
+            &43,
+            &44,
+            &45,
+            &46,
+            &47,
+            &48,
+            &49,
+            &50,
+            &51,
+            &52,
+            &53,
+            &54,
+            &55,
+            &56,
+            &57,
+            &58,
+            &59,
+            &60,
+            &61,
+            &62,
+            &63,
+            &64,
+            &65,
+            &66,
+            &67,
+            &68,
+            &69,
+            &70,
+            &71,
+            &72,
+            &73,
+            &74,
+            &75,
+            &76,
+            &77,
+            &78,
+            &79,
+            &80,
+            &81,
+            &82,
+            &83,
+            &84,
+            &85,
+            &86,
+            &87,
+            &88,
+            &89,
+            &90,
+            &91,
+            &92,
+            &93,
+            &94,
+            &95,
+            &96,
+            &97,
+            &98,
+            &99,
+            &100,
+            &101,
+            &102,
+            &103,
+            &104,
+            &105,
+            &106,
+            &107,
+            &108,
+            &109,
+            &110,
+            &111,
+            &112,
+            &113,
+            &114,
+            &115,
+            &116,
+            &117,
+            &118,
+            &119,
+            &120,
+            &121,
+            &122,
+            &123,
+            &124,
+            &125,
+            &126,
+            &127,
+            &128,
+            &129,
+            ..
+        ]
+    )
+}
+
+#[test]
+fn elements_are_matches_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&i32>> =
+        vec![eq(&1).boxed(), gt(&1).boxed(), anything().boxed()];
+    verify_that!(vec![1, 2, 3], elements_are![..matchers])
+}
+
+#[test]
+fn elements_are_matches_boxed_matchers_built_at_runtime() -> Result<()> {
+    let expected = [1, 2, 3];
+    let matchers: Vec<BoxedMatcher<&i32>> = expected.iter().map(|e| eq(e).boxed()).collect();
+    verify_that!(vec![1, 2, 3], elements_are![..matchers])
+}
+
+#[test]
+fn elements_are_explains_mismatch_with_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&i32>> = vec![eq(&1).boxed(), gt(&3).boxed()];
+    let result = verify_that!(vec![1, 2], elements_are![..matchers]);
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: has elements:
+              0. is equal to 1
+              1. is greater than 3
+            Actual: [1, 2],
+              where element #1 is 2, which is less than or equal to 3"
+        ))))
+    )
+}
+
+#[test]
+fn elements_are_matches_wildcard() -> Result<()> {
+    verify_that!(vec![1, 4, 3], elements_are![eq(&1), _, eq(&3)])
+}
+
+#[test]
+fn elements_are_matches_rest_in_the_middle() -> Result<()> {
+    verify_that!(vec![1, 2, 3, 4, 5], elements_are![eq(&1), .., eq(&5)])
+}
+
+#[test]
+fn elements_are_matches_rest_with_no_elements() -> Result<()> {
+    verify_that!(vec![1, 5], elements_are![eq(&1), .., eq(&5)])
+}
+
+#[test]
+fn elements_are_matches_wildcard_and_trailing_rest() -> Result<()> {
+    verify_that!(vec![1, 4, 3], elements_are![_, gt(&3), ..])
+}
+
+#[test]
+fn elements_are_matches_only_rest() -> Result<()> {
+    let value: Vec<i32> = vec![];
+    verify_that!(value, elements_are![..])
+}
+
+#[test]
+fn elements_are_matches_several_rests() -> Result<()> {
+    verify_that!(vec![1, 2, 3, 4, 5], elements_are![.., &2, .., &4, ..])
+}
+
+#[test]
+fn elements_are_with_wildcard_does_not_match_container_of_wrong_size() -> Result<()> {
+    verify_that!(vec![1, 2], not(elements_are![_, _, _]))
+}
+
+#[test]
+fn elements_are_with_rest_does_not_match_container_which_is_too_short() -> Result<()> {
+    verify_that!(vec![1], not(elements_are![eq(&1), .., eq(&1)]))
+}
+
+#[test]
+fn elements_are_with_rest_does_not_match_wrong_last_element() -> Result<()> {
+    verify_that!(vec![1, 2, 3], not(elements_are![eq(&1), .., eq(&4)]))
+}
+
+#[test]
+fn elements_are_with_rest_describes_rest() -> Result<()> {
+    let result = verify_that!(vec![1, 2, 3], elements_are![eq(&1), .., eq(&4)]);
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: has elements:
+              0. is equal to 1
+              1. any number of elements
+              2. is equal to 4
+            Actual: [1, 2, 3],
+              where element #2 is 3, which isn't equal to 4, for matcher #2
+            "
+        ))))
+    )
+}
+
+#[test]
+fn elements_are_with_rest_explains_missing_element() -> Result<()> {
+    let value = vec![1, 5];
+    let matcher = elements_are![eq(&1), eq(&2), .., eq(&5)];
+
+    verify_that!(
+        matcher.explain_match(&value),
+        displays_as(eq("where no element is left for matcher #1: is equal to 2"))
+    )
+}
+
+#[test]
+fn elements_are_with_rest_explains_unexpected_element() -> Result<()> {
+    let value = vec![5, 6, 7];
+    let matcher = elements_are![.., eq(&5), eq(&6)];
+
+    verify_that!(
+        matcher.explain_match(&value),
+        displays_as(eq("where element #2 is 7, which is unexpected"))
+    )
+}
+
+#[test]
+fn elements_are_with_rest_explains_several_mismatches() -> Result<()> {
+    let value = vec![0, 2, 3, 4, 6];
+    let matcher = elements_are![eq(&1), .., eq(&4), eq(&5)];
+
+    verify_that!(
+        matcher.explain_match(&value),
+        displays_as(eq(indoc!(
+            "
+            where:
+              * element #0 is 0, which isn't equal to 1, for matcher #0
+              * element #4 is 6, which isn't equal to 5, for matcher #3"
+        )))
+    )
+}
@@ -0,0 +1,39 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::prelude::*;
+
+#[test]
+fn eq_file_resolves_path_relative_to_crate() -> Result<()> {
+    verify_that!("Inventory\n  apples: 3\n  pears: 5\n", eq_file("tests/testdata/inventory.txt"))
+}
+
+#[test]
+fn eq_file_bytes_resolves_path_relative_to_crate() -> Result<()> {
+    verify_that!(b"\x89PNG\r\n\x1a\n", eq_file_bytes("tests/testdata/header.bin"))
+}
+
+#[test]
+fn eq_file_explains_against_which_directory_missing_file_was_resolved() -> Result<()> {
+    let result = verify_that!("", eq_file("tests/testdata/missing.txt"));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(format!(
+            "does not exist (resolved against CARGO_MANIFEST_DIR {}); run with \
+            GOOGLETEST_UPDATE_GOLDEN_FILES=1 to create it",
+            env!("CARGO_MANIFEST_DIR")
+        ))))
+    )
+}
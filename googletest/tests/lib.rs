@@ -17,11 +17,16 @@ mod any_matcher_test;
 mod assertions_test;
 mod colorized_diff_test;
 mod composition_test;
+mod contains_subsequence_matcher_test;
 mod elements_are_matcher_test;
 mod field_matcher_test;
+mod golden_file_matcher_test;
+mod matchable_derive_test;
+mod matcher_attribute_test;
 mod matches_pattern_test;
 mod pointwise_matcher_test;
 mod property_matcher_test;
 mod proptest_integration_test;
+mod snapshot_matcher_test;
 mod tuple_matcher_test;
 mod unordered_elements_are_matcher_test;
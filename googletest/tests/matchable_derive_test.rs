@@ -0,0 +1,192 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::matcher::MatcherResult;
+use googletest::prelude::*;
+use indoc::indoc;
+
+#[derive(Debug, Matchable)]
+struct AStruct {
+    a_field: String,
+    another_field: u32,
+}
+
+#[derive(Debug, Matchable)]
+struct ATupleStruct(i32, &'static str);
+
+#[derive(Debug, Matchable)]
+struct Outer {
+    inner: AStruct,
+    count: usize,
+}
+
+#[derive(Debug, Matchable)]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Matchable)]
+enum AnEnum {
+    Unit,
+    Tuple(u32, String),
+    Named { a_field: i32 },
+    HttpError(u16),
+    Type,
+}
+
+#[test]
+fn matches_struct_without_field_expectations() -> Result<()> {
+    verify_that!(AStruct { a_field: "A".into(), another_field: 1 }, AStruct::matcher())
+}
+
+#[test]
+fn matches_struct_with_matching_fields() -> Result<()> {
+    verify_that!(
+        AStruct { a_field: "Something".into(), another_field: 123 },
+        AStruct::matcher().a_field(starts_with("Some")).another_field(eq(&123))
+    )
+}
+
+#[test]
+fn does_not_match_struct_with_mismatching_field() -> Result<()> {
+    verify_that!(
+        AStruct { a_field: "Something".into(), another_field: 123 },
+        not(AStruct::matcher().a_field(starts_with("Some")).another_field(eq(&124)))
+    )
+}
+
+#[test]
+fn matches_tuple_struct_fields() -> Result<()> {
+    verify_that!(ATupleStruct(1, "A"), ATupleStruct::matcher()._0(gt(&0))._1(eq(&"A")))
+}
+
+#[test]
+fn matches_generic_struct() -> Result<()> {
+    verify_that!(Wrapper { value: vec![1, 2] }, Wrapper::matcher().value(len(eq(2))))
+}
+
+#[test]
+fn describes_each_field_expectation() -> Result<()> {
+    let matcher = AStruct::matcher().a_field(starts_with("Some")).another_field(eq(&123));
+
+    verify_that!(
+        matcher.describe(MatcherResult::Match),
+        displays_as(eq(indoc!(
+            r#"
+            is AStruct which has all the following properties:
+              * has field `a_field`, which starts with prefix "Some"
+              * has field `another_field`, which is equal to 123"#
+        )))
+    )
+}
+
+#[test]
+fn lists_each_mismatching_field_in_failure_message() -> Result<()> {
+    let result = verify_that!(
+        AStruct { a_field: "Something".into(), another_field: 123 },
+        AStruct::matcher().a_field(starts_with("Other")).another_field(eq(&124))
+    );
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            r#"
+            Actual: AStruct { a_field: "Something", another_field: 123 },
+              * which has field `a_field`, which does not start with "Other"
+              * which has field `another_field`, which isn't equal to 124"#
+        ))))
+    )
+}
+
+#[test]
+fn omits_matching_fields_from_failure_message() -> Result<()> {
+    let result = verify_that!(
+        AStruct { a_field: "Something".into(), another_field: 123 },
+        AStruct::matcher().a_field(starts_with("Some")).another_field(eq(&124))
+    );
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            r#"
+            Actual: AStruct { a_field: "Something", another_field: 123 },
+              which has field `another_field`, which isn't equal to 124"#
+        ))))
+    )
+}
+
+#[test]
+fn explains_nested_field_mismatch() -> Result<()> {
+    let result = verify_that!(
+        Outer { inner: AStruct { a_field: "A".into(), another_field: 1 }, count: 1 },
+        Outer::matcher().inner(AStruct::matcher().another_field(eq(&2))).count(eq(&1))
+    );
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which has field `inner`, which has field `another_field`, which isn't equal to 2"
+        )))
+    )
+}
+
+#[test]
+fn matches_enum_variant_without_fields() -> Result<()> {
+    verify_that!(AnEnum::Unit, AnEnum::matcher().unit())
+}
+
+#[test]
+fn does_not_match_other_enum_variant() -> Result<()> {
+    verify_that!(AnEnum::Named { a_field: 1 }, not(AnEnum::matcher().unit()))
+}
+
+#[test]
+fn matches_enum_tuple_variant_fields() -> Result<()> {
+    verify_that!(AnEnum::Tuple(1, "A".into()), AnEnum::matcher().tuple()._0(eq(&1))._1(eq("A")))
+}
+
+#[test]
+fn matches_enum_named_variant_fields() -> Result<()> {
+    verify_that!(AnEnum::Named { a_field: 1 }, AnEnum::matcher().named().a_field(eq(&1)))
+}
+
+#[test]
+fn names_variant_methods_in_snake_case() -> Result<()> {
+    verify_that!(AnEnum::HttpError(404), AnEnum::matcher().http_error()._0(eq(&404)))?;
+    verify_that!(AnEnum::Type, AnEnum::matcher().r#type())
+}
+
+#[test]
+fn explains_wrong_enum_variant() -> Result<()> {
+    let result = verify_that!(AnEnum::Unit, AnEnum::matcher().named().a_field(eq(&1)));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: is AnEnum::Named which has field `a_field`, which is equal to 1
+            Actual: Unit,
+              which has the wrong enum variant `Unit`"
+        ))))
+    )
+}
+
+#[test]
+fn can_be_used_in_container_matchers() -> Result<()> {
+    verify_that!(
+        vec![ATupleStruct(1, "A"), ATupleStruct(2, "B")],
+        elements_are![ATupleStruct::matcher()._0(eq(&1)), ATupleStruct::matcher()._1(eq(&"B"))]
+    )
+}
@@ -0,0 +1,288 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::matcher::MatcherResult;
+use googletest::prelude::*;
+use indoc::indoc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[matcher]
+fn is_even(actual: u32) -> bool {
+    actual % 2 == 0
+}
+
+#[matcher]
+fn is_divisible_by(actual: u32, divisor: u32) -> bool {
+    actual % divisor == 0
+}
+
+#[matcher(
+    description = "is between {low} and {high}",
+    negated_description = "is outside {low}..={high}"
+)]
+fn is_between(actual: i32, low: i32, high: i32) -> bool {
+    (low..=high).contains(&actual)
+}
+
+#[matcher(description = "has length {length}")]
+fn has_length(actual: &str, length: usize) -> bool {
+    actual.len() == length
+}
+
+#[matcher]
+fn is_sorted(actual: &Vec<i32>) -> std::result::Result<(), String> {
+    match actual.windows(2).position(|pair| pair[0] > pair[1]) {
+        Some(index) => Err(format!("which is out of order at index {}", index + 1)),
+        None => Ok(()),
+    }
+}
+
+static IS_LARGE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[matcher]
+fn is_large(actual: u32) -> std::result::Result<(), String> {
+    let calls = IS_LARGE_CALLS.fetch_add(1, Ordering::SeqCst) + 1;
+    if actual > 100 {
+        Ok(())
+    } else {
+        Err(format!("which is small, as seen on call #{calls}"))
+    }
+}
+
+#[matcher]
+fn is_default<T: Default + PartialEq>(actual: T) -> bool {
+    actual == T::default()
+}
+
+#[matcher]
+fn is_odd(actual: &u32) -> bool {
+    actual % 2 == 1
+}
+
+#[matcher]
+fn is_default_ref<T: Default + PartialEq>(actual: &T) -> bool {
+    *actual == T::default()
+}
+
+#[matcher]
+fn starts_with_word(actual: &str, word: String) -> bool {
+    actual.split_whitespace().next() == Some(word.as_str())
+}
+
+/// Is a multiple of
+/// three.
+///
+/// This paragraph is not part of the description.
+#[matcher]
+fn is_multiple_of_three(actual: u32) -> bool {
+    actual % 3 == 0
+}
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// Is a `Point { .. }` on the diagonal.
+#[matcher]
+fn is_on_diagonal(actual: &Point) -> bool {
+    actual.x == actual.y
+}
+
+/// Is ignored in favour of the explicit description.
+#[matcher(description = "is zero")]
+fn is_zero(actual: u32) -> bool {
+    actual == 0
+}
+
+#[test]
+fn matches_value_satisfying_predicate() -> Result<()> {
+    verify_that!(4, is_even())
+}
+
+#[test]
+fn does_not_match_value_not_satisfying_predicate() -> Result<()> {
+    verify_that!(3, not(is_even()))
+}
+
+#[test]
+fn describes_matcher_from_function_name() -> Result<()> {
+    let result = verify_that!(3, is_even());
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Value of: 3
+            Expected: is even
+            Actual: 3,
+              which isn't even"
+        ))))
+    )
+}
+
+#[test]
+fn matches_with_extra_parameter() -> Result<()> {
+    verify_that!(9, is_divisible_by(3))
+}
+
+#[test]
+fn describes_extra_parameter_after_function_name() -> Result<()> {
+    let result = verify_that!(10, is_divisible_by(3));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: is divisible by 3
+            Actual: 10,
+              which isn't divisible by 3"
+        ))))
+    )
+}
+
+#[test]
+fn uses_explicit_descriptions() -> Result<()> {
+    let result = verify_that!(11, is_between(1, 10));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: is between 1 and 10
+            Actual: 11,
+              which is outside 1..=10"
+        ))))
+    )
+}
+
+#[test]
+fn derives_negated_description_from_explicit_description() -> Result<()> {
+    verify_that!(
+        has_length(3).describe(MatcherResult::NoMatch),
+        displays_as(eq("doesn't have length 3"))
+    )
+}
+
+#[test]
+fn uses_first_paragraph_of_doc_comment_as_description() -> Result<()> {
+    let result = verify_that!(10, is_multiple_of_three());
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: is a multiple of three
+            Actual: 10,
+              which isn't a multiple of three"
+        ))))
+    )
+}
+
+#[test]
+fn uses_doc_comment_with_braces_literally() -> Result<()> {
+    let result = verify_that!(Point { x: 1, y: 2 }, is_on_diagonal());
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("Expected: is a `Point { .. }` on the diagonal")))
+    )
+}
+
+#[test]
+fn prefers_explicit_description_to_doc_comment() -> Result<()> {
+    verify_that!(is_zero().describe(MatcherResult::Match), displays_as(eq("is zero")))
+}
+
+#[test]
+fn matches_reference_actual_value() -> Result<()> {
+    verify_that!("abc", has_length(3))
+}
+
+#[test]
+fn matches_when_result_is_ok() -> Result<()> {
+    verify_that!(vec![1, 2, 3], is_sorted())
+}
+
+#[test]
+fn explains_mismatch_with_error_from_result() -> Result<()> {
+    let result = verify_that!(vec![1, 3, 2], is_sorted());
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: is sorted
+            Actual: [1, 3, 2],
+              which is out of order at index 2"
+        ))))
+    )
+}
+
+#[test]
+fn matches_with_generic_function() -> Result<()> {
+    verify_that!(0, is_default())?;
+    verify_that!(None::<i32>, is_default())
+}
+
+#[test]
+fn matches_with_non_copy_parameter() -> Result<()> {
+    verify_that!("hello world", starts_with_word("hello".to_string()))
+}
+
+#[test]
+fn can_be_composed_with_other_matchers() -> Result<()> {
+    verify_that!(6, is_even().and(is_divisible_by(3)))
+}
+
+#[test]
+fn matches_copy_value_with_reference_parameter() -> Result<()> {
+    verify_that!(3u32, is_odd())?;
+    verify_that!(4u32, not(is_odd()))
+}
+
+#[test]
+fn matches_copy_value_with_generic_reference_parameter() -> Result<()> {
+    verify_that!(0i64, is_default_ref())?;
+    verify_that!(vec![1], not(is_default_ref()))
+}
+
+#[test]
+fn describes_copy_value_with_reference_parameter() -> Result<()> {
+    let result = verify_that!(4u32, is_odd());
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Expected: is odd
+            Actual: 4,
+              which isn't odd"
+        ))))
+    )
+}
+
+#[test]
+fn can_be_used_in_container_matchers() -> Result<()> {
+    verify_that!(vec![1u32, 2, 3], elements_are![is_odd(), not(is_odd()), is_odd()])
+}
+
+#[test]
+fn evaluates_function_once_on_mismatch() -> Result<()> {
+    let result = verify_that!(5, is_large());
+
+    verify_that!(IS_LARGE_CALLS.load(Ordering::SeqCst), eq(1))?;
+    verify_that!(result, err(displays_as(contains_substring("which is small, as seen on call #1"))))
+}
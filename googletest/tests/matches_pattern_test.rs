@@ -1814,3 +1814,23 @@ fn matches_struct_auto_eq() -> Result<()> {
         matches_pattern!(&AStruct { int: 123, string: ref "123", option: Some(123) })
     )
 }
+
+#[test]
+fn evaluates_property_once_on_mismatch() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        calls: std::cell::Cell<u32>,
+    }
+    impl AStruct {
+        fn next_call(&self) -> u32 {
+            self.calls.set(self.calls.get() + 1);
+            self.calls.get()
+        }
+    }
+    let actual = AStruct { calls: 0.into() };
+
+    let result = verify_that!(actual, matches_pattern!(&AStruct { next_call(): eq(234) }));
+
+    verify_that!(actual.calls.get(), eq(1))?;
+    verify_that!(result, err(displays_as(contains_substring("is `1`, which isn't equal to 234"))))
+}
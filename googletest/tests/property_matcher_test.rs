@@ -14,6 +14,7 @@
 
 use googletest::matcher::MatcherResult;
 use googletest::prelude::*;
+use std::cell::Cell;
 
 #[derive(Debug)]
 struct SomeStruct {
@@ -38,6 +39,18 @@ impl SomeStruct {
     }
 }
 
+#[derive(Debug)]
+struct CallCounter {
+    calls: Cell<u32>,
+}
+
+impl CallCounter {
+    fn next_call(&self) -> u32 {
+        self.calls.set(self.calls.get() + 1);
+        self.calls.get()
+    }
+}
+
 #[test]
 fn matches_struct_with_matching_property() -> Result<()> {
     let value = SomeStruct { a_property: 10 };
@@ -296,3 +309,36 @@ fn matches_property_auto_eq() -> Result<()> {
 
     verify_that!(Struct, property!(Struct.property(), "something"))
 }
+
+#[test]
+fn calls_property_once_on_mismatch() -> Result<()> {
+    let value = CallCounter { calls: 0.into() };
+
+    let result = verify_that!(value, property!(&CallCounter.next_call(), eq(10)));
+
+    verify_that!(value.calls.get(), eq(1))?;
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("whose property `next_call()` is `1`, which isn't")))
+    )
+}
+
+#[test]
+fn calls_property_once_in_composed_matchers() -> Result<()> {
+    let value = CallCounter { calls: 0.into() };
+
+    let result =
+        verify_that!(value, not(all!(property!(&CallCounter.next_call(), eq(1)), anything())));
+
+    verify_that!(value.calls.get(), eq(1))?;
+    verify_that!(result, err(anything()))
+}
+
+#[test]
+fn calls_property_once_when_matching() -> Result<()> {
+    let value = CallCounter { calls: 0.into() };
+
+    verify_that!(value, property!(&CallCounter.next_call(), eq(1)))?;
+
+    verify_that!(value.calls.get(), eq(1))
+}
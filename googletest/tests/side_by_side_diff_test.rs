@@ -0,0 +1,45 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Strukt {
+    int: i32,
+    string: &'static str,
+}
+
+#[test]
+fn shows_side_by_side_diff_when_requested() -> Result<()> {
+    std::env::set_var("GOOGLETEST_DIFF_STYLE", "side-by-side");
+    std::env::set_var("COLUMNS", "100");
+    std::env::set_var("NO_COLOR", "1");
+
+    let result = verify_that!(
+        Strukt { int: 123, string: "something" },
+        eq(&Strukt { int: 321, string: "something" })
+    );
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "
+  Difference(-actual / +expected):
+  1   Strukt {                 | 1   Strukt {
+  2 -     int: 123,            | 2 +     int: 321,
+  3       string: \"something\", | 3       string: \"something\",
+  4   }                        | 4   }"
+        )))
+    )
+}
@@ -0,0 +1,39 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Inventory {
+    owner: &'static str,
+    items: BTreeMap<&'static str, u32>,
+}
+
+#[test]
+fn matches_checked_in_snapshots() -> Result<()> {
+    let inventory = Inventory { owner: "warehouse", items: BTreeMap::from([("apples", 3)]) };
+
+    verify_that!(inventory, matches_snapshot!())?;
+    verify_that!("Restocked at 2024-03-01", matches_snapshot!().using_display())
+}
+
+#[test]
+fn matches_named_snapshot_with_redaction() -> Result<()> {
+    verify_that!(
+        "Shipped 12 crates on 2024-03-01",
+        matches_snapshot!("shipment").using_display().redacting(r"\d{4}-\d{2}-\d{2}", "[date]")
+    )
+}
@@ -463,3 +463,36 @@ fn contains_each_with_auto_eq() -> Result<()> {
 fn is_contained_in_with_auto_eq() -> Result<()> {
     verify_that!(vec![3, 4, 2], is_contained_in![&1, &2, &3, &4])
 }
+
+#[test]
+fn unordered_elements_are_matches_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&i32>> = vec![eq(&3).boxed(), ge(&2).boxed(), lt(&2).boxed()];
+    verify_that!(vec![1, 2, 3], unordered_elements_are![..matchers])
+}
+
+#[test]
+fn unordered_elements_are_matches_boxed_matchers_built_at_runtime() -> Result<()> {
+    let expected = (0..100).collect::<Vec<i32>>();
+    let matchers: Vec<BoxedMatcher<&i32>> = expected.iter().rev().map(|e| eq(e).boxed()).collect();
+    verify_that!(expected, unordered_elements_are![..matchers])
+}
+
+#[test]
+fn unordered_elements_are_explains_size_mismatch_with_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&i32>> = vec![eq(&1).boxed(), eq(&2).boxed()];
+    let result = verify_that!(vec![1], unordered_elements_are![..matchers]);
+
+    verify_that!(result, err(displays_as(contains_substring("which has size 1 (expected 2)"))))
+}
+
+#[test]
+fn contains_each_matches_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&i32>> = vec![eq(&2).boxed(), ge(&3).boxed()];
+    verify_that!(vec![3, 2, 1], contains_each![..matchers])
+}
+
+#[test]
+fn is_contained_in_matches_vec_of_boxed_matchers() -> Result<()> {
+    let matchers: Vec<BoxedMatcher<&i32>> = vec![eq(&1).boxed(), ge(&2).boxed(), eq(&5).boxed()];
+    verify_that!(vec![2, 1], is_contained_in![..matchers])
+}
@@ -332,8 +332,101 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     .into()
 }
 
+mod matchable_derive;
+
+/// Derives `googletest::matcher::Matchable`, generating a matcher type with
+/// one builder method per field for structs, and per-variant matchers for
+/// enums.
+///
+/// See the documentation of `googletest::matcher::Matchable` for details.
+#[proc_macro_derive(Matchable)]
+pub fn derive_matchable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    matchable_derive::derive_matchable_impl(input)
+}
+
 mod verify_pred;
 
+mod matcher_attribute;
+
+/// Defines a matcher from a function which evaluates the actual value.
+///
+/// The first parameter of the annotated function receives the actual value.
+/// The function returns either `bool`, or `Result<(), E>` where `E: Display`
+/// explains why the actual value does not match:
+///
+/// ```ignore
+/// #[matcher]
+/// fn is_even(actual: u32) -> bool {
+///     actual % 2 == 0
+/// }
+///
+/// #[matcher]
+/// fn is_sorted(actual: &Vec<i32>) -> Result<(), String> {
+///     match actual.windows(2).position(|pair| pair[0] > pair[1]) {
+///         Some(index) => Err(format!("which is out of order at index {}", index + 1)),
+///         None => Ok(()),
+///     }
+/// }
+///
+/// verify_that!(4, is_even())?;
+/// verify_that!(vec![1, 2, 3], is_sorted())?;
+/// ```
+///
+/// A matcher whose actual value is a shared reference `&T` to a sized type
+/// also matches values of type `T` which implement `Copy`, so that a matcher
+/// taking `&u32` can be used both on `4u32` and on the elements of a
+/// `Vec<u32>`.
+///
+/// The function is replaced with a factory function of the same name which
+/// takes the remaining parameters and returns the matcher. These parameters
+/// are stored in the matcher and cloned each time it is evaluated, so they
+/// must implement `Clone`:
+///
+/// ```ignore
+/// #[matcher]
+/// fn is_divisible_by(actual: u32, divisor: u32) -> bool {
+///     actual % divisor == 0
+/// }
+///
+/// verify_that!(9, is_divisible_by(3))?;
+/// ```
+///
+/// The description of the matcher is derived from the function name followed
+/// by the `Debug` representation of the parameters, so the matcher above is
+/// described as "is divisible by 3" and, when negated, as "isn't divisible by
+/// 3". Use the `description` and `negated_description` arguments to override
+/// it. These are format strings in which the parameters are in scope:
+///
+/// ```ignore
+/// #[matcher(description = "is a multiple of {divisor}")]
+/// fn is_divisible_by(actual: u32, divisor: u32) -> bool {
+///     actual % divisor == 0
+/// }
+/// ```
+///
+/// Without a `description` argument, the first paragraph of the doc comment
+/// of the function is used as the description. Its first letter is lowercased
+/// and a final period is removed, so that the matcher below is described as
+/// "is a `Point { .. }` on the diagonal". Unlike the `description` argument,
+/// the doc comment is not a format string, so it cannot refer to parameters:
+///
+/// ```ignore
+/// /// Is a `Point { .. }` on the diagonal.
+/// #[matcher]
+/// fn is_on_diagonal(actual: &Point) -> bool {
+///     actual.x == actual.y
+/// }
+/// ```
+///
+/// If only `description` is given, `negated_description` is derived from it.
+#[proc_macro_attribute]
+pub fn matcher(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    matcher_attribute::matcher_impl(args, input)
+}
+
 /// This is an implementation detail of `verify_pred!`.
 ///
 /// It's not intended to be used directly.
@@ -369,7 +462,7 @@ pub fn __abbreviated_stringify(input: proc_macro::TokenStream) -> proc_macro::To
     .into()
 }
 
-fn abbreviated_string(target: &str) -> Result<std::borrow::Cow<str>, &'static str> {
+fn abbreviated_string(target: &str) -> Result<std::borrow::Cow<'_, str>, &'static str> {
     use std::borrow::Cow;
     match target.rsplit_once(',') {
         None => Err("Expect a `max_length` argument, but got none"),
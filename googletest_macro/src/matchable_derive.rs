@@ -0,0 +1,287 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, Generics, Ident,
+    Lifetime, LifetimeParam, Member, Type, Visibility,
+};
+
+/// A struct or enum variant for which a matcher is generated.
+struct Target<'a> {
+    /// The path used in patterns matching the struct or variant.
+    path: TokenStream,
+    /// The name of the struct or variant shown in descriptions.
+    name: String,
+    /// The name of the generated matcher type.
+    matcher: Ident,
+    fields: &'a Fields,
+}
+
+/// The input type with everything needed to generate its matchers.
+struct Context<'a> {
+    ident: &'a Ident,
+    vis: &'a Visibility,
+    /// The input type with its generic parameters.
+    ty: Type,
+    /// The generics of the generated types: those of the input type preceded by
+    /// the lifetime of the borrowed actual value.
+    generics: Generics,
+    lifetime: Lifetime,
+}
+
+pub fn derive_matchable_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let DeriveInput { ident, vis, generics, data, .. } = input;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let lifetime = Lifetime::new("'__googletest_actual", Span::call_site());
+    let ty: Type = parse_quote!(#ident #ty_generics);
+    let mut matcher_generics = generics.clone();
+    matcher_generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    matcher_generics.make_where_clause().predicates.push(parse_quote!(#ty: ::std::fmt::Debug));
+    let context = Context { ident, vis, ty, generics: matcher_generics, lifetime };
+    let matcher_ident = format_ident!("{}Matcher", ident);
+    let (_, matcher_ty_generics, _) = context.generics.split_for_impl();
+
+    let (items, constructor) = match data {
+        Data::Struct(data) => {
+            let target = Target {
+                path: quote!(#ident),
+                name: ident.to_string(),
+                matcher: matcher_ident.clone(),
+                fields: &data.fields,
+            };
+            let items = generate_matcher(&context, &target);
+            let name = &target.name;
+            let constructor = quote! {
+                #matcher_ident(
+                    ::googletest::matchers::__internal_unstable_do_not_depend_on_these::StructMatcher::new(
+                        #name,
+                        |_| true,
+                        |_| #name,
+                    ),
+                )
+            };
+            (items, constructor)
+        }
+        Data::Enum(data) => {
+            let targets = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_ident = &variant.ident;
+                    Target {
+                        path: quote!(#ident::#variant_ident),
+                        name: format!("{ident}::{variant_ident}"),
+                        matcher: format_ident!("{}{}Matcher", ident, variant_ident),
+                        fields: &variant.fields,
+                    }
+                })
+                .collect::<Vec<_>>();
+            let items = generate_variant_matchers(&context, &matcher_ident, &targets);
+            (items, quote!(#matcher_ident(::std::marker::PhantomData)))
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "#[derive(Matchable)] is only supported for structs and enums",
+            ))
+        }
+    };
+
+    let ty = &context.ty;
+    let mut matchable_where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    matchable_where_clause.predicates.push(parse_quote!(#ty: ::std::fmt::Debug));
+    Ok(quote! {
+        #items
+
+        impl #impl_generics ::googletest::matcher::Matchable for #ty #matchable_where_clause {
+            type Matcher<'__googletest_actual> = #matcher_ident #matcher_ty_generics
+            where
+                Self: '__googletest_actual;
+
+            fn matcher<'__googletest_actual>() -> Self::Matcher<'__googletest_actual>
+            where
+                Self: '__googletest_actual,
+            {
+                #constructor
+            }
+        }
+    })
+}
+
+/// Generates the matcher type for a struct or an enum variant, with one
+/// builder method per field.
+fn generate_matcher(context: &Context, target: &Target) -> TokenStream {
+    let Context { vis, ty, generics, lifetime, .. } = context;
+    let Target { path, matcher, fields, .. } = target;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let methods = fields.iter().enumerate().map(|(index, field)| {
+        let field_type = &field.ty;
+        let (member, method, field_name) = match &field.ident {
+            Some(name) => (Member::Named(name.clone()), name.clone(), name.to_string()),
+            None => (Member::Unnamed(index.into()), format_ident!("_{index}"), index.to_string()),
+        };
+        let doc = format!("Expects the field `{field_name}` to match `matcher`.");
+        quote! {
+            #[doc = #doc]
+            #vis fn #method(
+                self,
+                matcher: impl ::googletest::matcher::Matcher<&#lifetime #field_type> + #lifetime,
+            ) -> Self
+            where
+                #field_type: ::std::fmt::Debug,
+            {
+                Self(self.0.field(
+                    ::googletest::matchers::__internal_unstable_do_not_depend_on_these::field_matcher(
+                        |actual: &#ty| match actual {
+                            #path { #member: value, .. } => ::std::option::Option::Some(value),
+                            // The pattern below is unreachable if the type is a struct.
+                            #[allow(unreachable_patterns)]
+                            _ => ::std::option::Option::None,
+                        },
+                        #field_name,
+                        matcher,
+                    ),
+                ))
+            }
+        }
+    });
+    let doc = format!(
+        "Matcher for [`{}`] generated by `#[derive(Matchable)]`.\n\n\
+         Each method adds an expectation on the field of the same name.",
+        target.name
+    );
+    quote! {
+        #[doc = #doc]
+        #vis struct #matcher #impl_generics(
+            ::googletest::matchers::__internal_unstable_do_not_depend_on_these::StructMatcher<#lifetime, #ty>,
+        ) #where_clause;
+
+        impl #impl_generics #matcher #ty_generics #where_clause {
+            #(#methods)*
+        }
+
+        impl #impl_generics ::googletest::matcher::MatcherBase for #matcher #ty_generics #where_clause {}
+
+        impl #impl_generics ::googletest::matcher::Matcher<&#lifetime #ty>
+            for #matcher #ty_generics #where_clause
+        {
+            fn matches(&self, actual: &#lifetime #ty) -> ::googletest::matcher::MatcherResult {
+                self.0.matches(actual)
+            }
+
+            fn describe(
+                &self,
+                matcher_result: ::googletest::matcher::MatcherResult,
+            ) -> ::googletest::description::Description {
+                self.0.describe(matcher_result)
+            }
+
+            fn explain_match(&self, actual: &#lifetime #ty) -> ::googletest::description::Description {
+                self.0.explain_match(actual)
+            }
+
+            fn match_and_explain(
+                &self,
+                actual: &#lifetime #ty,
+                then: &mut dyn ::std::ops::FnMut(
+                    ::googletest::matcher::MatcherResult,
+                    &dyn ::std::ops::Fn() -> ::googletest::description::Description,
+                ),
+            ) {
+                self.0.match_and_explain(actual, then)
+            }
+        }
+    }
+}
+
+/// Generates the matcher type of each variant of an enum, and the type
+/// returned by `Matchable::matcher` with one method per variant.
+fn generate_variant_matchers(
+    context: &Context,
+    matcher_ident: &Ident,
+    targets: &[Target],
+) -> TokenStream {
+    let Context { ident, vis, ty, generics, lifetime } = context;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let variant_matchers = targets.iter().map(|target| generate_matcher(context, target));
+    let variant_name_arms = targets.iter().map(|Target { path, name, .. }| {
+        let variant_name = &name[ident.to_string().len() + 2..];
+        quote!(#path { .. } => #variant_name,)
+    });
+    let variant_name = quote! {
+        |actual: &#ty| match actual {
+            #(#variant_name_arms)*
+        }
+    };
+    let methods = targets.iter().map(|target| {
+        let Target { path, name, matcher, .. } = target;
+        let method = variant_method_name(&name[ident.to_string().len() + 2..]);
+        let doc = format!("Returns a matcher for the variant [`{name}`].");
+        quote! {
+            #[doc = #doc]
+            #vis fn #method(self) -> #matcher #ty_generics {
+                #matcher(
+                    ::googletest::matchers::__internal_unstable_do_not_depend_on_these::StructMatcher::new(
+                        #name,
+                        |actual: &#ty| ::std::matches!(actual, #path { .. }),
+                        #variant_name,
+                    ),
+                )
+            }
+        }
+    });
+    let doc = format!(
+        "Matchers for the variants of [`{ident}`] generated by `#[derive(Matchable)]`.\n\n\
+         Each method returns a matcher for the variant of the same name."
+    );
+    quote! {
+        #(#variant_matchers)*
+
+        #[doc = #doc]
+        #vis struct #matcher_ident #impl_generics(
+            ::std::marker::PhantomData<&#lifetime #ty>,
+        ) #where_clause;
+
+        impl #impl_generics #matcher_ident #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
+
+/// Converts the name of an enum variant to the snake case name of the method
+/// returning its matcher, using a raw identifier for keywords.
+fn variant_method_name(variant: &str) -> Ident {
+    let mut name = String::new();
+    for (index, c) in variant.char_indices() {
+        if c.is_uppercase() {
+            if index > 0 {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    syn::parse_str::<Ident>(&name).unwrap_or_else(|_| Ident::new_raw(&name, Span::call_site()))
+}
@@ -0,0 +1,481 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, visit_mut::VisitMut, Attribute, Expr,
+    ExprLit, FnArg, GenericParam, Ident, ItemFn, Lifetime, LifetimeParam, Lit, LitStr, Meta,
+    MetaNameValue, Pat, PatIdent, PatType, ReturnType, Type, TypeReference,
+};
+
+/// Arguments accepted by `#[matcher(...)]`.
+#[derive(Default)]
+struct MatcherArgs {
+    description: Option<LitStr>,
+    negated_description: Option<LitStr>,
+}
+
+impl MatcherArgs {
+    fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("description") {
+            self.description = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("negated_description") {
+            self.negated_description = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `description` or `negated_description`"))
+        }
+    }
+}
+
+/// The kind of value returned by the function annotated with `#[matcher]`.
+enum Outcome {
+    /// `bool`: `true` if the actual value matches.
+    Bool,
+    /// `Result<(), E>`: `Ok(())` if the actual value matches, otherwise an
+    /// error whose `Display` output explains the mismatch.
+    Result,
+}
+
+impl Outcome {
+    fn new(output: &ReturnType) -> syn::Result<Self> {
+        match output {
+            ReturnType::Type(_, ty) => match &**ty {
+                Type::Path(path) if path.path.is_ident("bool") => Ok(Outcome::Bool),
+                Type::Path(path)
+                    if path.path.segments.last().is_some_and(|s| s.ident == "Result") =>
+                {
+                    Ok(Outcome::Result)
+                }
+                _ => Err(syn::Error::new(
+                    ty.span(),
+                    "a matcher function must return `bool` or `Result<(), E>`",
+                )),
+            },
+            ReturnType::Default => Err(syn::Error::new(
+                output.span(),
+                "a matcher function must return `bool` or `Result<(), E>`",
+            )),
+        }
+    }
+}
+
+/// Replaces every elided or anonymous (`'_`) lifetime in a type with a fresh
+/// named lifetime, so that the type can appear in an `impl` header.
+struct NameElidedLifetimes {
+    lifetimes: Vec<Lifetime>,
+}
+
+impl NameElidedLifetimes {
+    fn fresh(&mut self) -> Lifetime {
+        let lifetime = Lifetime::new(
+            &format!("'__googletest_matcher_{}", self.lifetimes.len()),
+            Span::call_site(),
+        );
+        self.lifetimes.push(lifetime.clone());
+        lifetime
+    }
+}
+
+impl VisitMut for NameElidedLifetimes {
+    fn visit_type_reference_mut(&mut self, reference: &mut syn::TypeReference) {
+        match &reference.lifetime {
+            Some(lifetime) if lifetime.ident != "_" => {}
+            _ => reference.lifetime = Some(self.fresh()),
+        }
+        syn::visit_mut::visit_type_reference_mut(self, reference);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = self.fresh();
+        }
+    }
+}
+
+pub fn matcher_impl(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut matcher_args = MatcherArgs::default();
+    let args_parser = syn::meta::parser(|meta| matcher_args.parse(meta));
+    parse_macro_input!(args with args_parser);
+    let item = parse_macro_input!(input as ItemFn);
+    match expand(matcher_args, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn expand(args: MatcherArgs, item: ItemFn) -> syn::Result<TokenStream> {
+    let ItemFn { attrs, vis, sig, block } = item;
+    let outcome = Outcome::new(&sig.output)?;
+    let output = &sig.output;
+    let fn_name = &sig.ident;
+    let struct_name = format_ident!("{}Matcher", to_upper_camel_case(&fn_name.to_string()));
+
+    let mut inputs = sig.inputs.iter();
+    let Some(actual_arg) = inputs.next() else {
+        return Err(syn::Error::new(
+            sig.span(),
+            "a matcher function must take the actual value as its first parameter",
+        ));
+    };
+    let FnArg::Typed(PatType { ty: actual_type, .. }) = actual_arg else {
+        return Err(syn::Error::new(
+            actual_arg.span(),
+            "`self` receiver is not accepted as matcher argument",
+        ));
+    };
+    let parameters = inputs
+        .map(|input| match input {
+            FnArg::Typed(PatType { pat, ty, .. }) => match &**pat {
+                Pat::Ident(PatIdent { ident, .. }) => Ok((ident.clone(), ty.clone())),
+                _ => {
+                    Err(syn::Error::new(pat.span(), "matcher parameters must be plain identifiers"))
+                }
+            },
+            FnArg::Receiver(_) => Err(syn::Error::new(
+                input.span(),
+                "`self` receiver is not accepted as matcher argument",
+            )),
+        })
+        .collect::<syn::Result<Vec<(Ident, Box<Type>)>>>()?;
+    let parameter_names = parameters.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    let parameter_types = parameters.iter().map(|(_, ty)| ty).collect::<Vec<_>>();
+
+    let generics = &sig.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_parameters = generics.type_params().map(|param| &param.ident).collect::<Vec<_>>();
+    let (phantom_field, phantom_init) = if type_parameters.is_empty() {
+        (quote!(), quote!())
+    } else {
+        (
+            quote!(__phantom: ::std::marker::PhantomData<fn() -> (#(#type_parameters,)*)>,),
+            quote!(__phantom: ::std::marker::PhantomData,),
+        )
+    };
+
+    let mut actual_type = (**actual_type).clone();
+    let mut lifetime_namer = NameElidedLifetimes { lifetimes: vec![] };
+    lifetime_namer.visit_type_mut(&mut actual_type);
+    let mut matcher_generics = generics.clone();
+    for lifetime in lifetime_namer.lifetimes.iter().rev() {
+        matcher_generics
+            .params
+            .insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    }
+    matcher_generics.make_where_clause().predicates.push(parse_quote! {
+        #actual_type: ::std::fmt::Debug + ::std::clone::Clone
+    });
+    let (matcher_impl_generics, _, matcher_where_clause) = matcher_generics.split_for_impl();
+
+    let evaluate_inputs = &sig.inputs;
+    // Names the trait explicitly, as the matcher may also implement
+    // `Matcher<T>` for an actual value of type `&T`.
+    let describe = quote!(::googletest::matcher::Matcher::<#actual_type>::describe);
+    let evaluate = quote! {
+        Self::__evaluate(actual #(, ::std::clone::Clone::clone(&self.#parameter_names))*)
+    };
+    let explain_match = match outcome {
+        Outcome::Bool => quote! {
+            fn match_and_explain(
+                &self,
+                actual: #actual_type,
+                then: &mut dyn ::std::ops::FnMut(
+                    ::googletest::matcher::MatcherResult,
+                    &dyn ::std::ops::Fn() -> ::googletest::description::Description,
+                ),
+            ) {
+                let matcher_result = ::googletest::matcher::MatcherResult::from(#evaluate);
+                then(matcher_result, &|| {
+                    ::std::format!("which {}", #describe(self, matcher_result)).into()
+                })
+            }
+        },
+        Outcome::Result => quote! {
+            fn explain_match(
+                &self,
+                actual: #actual_type,
+            ) -> ::googletest::description::Description {
+                match #evaluate {
+                    ::std::result::Result::Ok(()) => {
+                        ::std::format!(
+                            "which {}",
+                            #describe(self, ::googletest::matcher::MatcherResult::Match),
+                        )
+                        .into()
+                    }
+                    ::std::result::Result::Err(error) => ::std::string::ToString::to_string(&error).into(),
+                }
+            }
+
+            fn match_and_explain(
+                &self,
+                actual: #actual_type,
+                then: &mut dyn ::std::ops::FnMut(
+                    ::googletest::matcher::MatcherResult,
+                    &dyn ::std::ops::Fn() -> ::googletest::description::Description,
+                ),
+            ) {
+                match #evaluate {
+                    ::std::result::Result::Ok(()) => then(
+                        ::googletest::matcher::MatcherResult::Match,
+                        &|| {
+                            ::std::format!(
+                                "which {}",
+                                #describe(self, ::googletest::matcher::MatcherResult::Match),
+                            )
+                            .into()
+                        },
+                    ),
+                    ::std::result::Result::Err(error) => then(
+                        ::googletest::matcher::MatcherResult::NoMatch,
+                        &|| ::std::string::ToString::to_string(&error).into(),
+                    ),
+                }
+            }
+        },
+    };
+    let is_match = match outcome {
+        Outcome::Bool => quote!(#evaluate),
+        Outcome::Result => quote!(::std::result::Result::is_ok(&#evaluate)),
+    };
+
+    let (description, negated_description) =
+        descriptions(&args, doc_description(&attrs), fn_name, &parameter_names);
+    let by_value_impl = by_value_impl(
+        &actual_type,
+        generics,
+        &lifetime_namer.lifetimes,
+        &struct_name,
+        &ty_generics,
+    );
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis fn #fn_name #impl_generics (#(#parameter_names: #parameter_types),*)
+            -> #struct_name #ty_generics #where_clause
+        {
+            #struct_name { #(#parameter_names,)* #phantom_init }
+        }
+
+        #[doc = ::std::concat!("Matcher returned by [`", ::std::stringify!(#fn_name), "`].")]
+        #vis struct #struct_name #impl_generics #where_clause {
+            #(#parameter_names: #parameter_types,)*
+            #phantom_field
+        }
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[allow(clippy::needless_pass_by_value, clippy::ptr_arg)]
+            fn __evaluate(#evaluate_inputs) #output #block
+        }
+
+        impl #impl_generics ::googletest::matcher::MatcherBase
+            for #struct_name #ty_generics #where_clause {}
+
+        impl #matcher_impl_generics ::googletest::matcher::Matcher<#actual_type>
+            for #struct_name #ty_generics #matcher_where_clause
+        {
+            fn matches(&self, actual: #actual_type) -> ::googletest::matcher::MatcherResult {
+                (#is_match).into()
+            }
+
+            fn describe(
+                &self,
+                matcher_result: ::googletest::matcher::MatcherResult,
+            ) -> ::googletest::description::Description {
+                #[allow(unused_variables)]
+                let #struct_name { #(#parameter_names,)* .. } = self;
+                match matcher_result {
+                    ::googletest::matcher::MatcherResult::Match => #description.into(),
+                    ::googletest::matcher::MatcherResult::NoMatch => #negated_description.into(),
+                }
+            }
+
+            #explain_match
+        }
+
+        #by_value_impl
+    })
+}
+
+/// Returns an implementation of `Matcher<T>` delegating to the one of
+/// `Matcher<&T>` if the actual value is a shared reference `&T`.
+///
+/// Assertions pass `Copy` values such as `u32` by value, so that a matcher
+/// taking `&u32` could otherwise only be used inside container matchers. The
+/// implementation is restricted to `Copy` types by a bound which is
+/// higher-ranked, so that the compiler accepts it even where it never holds,
+/// as for `&str` or `&Vec<i32>`.
+///
+/// The implementation is generic over the lifetimes named in `T` only. A
+/// bound on `&T` for the lifetime of the reference would make the compiler
+/// require that lifetime of the reference to the local value.
+fn by_value_impl(
+    actual_type: &Type,
+    generics: &syn::Generics,
+    named_lifetimes: &[Lifetime],
+    struct_name: &Ident,
+    ty_generics: &syn::TypeGenerics,
+) -> TokenStream {
+    let Type::Reference(TypeReference { lifetime, mutability: None, elem, .. }) = actual_type
+    else {
+        return quote!();
+    };
+    // Unsized values cannot be passed by value.
+    match &**elem {
+        Type::Slice(_) | Type::TraitObject(_) => return quote!(),
+        Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => return quote!(),
+        _ => {}
+    }
+    let mut generics = generics.clone();
+    for named_lifetime in named_lifetimes.iter().rev() {
+        if Some(named_lifetime) != lifetime.as_ref() {
+            generics
+                .params
+                .insert(0, GenericParam::Lifetime(LifetimeParam::new(named_lifetime.clone())));
+        }
+    }
+    generics.make_where_clause().predicates.push(parse_quote! {
+        for<'__googletest_actual> #elem: ::std::fmt::Debug + ::std::marker::Copy
+    });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::googletest::matcher::Matcher<#elem>
+            for #struct_name #ty_generics #where_clause
+        {
+            fn matches(&self, actual: #elem) -> ::googletest::matcher::MatcherResult {
+                ::googletest::matcher::Matcher::<&#elem>::matches(self, &actual)
+            }
+
+            fn describe(
+                &self,
+                matcher_result: ::googletest::matcher::MatcherResult,
+            ) -> ::googletest::description::Description {
+                ::googletest::matcher::Matcher::<&#elem>::describe(self, matcher_result)
+            }
+
+            fn explain_match(&self, actual: #elem) -> ::googletest::description::Description {
+                ::googletest::matcher::Matcher::<&#elem>::explain_match(self, &actual)
+            }
+
+            fn match_and_explain(
+                &self,
+                actual: #elem,
+                then: &mut dyn ::std::ops::FnMut(
+                    ::googletest::matcher::MatcherResult,
+                    &dyn ::std::ops::Fn() -> ::googletest::description::Description,
+                ),
+            ) {
+                ::googletest::matcher::Matcher::<&#elem>::match_and_explain(self, &actual, then)
+            }
+        }
+    }
+}
+
+/// Returns the first paragraph of the doc comment of the function, if it has
+/// one, as a format string describing the matcher.
+///
+/// The lines of the paragraph are joined with spaces, the first letter is
+/// lowercased unless it starts an acronym, and a final period is removed, so
+/// that `/// Is an even number.` becomes "is an even number". The text is
+/// used literally, so its braces are escaped.
+fn doc_description(attrs: &[Attribute]) -> Option<LitStr> {
+    let mut lines = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("doc")) {
+        let Meta::NameValue(MetaNameValue {
+            value: Expr::Lit(ExprLit { lit: Lit::Str(line), .. }),
+            ..
+        }) = &attr.meta
+        else {
+            continue;
+        };
+        let text = line.value();
+        let text = text.trim();
+        if text.is_empty() {
+            if lines.is_empty() {
+                continue;
+            }
+            break;
+        }
+        lines.push((text.to_string(), line.span()));
+    }
+    let span = lines.first()?.1;
+    let mut description = lines.into_iter().map(|(text, _)| text).collect::<Vec<_>>().join(" ");
+    if description.ends_with('.') && !description.ends_with("..") {
+        description.pop();
+    }
+    let mut chars = description.chars();
+    let first = chars.next()?;
+    let description = match chars.next() {
+        Some(second) if second.is_uppercase() => description,
+        _ => first.to_lowercase().chain(description.chars().skip(1)).collect(),
+    };
+    Some(LitStr::new(&description.replace('{', "{{").replace('}', "}}"), span))
+}
+
+/// Returns the expressions formatting the positive and negative descriptions
+/// of the matcher.
+///
+/// Explicit descriptions from the attribute arguments are used as format
+/// strings in which the matcher parameters are in scope. Failing that, the doc
+/// comment of the function is used literally. Otherwise the description
+/// is derived from the function name, followed by the `Debug` representation
+/// of the parameters, so that `is_divisible_by(3)` is described as "is
+/// divisible by 3". Without an explicit negated description, it is derived
+/// from the positive one at runtime.
+fn descriptions(
+    args: &MatcherArgs,
+    doc_description: Option<LitStr>,
+    fn_name: &Ident,
+    parameter_names: &[&Ident],
+) -> (TokenStream, TokenStream) {
+    let description = match args.description.clone().or(doc_description) {
+        Some(description) => quote!(::std::format!(#description)),
+        None => {
+            let mut description = fn_name.to_string().trim_matches('_').replace('_', " ");
+            if !parameter_names.is_empty() {
+                description.push(' ');
+                description.push_str(&vec!["{:?}"; parameter_names.len()].join(", "));
+            }
+            let description = LitStr::new(&description, fn_name.span());
+            quote!(::std::format!(#description #(, #parameter_names)*))
+        }
+    };
+    let negated_description = match &args.negated_description {
+        Some(negated_description) => quote!(::std::format!(#negated_description)),
+        None => quote! {
+            ::googletest::matcher_support::__internal_unstable_do_not_depend_on_these::negated_description(
+                &#description,
+            )
+        },
+    };
+    (description, negated_description)
+}
+
+fn to_upper_camel_case(snake_case: &str) -> String {
+    snake_case
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
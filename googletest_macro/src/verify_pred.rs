@@ -141,7 +141,7 @@ impl AccumulatePartsState {
             Ident::new(&format!("__googletest__verify_pred__var{}", self.var_num), value.span());
         self.var_num += 1;
         self.statements.push(quote! {
-            #[allow(non_snake_case)]
+            #[allow(non_snake_case, unused_parens)]
             let mut #var_name = #value;
         });
         syn::parse::<Expr>(quote!(#var_name).into()).unwrap()
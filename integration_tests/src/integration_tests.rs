@@ -1513,9 +1513,9 @@ mod tests {
         verify_that!(
             output,
             contains_regex(indoc! {"
-                Expected: is within 1.[0-9]+e-[0-9]+ of 2.0
+                Expected: is within 4 ULPs of 2.0
                 Actual: 1.0,
-                  which isn't within 1.[0-9]+e-[0-9]+ of 2.0
+                  which is 4503599627370496 ULPs away
                   at .*verify_float_eq_when_not_equal_returns_error.rs:[0-9]+:[0-9]
             "})
         )
@@ -1545,9 +1545,9 @@ mod tests {
         expect_that!(
             output,
             contains_regex(indoc! {"
-                Expected: is within 1.[0-9]+e-[0-9]+ of 2.0
+                Expected: is within 4 ULPs of 2.0
                 Actual: 1.0,
-                  which isn't within 1.[0-9]+e-[0-9]+ of 2.0
+                  which is 4503599627370496 ULPs away
                   at .*expect_float_eq_when_not_equal_marks_failed.rs:[0-9]+:[0-9]
             "})
         );
@@ -1562,9 +1562,9 @@ mod tests {
         expect_that!(
             output,
             contains_regex(indoc! {"
-                Expected: is within 1.[0-9]+e-[0-9]+ of 2.0
+                Expected: is within 4 ULPs of 2.0
                 Actual: 1.0,
-                  which isn't within 1.[0-9]+e-[0-9]+ of 2.0
+                  which is 4503599627370496 ULPs away
                 Failure message with argument: argument
                   at .*expect_float_eq_supports_custom_message.rs:[0-9]+:[0-9]
             "})